@@ -0,0 +1,58 @@
+use crate::common::i18n::trf;
+use crate::common::logging::timestamp;
+use dapp_lib::prelude::{ContentID, SwarmName};
+
+// A change of content in one of watched swarms,
+// that happened while we were looking at another swarm
+#[derive(Debug, Clone)]
+pub struct Activity {
+    pub s_name: SwarmName,
+    pub c_id: ContentID,
+    pub header: String,
+    pub tags: Vec<String>,
+    pub is_new: bool,
+    time: String,
+}
+
+impl Activity {
+    pub fn new(
+        s_name: SwarmName,
+        c_id: ContentID,
+        header: String,
+        tags: Vec<String>,
+        is_new: bool,
+    ) -> Self {
+        Activity {
+            s_name,
+            c_id,
+            header,
+            tags,
+            is_new,
+            time: timestamp(),
+        }
+    }
+
+    // Text for a notification
+    pub fn summary(&self) -> String {
+        if self.is_new {
+            trf("activity.new", &[&self.c_id, &self.s_name])
+        } else {
+            trf("activity.changed", &[&self.c_id, &self.s_name])
+        }
+    }
+
+    // Single line for activity feed
+    pub fn line(&self) -> String {
+        // Only hours, minutes and seconds out of full timestamp
+        let time = self.time.get(11..19).unwrap_or(&self.time);
+        let mut line = format!("{} {}", time, self.summary());
+        if !self.header.is_empty() {
+            line.push_str(": ");
+            line.push_str(self.header.lines().next().unwrap_or(""));
+        }
+        if !self.tags.is_empty() {
+            line.push_str(&format!(" [{}]", self.tags.join(", ")));
+        }
+        line
+    }
+}
@@ -0,0 +1,169 @@
+use crate::common::i18n::{tr, trf};
+use dapp_lib::prelude::{GnomeId, SwarmID};
+use std::collections::{HashSet, VecDeque};
+
+// Chat messages travel as neighbor requests (see neighbor_msg.rs).
+// Every gnome passes a message it has not seen before on to all of it's
+// other neighbors in that swarm, so it reaches whole swarm,
+// but it is only shown by those that have joined swarm's chat room.
+// A message is identified by it's author and a number given by that author.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChatKind {
+    Text,
+    Joined,
+    Left,
+}
+
+impl ChatKind {
+    pub fn byte(&self) -> u8 {
+        match self {
+            ChatKind::Text => 0,
+            ChatKind::Joined => 1,
+            ChatKind::Left => 2,
+        }
+    }
+
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(ChatKind::Text),
+            1 => Some(ChatKind::Joined),
+            2 => Some(ChatKind::Left),
+            _other => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatMessage {
+    pub author: GnomeId,
+    pub id: u32,
+    pub kind: ChatKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChatAction {
+    Join,
+    Write,
+    ShowLog,
+    Leave,
+    Back,
+}
+
+impl ChatAction {
+    pub fn text(&self) -> String {
+        tr(match self {
+            ChatAction::Join => "chat.join",
+            ChatAction::Write => "chat.write",
+            ChatAction::ShowLog => "chat.show_log",
+            ChatAction::Leave => "chat.leave",
+            ChatAction::Back => "common.back",
+        })
+    }
+}
+
+// How many message ids we remember in order not to show
+// or pass on the same message twice
+const SEEN_LIMIT: usize = 1024;
+const LOG_LIMIT: usize = 256;
+
+pub struct ChatRoom {
+    pub swarm_id: SwarmID,
+    pub joined: bool,
+    // Current neighbors in that swarm, messages are sent to them
+    pub neighbors: Vec<GnomeId>,
+    // Neighbors that told us they are in the room
+    pub members: HashSet<GnomeId>,
+    next_id: u32,
+    seen: VecDeque<(GnomeId, u32)>,
+    log: VecDeque<(GnomeId, String)>,
+}
+
+impl ChatRoom {
+    pub fn new(swarm_id: SwarmID) -> Self {
+        ChatRoom {
+            swarm_id,
+            joined: false,
+            neighbors: vec![],
+            members: HashSet::new(),
+            next_id: 0,
+            seen: VecDeque::with_capacity(SEEN_LIMIT),
+            log: VecDeque::with_capacity(LOG_LIMIT),
+        }
+    }
+
+    pub fn available_actions(&self) -> Vec<ChatAction> {
+        if self.joined {
+            vec![
+                ChatAction::Write,
+                ChatAction::ShowLog,
+                ChatAction::Leave,
+                ChatAction::Back,
+            ]
+        } else {
+            vec![ChatAction::Join, ChatAction::ShowLog, ChatAction::Back]
+        }
+    }
+
+    // New message of ours, marked as seen so that it
+    // is not shown again once neighbors pass it back
+    pub fn compose(&mut self, author: GnomeId, kind: ChatKind, text: String) -> ChatMessage {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.remember(author, id);
+        ChatMessage {
+            author,
+            id,
+            kind,
+            text,
+        }
+    }
+
+    // Returns false for messages we have already seen
+    pub fn remember(&mut self, author: GnomeId, id: u32) -> bool {
+        if self.seen.contains(&(author, id)) {
+            return false;
+        }
+        if self.seen.len() >= SEEN_LIMIT {
+            self.seen.pop_front();
+        }
+        self.seen.push_back((author, id));
+        true
+    }
+
+    pub fn push(&mut self, author: GnomeId, text: String) {
+        if self.log.len() >= LOG_LIMIT {
+            self.log.pop_front();
+        }
+        self.log.push_back((author, text));
+    }
+
+    pub fn push_info(&mut self, text: String) {
+        self.push(GnomeId::any(), text);
+    }
+
+    pub fn push_message(&mut self, message: &ChatMessage) {
+        match message.kind {
+            ChatKind::Text => self.push(message.author, message.text.clone()),
+            ChatKind::Joined => self.push_info(trf("chat.member_joined", &[&message.author])),
+            ChatKind::Left => self.push_info(trf("chat.member_left", &[&message.author])),
+        }
+    }
+
+    pub fn log_text(&self, my_id: GnomeId) -> String {
+        let mut text = String::with_capacity(self.log.len() * 32);
+        for (author, line) in &self.log {
+            if author.is_any() {
+                text.push_str(&format!("*** {}\n", line));
+            } else if *author == my_id {
+                text.push_str(&format!("<{}> {}\n", tr("chat.me"), line));
+            } else {
+                text.push_str(&format!("<{}> {}\n", author, line));
+            }
+        }
+        if text.is_empty() {
+            text.push_str(&tr("chat.no_messages"));
+        }
+        text
+    }
+}
@@ -0,0 +1,33 @@
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+
+// Experiments are only available in developer mode,
+// some of them send test payloads that can damage swarm's contents.
+pub const EXPERIMENTS: [KeyAction; 18] = [
+    KeyAction::BroadcastTestData,
+    KeyAction::ChangeDiameter,
+    KeyAction::StartBroadcast,
+    KeyAction::EndBroadcast,
+    KeyAction::UnsubscribeBroadcast,
+    KeyAction::StartMulticast,
+    KeyAction::MulticastTestData,
+    KeyAction::EndMulticast,
+    KeyAction::UnsubscribeMulticast,
+    KeyAction::SubscribeMulticast,
+    KeyAction::SendToMCastSource,
+    KeyAction::SendToBCastSource,
+    KeyAction::StartForum,
+    KeyAction::TransformLink,
+    KeyAction::ReadManifest,
+    KeyAction::TestNotification,
+    KeyAction::AppendEmptyContent,
+    KeyAction::StartUnicast,
+];
+
+pub fn is_experiment(action: KeyAction) -> bool {
+    EXPERIMENTS.contains(&action)
+}
+
+pub fn experiment_line(action: KeyAction, keymap: &Keymap) -> String {
+    let keys = keymap.keys_for(KeyContext::Debug, action);
+    format!("{}: {}", keys.join(" "), action.description())
+}
@@ -0,0 +1,136 @@
+use crate::catalog::tui::{MenuItem, TileType};
+use crate::common::i18n::tr;
+
+// Everything that can be done from a context menu.
+// Menus are built for every tile separately, so an action
+// keeps it's meaning no matter where it ends up in a menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MenuAction {
+    ShowTags,
+    ShowDataTypes,
+    AddTag,
+    AddDataType,
+    CreateContent,
+    Invitations,
+    ActiveSwarms,
+    AddSearch,
+    AppendNote,
+    RemoveNote,
+    CopyLink,
+    EditLink,
+    PasteLink,
+    ListSearches,
+    Chat,
+    Ping,
+    VillageSummary,
+    FriendRequest,
+    AskForContent,
+}
+
+impl MenuAction {
+    pub fn label(&self) -> String {
+        tr(match self {
+            MenuAction::ShowTags => "cmenu.tags",
+            MenuAction::ShowDataTypes => "cmenu.data_types",
+            MenuAction::AddTag => "cmenu.add_tag",
+            MenuAction::AddDataType => "cmenu.add_data_type",
+            MenuAction::CreateContent => "cmenu.create_new",
+            MenuAction::Invitations => "cmenu.invitations",
+            MenuAction::ActiveSwarms => "cmenu.active_swarms",
+            MenuAction::AddSearch => "cmenu.add_search",
+            MenuAction::AppendNote => "cmenu.new_note",
+            MenuAction::RemoveNote => "cmenu.remove_note",
+            MenuAction::CopyLink => "cmenu.copy_link",
+            MenuAction::EditLink => "cmenu.edit_link",
+            MenuAction::PasteLink => "cmenu.paste_link",
+            MenuAction::ListSearches => "cmenu.list_searches",
+            MenuAction::Chat => "cmenu.chat",
+            MenuAction::Ping => "cmenu.ping",
+            MenuAction::VillageSummary => "cmenu.summary",
+            MenuAction::FriendRequest => "cmenu.invite",
+            MenuAction::AskForContent => "cmenu.ask_for_cid",
+        })
+    }
+
+    // Actions that modify swarm's data
+    fn changes_data(&self) -> bool {
+        matches!(
+            self,
+            MenuAction::AddTag
+                | MenuAction::AddDataType
+                | MenuAction::CreateContent
+                | MenuAction::AppendNote
+                | MenuAction::RemoveNote
+                | MenuAction::EditLink
+                | MenuAction::PasteLink
+        )
+    }
+}
+
+// What decides which menu items can be used right now
+pub struct MenuContext {
+    // Why we are not allowed to change swarm's data, None when we are
+    pub change_denied: Option<String>,
+    pub clipboard_full: bool,
+}
+
+// Actions available on given tile, each with a reason
+// why it can not be used, if that is the case
+pub fn menu_for(tile: &TileType, ctx: &MenuContext) -> Vec<(MenuAction, Option<String>)> {
+    let actions = match tile {
+        TileType::Home(_g_id) => vec![
+            MenuAction::ShowTags,
+            MenuAction::ShowDataTypes,
+            MenuAction::AddTag,
+            MenuAction::AddDataType,
+            MenuAction::CreateContent,
+            MenuAction::Invitations,
+            MenuAction::ActiveSwarms,
+            MenuAction::AddSearch,
+        ],
+        TileType::Content(_d_type, _c_id) => vec![
+            MenuAction::AppendNote,
+            MenuAction::RemoveNote,
+            MenuAction::CopyLink,
+            MenuAction::EditLink,
+        ],
+        TileType::Field => vec![
+            MenuAction::PasteLink,
+            MenuAction::Chat,
+            MenuAction::ListSearches,
+        ],
+        TileType::Neighbor(_g_id) => vec![
+            MenuAction::Ping,
+            MenuAction::VillageSummary,
+            MenuAction::FriendRequest,
+            MenuAction::AskForContent,
+        ],
+        TileType::Application => vec![],
+    };
+    actions
+        .into_iter()
+        .map(|action| {
+            let reason = match (tile, action) {
+                (TileType::Content(d_type, _c_id), MenuAction::EditLink) if !d_type.is_link() => {
+                    Some(tr("cmenu.not_a_link"))
+                }
+                (_tile, MenuAction::PasteLink) if !ctx.clipboard_full => {
+                    Some(tr("cmenu.clipboard_empty"))
+                }
+                (_tile, action) if action.changes_data() => ctx.change_denied.clone(),
+                _other => None,
+            };
+            (action, reason)
+        })
+        .collect()
+}
+
+pub fn menu_items(actions: &[(MenuAction, Option<String>)]) -> Vec<MenuItem> {
+    actions
+        .iter()
+        .map(|(action, reason)| match reason {
+            Some(reason) => MenuItem::disabled(action.label(), reason.clone()),
+            None => MenuItem::enabled(action.label()),
+        })
+        .collect()
+}
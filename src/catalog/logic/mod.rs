@@ -1,14 +1,24 @@
-use crate::catalog::tui::{from_catalog_tui_adapter, Notifier};
-use crate::catalog::tui::{serve_catalog_tui, EditorResult};
+mod activity;
+mod chat;
+mod debug;
+mod menu;
+mod neighbor_msg;
+use crate::catalog::tui::{
+    from_catalog_tui_adapter, NoteAction, NoteCmd, Notification, Notifier, Severity,
+};
+use crate::catalog::tui::{serve_catalog_tui, Answer, Column, EditorResult, ListData};
+use crate::commands::Command;
+use crate::common::i18n::{tr, trf};
 // use crate::config::Configuration;
 use animaterm::prelude::*;
 use async_std::channel::Receiver as AReceiver;
 use async_std::channel::Sender as ASender;
 use async_std::channel::{self as achannel};
-use async_std::task::{spawn, spawn_blocking};
+use async_std::task::{sleep, spawn, spawn_blocking};
 use std::sync::mpsc::channel;
 use std::sync::mpsc::Receiver;
 // use async_std::path::Path;
+use crate::{log_debug, log_info, log_warn};
 use dapp_lib::prelude::Description;
 pub use dapp_lib::prelude::Manifest;
 use dapp_lib::prelude::SwarmID;
@@ -18,15 +28,32 @@ use dapp_lib::prelude::*;
 use dapp_lib::ToAppMgr;
 use std::collections::HashMap;
 use std::collections::HashSet;
-// use std::net::IpAddr;
+use std::collections::VecDeque;
+use std::net::{IpAddr, Ipv4Addr};
 // use crate::config::Configuration;
 use std::path::PathBuf;
 use std::sync::mpsc::Sender;
+use std::time::Duration;
+use std::time::Instant;
 // mod manifest;
 use crate::catalog::tui::Direction;
 use crate::catalog::tui::{CreatorResult, FromCatalogView, TileType, ToCatalogView};
+use crate::common::invitation::{decode_invitation, encode_invitation};
+use crate::common::local_address::{listening_ports, local_ip};
+use crate::common::logging::{log_path, recent_problems};
+use crate::config::{
+    add_neighbors, read_autojoin, read_neighbors, read_watch_list, replace_neighbor,
+    write_autojoin, write_session, write_watch_list, NeighborEntry, Session, WatchList,
+};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
 use crate::{Configuration as AppConf, Creator, Editor, Selector, Toolset};
 use crate::{Indexer, InternalMsg};
+use activity::Activity;
+use chat::{ChatAction, ChatKind, ChatMessage, ChatRoom};
+use debug::{experiment_line, is_experiment, EXPERIMENTS};
+use menu::{menu_for, menu_items, MenuAction, MenuContext};
+use neighbor_msg::{request_name, NeighborRequest, NeighborResponse, CHAT_ID, PROTOCOL_VERSION};
+use std::path::Path;
 // pub use manifest::Manifest;
 // pub use manifest::Tag;
 
@@ -166,6 +193,7 @@ enum TuiState {
     Street(Tag, u16),
     AddTag,
     ChangeTag(u8),
+    ConfirmChangeTag(u8, Tag),
     PresentTags,
     ChooseActionForTag(u8, String),
     AddSearch,
@@ -173,8 +201,9 @@ enum TuiState {
     SearchResults(String, bool, Vec<(SwarmName, ContentID)>),
     AddDType,
     RemovePage(ContentID),
+    ConfirmRemovePage(ContentID, u16),
     AppendData(ContentID),
-    ContextMenuOn(TileType),
+    ContextMenuOn(TileType, Vec<MenuAction>),
     Creator(CreatorContext),
     CreatorSelectTags(CreatorContext),
     CreatorDisplayDescription(CreatorContext),
@@ -192,8 +221,24 @@ enum TuiState {
         Vec<String>,
     ),
     ShowActiveSwarms(Vec<(SwarmName, Option<AppType>, SwarmID)>),
+    SwarmActions(Vec<(SwarmName, Option<AppType>, SwarmID)>, usize),
+    ConfirmLeaveSwarm(SwarmName),
     SettingsMenu,
     StorageRulesMenu(Option<usize>, bool),
+    NeighborsMenu(Vec<NeighborEntry>),
+    NeighborActions(Vec<NeighborEntry>, usize),
+    NeighborEdit(Vec<NeighborEntry>, Option<usize>),
+    InvitationMenu,
+    AcceptInvitation,
+    ChatMenu(Vec<ChatAction>),
+    ChatWrite,
+    ChatLog,
+    ActivityFeed,
+    NeighborFriendRequest(GnomeId),
+    NeighborAskContent(GnomeId),
+    DebugPanel,
+    LogViewer,
+    NotificationHistory,
 }
 
 impl TuiState {
@@ -205,6 +250,51 @@ impl TuiState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SwarmStatus {
+    Syncing,
+    Synced,
+    Reconnecting,
+    Disconnected,
+}
+
+// What we know about every swarm we have seen during this session,
+// used by Swarm manager screen
+#[derive(Debug, Clone)]
+struct SwarmStats {
+    swarm_id: SwarmID,
+    app_type: Option<AppType>,
+    neighbors: usize,
+    status: SwarmStatus,
+    // When swarm was last synced, contents reported shortly after
+    // are not considered news in activity feed
+    synced_at: Option<Instant>,
+}
+
+impl SwarmStats {
+    fn new(swarm_id: SwarmID, app_type: Option<AppType>) -> Self {
+        SwarmStats {
+            swarm_id,
+            app_type,
+            neighbors: 0,
+            status: SwarmStatus::Syncing,
+            synced_at: None,
+        }
+    }
+
+    fn mark_synced(&mut self) {
+        self.status = SwarmStatus::Synced;
+        self.synced_at = Some(Instant::now());
+    }
+
+    fn is_settled(&self) -> bool {
+        self.status == SwarmStatus::Synced
+            && self
+                .synced_at
+                .is_some_and(|at| at.elapsed() >= WATCH_SETTLE_TIME)
+    }
+}
+
 pub struct SwarmShell {
     pub swarm_id: SwarmID,
     pub swarm_name: SwarmName,
@@ -339,6 +429,9 @@ pub struct CatalogLogic {
     my_name: SwarmName,
     display_id: usize,
     pub_ips: Vec<NetworkSettings>,
+    // last set of public IPs reported by AppMgr, used to detect changes
+    known_pub_ips: Vec<NetworkSettings>,
+    pub_ips_refresh_stop: Option<ASender<()>>,
     state: TuiState,
     active_swarm: SwarmShell,
     to_app_mgr_send: ASender<ToAppMgr>,
@@ -348,15 +441,49 @@ pub struct CatalogLogic {
     // from_tui_recv: Receiver<FromCatalogView>,
     to_user_send: ASender<InternalMsg>,
     to_app: AReceiver<InternalMsg>,
-    notification_sender: ASender<Option<String>>,
-    pending_notifications: HashMap<SwarmID, Vec<ToApp>>, //TODO: clear pending
-    // when given SwarmID was disconnected
+    notification_sender: ASender<NoteCmd>,
+    // Most recent notifications, newest last
+    notifications: VecDeque<Notification>,
+    notifications_paused: bool,
+    // Messages for swarms that are not active, cleared when given swarm disconnects
+    pending_notifications: HashMap<SwarmID, Vec<ToApp>>,
+    watch_list: WatchList,
+    // Recent changes in watched swarms, newest last
+    activity: VecDeque<Activity>,
+    // Street names of every swarm we have read Manifest of
+    known_tags: HashMap<SwarmID, HashMap<u8, Tag>>,
     visible_streets: (usize, Vec<Tag>), // (how many streets visible at once, visible street names),
     tiles_on_screen: usize,
     home_swarm_enforced: bool,
     buffered_from_tui: Vec<FromCatalogView>,
     clipboard: Option<(SwarmName, ContentID)>,
     storage_rules: Vec<(StorageCondition, StoragePolicy)>,
+    swarm_stats: HashMap<SwarmName, SwarmStats>,
+    // Pinned swarms that we requested to join, but not yet got ActiveSwarm for
+    auto_joining: Vec<SwarmName>,
+    // Swarm joined in background and swarm to switch back to once it is joined
+    return_after_join: Option<(SwarmName, SwarmName)>,
+    // Swarm we got kicked out of, and should return to once it reconnects
+    reenter_after_reconnect: Option<SwarmName>,
+    // Swarms we asked to leave, but not yet got Disconnected for
+    leaving: Vec<SwarmName>,
+    // All swarms got disconnected and none is back yet
+    offline: bool,
+    // nonce => (swarm, neighbor, request id, when sent)
+    pending_neighbor_requests: HashMap<u32, (SwarmID, GnomeId, u8, Instant)>,
+    chats: HashMap<SwarmID, ChatRoom>,
+    // nonce => (address we try to connect with, swarm, it's neighbors count before)
+    connect_attempt: Option<(u32, NeighborEntry, SwarmID, usize)>,
+    next_nonce: u32,
+    // Test keys and debug panel are only served in developer mode
+    dev_mode: bool,
+    keymap: Keymap,
+    // Last session, waiting for it's swarm to become active
+    session: Option<Session>,
+    // Last session, waiting for contents of it's swarm,
+    // dropped once that swarm is synced or user does anything
+    restoring: Option<Session>,
+    save_session: bool,
 }
 impl CatalogLogic {
     pub fn new(
@@ -393,6 +520,8 @@ impl CatalogLogic {
             my_name,
             display_id,
             pub_ips: vec![],
+            known_pub_ips: vec![],
+            pub_ips_refresh_stop: None,
             state: TuiState::MainSt,
             active_swarm: SwarmShell::new(
                 SwarmID(0),
@@ -410,17 +539,42 @@ impl CatalogLogic {
             to_user_send,
             to_app: to_user_recv,
             pending_notifications: HashMap::new(),
+            watch_list: WatchList::default(),
+            activity: VecDeque::with_capacity(ACTIVITY_FEED),
+            known_tags: HashMap::new(),
             visible_streets: (0, vec![]),
             tiles_on_screen: 20, // just a number it should get updated from presentation msg
             home_swarm_enforced: false,
             buffered_from_tui: vec![],
             notification_sender,
+            notifications: VecDeque::with_capacity(NOTIFICATION_HISTORY),
+            notifications_paused: false,
             clipboard: None,
             storage_rules: vec![(StorageCondition::Default, StoragePolicy::Forget)],
+            swarm_stats: HashMap::new(),
+            auto_joining: vec![],
+            return_after_join: None,
+            reenter_after_reconnect: None,
+            leaving: vec![],
+            offline: false,
+            pending_neighbor_requests: HashMap::new(),
+            chats: HashMap::new(),
+            connect_attempt: None,
+            next_nonce: 0,
+            dev_mode: false,
+            keymap: Keymap::new(),
+            session: None,
+            restoring: None,
+            save_session: false,
             //TODO: read storage_rules from config file
             // rule with index 0 is always there as a template for adding new rules
         }
     }
+    // Bring back street, tile or Forum topic we were at on last exit,
+    // once given swarm becomes active
+    pub fn restore_session(&mut self, session: Session) {
+        self.session = Some(session);
+    }
     pub async fn run(
         mut self,
         config_dir: PathBuf,
@@ -447,7 +601,12 @@ impl CatalogLogic {
             let mut datastore_rules = read_storage_rules_from_file(storage_rules_file_path);
             self.storage_rules.append(&mut datastore_rules);
         }
-        let (mut tui_mgr, config, e_opt, c_opt, s_opt, i_opt, _pe_opt) = toolset.unfold();
+        let (mut tui_mgr, mut config, e_opt, c_opt, s_opt, i_opt, _pe_opt) = toolset.unfold();
+        let keymap_problems = config.keymap.take_problems();
+        self.dev_mode = config.dev_mode;
+        self.keymap = config.keymap.clone();
+        self.save_session = config.restore_session;
+        self.watch_list = read_watch_list(&config_dir);
         let mut return_val = None;
         let (cols, rows) = tui_mgr.screen_size();
         let frame = vec![Glyph::plain(); cols * rows];
@@ -455,7 +614,7 @@ impl CatalogLogic {
         library.insert(0, frame);
         let bg = Graphic::new(cols, rows, 0, library, None);
         let res = tui_mgr.add_graphic(bg, 1, (0, 0));
-        eprintln!("TUI background index: {:?}", res);
+        log_debug!("TUI background index: {:?}", res);
         // TODO: notifier should send internal message
         // so that switching between apps  should be seamless.
         // That internal massage should be served by each app's
@@ -499,30 +658,47 @@ impl CatalogLogic {
             )
         });
 
+        let (stop_send, stop_recv) = achannel::bounded(1);
+        spawn(refresh_public_ips(
+            self.to_app_mgr_send.clone(),
+            stop_recv,
+            PUB_IPS_REFRESH_PERIOD,
+        ));
+        self.pub_ips_refresh_stop = Some(stop_send);
+        for problem in keymap_problems {
+            self.notify(
+                Severity::Warning,
+                trf("common.keymap_problem", &[&problem]),
+                Some(NoteAction::ShowLog),
+            )
+            .await;
+        }
+
         // TODO: move above inside CatalogLogic::new
         'outer: loop {
             while let Ok(internal_msg) = self.to_app.recv().await {
+                if let InternalMsg::Catalog(from_tui) = &internal_msg {
+                    if from_tui.is_navigation() && self.restoring.take().is_some() {
+                        log_info!("User went on, last session will not be restored");
+                    }
+                }
                 match internal_msg {
                     InternalMsg::User(msg) => match msg {
                         ToApp::SearchQueries(phrases) => {
                             if phrases.is_empty() {
                                 continue;
                             }
-                            eprintln!("Received list of Search queries:\n {:?}", phrases);
-                            let mut formated_phrases = Vec::with_capacity(phrases.len());
-                            let mut indices = Vec::with_capacity(phrases.len());
-                            let mut idx = 0;
+                            log_debug!("Received list of Search queries:\n {:?}", phrases);
+                            let mut searches = ListData::new(vec![
+                                Column::text(tr("column.phrase"), 0),
+                                Column::number(tr("column.results"), 8),
+                            ]);
                             for (p, h) in &phrases {
-                                formated_phrases.push(format!("{}: {}", p, h));
-                                indices.push(idx);
-                                idx += 1;
+                                searches.push(vec![p.clone(), h.to_string()]);
                             }
-                            let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(
-                                // true,
-                                // "Active Searches".to_string(),
-                                formated_phrases,
-                                // indices,
-                            ));
+                            let _ = self
+                                .to_tui
+                                .send(ToCatalogView::DisplayList(tr("search.active"), searches));
                             self.state = TuiState::ListSearches(phrases);
                         }
                         ToApp::SearchResults(phrase, is_permanent, hits) => {
@@ -535,17 +711,28 @@ impl CatalogLogic {
                             // every time we change this value, search engine should
                             // be notified
                             let mut links = Vec::with_capacity(hits.len());
-                            let mut texts = Vec::with_capacity(hits.len());
-                            texts.push(format!("Is permanent: {}", is_permanent));
+                            let mut results = ListData::new(vec![
+                                Column::text(tr("column.swarm"), 0),
+                                Column::number(tr("column.content"), 8),
+                                Column::number(tr("column.score"), 8),
+                            ]);
+                            results.pin(vec![trf("search.is_permanent", &[&is_permanent])]);
                             links.push((SwarmName::new(GnomeId::any(), String::new()).unwrap(), 0));
                             for Hit(s_name, c_id, score) in &hits {
-                                texts.push(format!("{}-{}: {}", s_name, c_id, score));
+                                results.push(vec![
+                                    s_name.to_string(),
+                                    c_id.to_string(),
+                                    score.to_string(),
+                                ]);
                                 links.push((s_name.clone(), *c_id));
                             }
-                            let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(texts));
+                            let _ = self.to_tui.send(ToCatalogView::DisplayList(
+                                trf("search.results_for", &[&phrase]),
+                                results,
+                            ));
                             // TODO: SearchResults should contain an id of search in order
                             // to easily send a notification to Search engine
-                            eprintln!("Search results for {}{:?}", phrase, hits);
+                            log_debug!("Search results for {}{:?}", phrase, hits);
                             self.state = TuiState::SearchResults(phrase, is_permanent, links);
                         }
                         ToApp::AllNeighborsGone => {
@@ -557,7 +744,40 @@ impl CatalogLogic {
                                 .await;
                         }
                         ToApp::ActiveSwarm(s_name, s_id) => {
-                            eprintln!("Requesting Manifest");
+                            let stats = self
+                                .swarm_stats
+                                .entry(s_name.clone())
+                                .or_insert(SwarmStats::new(s_id, None));
+                            stats.swarm_id = s_id;
+                            if self.offline {
+                                self.offline = false;
+                                let _ = self.to_tui.send(ToCatalogView::Offline(false));
+                            }
+                            // It is synced once it provides first pages
+                            if stats.status != SwarmStatus::Synced {
+                                stats.status = SwarmStatus::Syncing;
+                            }
+                            if let Some(pos) = self.auto_joining.iter().position(|s| *s == s_name) {
+                                // We only wanted to join it, not to look at it
+                                log_info!("Auto-joined {}", s_name);
+                                self.auto_joining.remove(pos);
+                                let _ = self
+                                    .to_app_mgr_send
+                                    .send(ToAppMgr::FromApp(LibRequest::ReadFirstPages(s_id, None)))
+                                    .await;
+                                if let Some((joined, back)) = self.return_after_join.take() {
+                                    if joined == s_name {
+                                        let _ = self
+                                            .to_app_mgr_send
+                                            .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(back)))
+                                            .await;
+                                    } else {
+                                        self.return_after_join = Some((joined, back));
+                                    }
+                                }
+                                continue;
+                            }
+                            log_debug!("Requesting Manifest");
                             let _ = self
                                 .to_app_mgr_send
                                 .send(ToAppMgr::FromApp(LibRequest::ReadAllPages(s_id, 0)))
@@ -586,18 +806,19 @@ impl CatalogLogic {
                                         .to_tui
                                         .send(ToCatalogView::SwapTiles(target_name.founder));
                                 } else {
-                                    eprintln!(
+                                    log_debug!(
                                         "Had to follow a link, but switched to a different swarm"
                                     );
                                 }
                             }
                             if !self.home_swarm_enforced {
-                                // TODO: build some logic to periodically ask for IPs
+                                // refresh_public_ips task keeps asking periodically
                                 let _ = self
                                     .to_app_mgr_send
                                     .send(ToAppMgr::FromApp(LibRequest::ProvidePublicIPs))
                                     .await;
                                 self.home_swarm_enforced = true;
+                                self.join_pinned_swarms(&config_dir).await;
                                 for msg in self.buffered_from_tui.iter_mut() {
                                     // let _ = self.from_tui_send.send(msg.clone());
                                     let _ = self
@@ -606,7 +827,7 @@ impl CatalogLogic {
                                         .await;
                                 }
                             }
-                            eprintln!("Set active {}", s_id);
+                            log_debug!("Set active {}", s_id);
                             self.active_swarm = SwarmShell::new(s_id, s_name, AppType::Catalog);
                             if let Some(pending_notifications) = self
                                 .pending_notifications
@@ -620,50 +841,113 @@ impl CatalogLogic {
                                 .to_app_mgr_send
                                 .send(ToAppMgr::FromApp(LibRequest::ReadAllPages(s_id, 0)))
                                 .await;
-                            eprintln!("Requested manifest for {s_id}");
+                            log_debug!("Requested manifest for {s_id}");
                             let _ = self.to_app_mgr_send.send(ToAppMgr::ListNeighbors).await;
-                            eprintln!("Requested Neighbors for {s_id}");
+                            log_debug!("Requested Neighbors for {s_id}");
                             let _ = self
                                 .to_app_mgr_send
                                 .send(ToAppMgr::FromApp(LibRequest::ReadFirstPages(s_id, None)))
                                 .await;
-                            eprintln!("Requested all first pages for {s_id}");
+                            log_debug!("Requested all first pages for {s_id}");
+                            // Pinned swarms are joined first, we restore once we are back
+                            let session_active = self.auto_joining.is_empty()
+                                && self
+                                    .session
+                                    .as_ref()
+                                    .is_some_and(|s| s.swarm == self.active_swarm.swarm_name);
+                            if session_active {
+                                if let Some(session) = self.session.take() {
+                                    if matches!(session.app_type, AppType::Forum) {
+                                        log_info!("Restoring Forum {}", session.swarm);
+                                        let _ = self.to_tui.send(ToCatalogView::Quit);
+                                        return_val = Some((AppType::Forum, s_id, session.swarm));
+                                        break 'outer;
+                                    }
+                                    // Street is shown once we know what is on it
+                                    self.restoring = Some(session);
+                                }
+                            }
                         }
                         ToApp::MyPublicIPs(ip_list) => {
-                            //TODO: push this to my swarm's Manifest after syncing
-                            eprintln!("\nApplication got Pub IPs:\n{:?}\n", ip_list);
-                            for ip_tuple in ip_list {
-                                if !self.pub_ips.contains(&ip_tuple) {
-                                    self.pub_ips.push(ip_tuple);
-                                }
+                            log_debug!("\nApplication got Pub IPs:\n{:?}\n", ip_list);
+                            if ip_list.is_empty() || ip_list == self.known_pub_ips {
+                                continue;
+                            }
+                            log_debug!("Our public IPs have changed");
+                            self.known_pub_ips = ip_list.clone();
+                            // Those get pushed to our Manifest once it is read
+                            self.pub_ips = ip_list;
+                            if self.my_name == self.active_swarm.swarm_name {
+                                let _ = self
+                                    .to_app_mgr_send
+                                    .send(ToAppMgr::FromApp(LibRequest::ReadAllPages(
+                                        self.active_swarm.swarm_id,
+                                        0,
+                                    )))
+                                    .await;
                             }
                         }
                         ToApp::NameToIDMapping(mapping) => {
-                            eprintln!("User got mapping: {:?}", mapping);
+                            log_debug!("User got mapping: {:?}", mapping);
                             // TODO: show after mgr responds with a list
-                            if mapping.is_empty() {
-                                //TODO: display a notification
-                                // that there are no synced swarms available
-                                self.state = TuiState::MainSt;
-                                continue;
+                            let all_disconnected = mapping.is_empty();
+                            // Swarms not mapped are no longer running
+                            for (s_name, stats) in self.swarm_stats.iter_mut() {
+                                if !mapping.iter().any(|(n, _v)| n == s_name) {
+                                    stats.neighbors = 0;
+                                    stats.synced_at = None;
+                                    stats.status = SwarmStatus::Disconnected;
+                                }
                             }
                             let mut map_vec = Vec::with_capacity(mapping.len());
-                            let mut name_vec = Vec::with_capacity(mapping.len());
                             for (s_name, (s_id, a_type)) in mapping {
-                                map_vec.push((s_name.clone(), a_type, s_id));
-                                name_vec.push(format!("{:?} SID {}: {}", a_type, s_id.0, s_name));
+                                let stats = self
+                                    .swarm_stats
+                                    .entry(s_name.clone())
+                                    .or_insert(SwarmStats::new(s_id, a_type));
+                                stats.swarm_id = s_id;
+                                stats.app_type = a_type;
+                                map_vec.push((s_name, a_type, s_id));
+                            }
+                            // Disconnected swarms are no longer mapped,
+                            // but we still want to be able to reconnect them
+                            for (s_name, stats) in &self.swarm_stats {
+                                if stats.status == SwarmStatus::Disconnected
+                                    && !map_vec.iter().any(|(n, _a, _i)| n == s_name)
+                                {
+                                    map_vec.push((s_name.clone(), stats.app_type, stats.swarm_id));
+                                }
+                            }
+                            map_vec.sort_by(|a, b| a.2 .0.cmp(&b.2 .0));
+                            if all_disconnected {
+                                self.all_swarms_disconnected().await;
+                                if map_vec.is_empty() {
+                                    continue;
+                                }
+                            }
+                            let mut swarms = ListData::new(vec![
+                                Column::number(tr("column.sid"), 5),
+                                Column::text(tr("column.swarm"), 0),
+                                Column::text(tr("column.app"), 9),
+                                Column::text(tr("column.role"), 11),
+                                Column::number(tr("column.neighbors"), 5),
+                                Column::text(tr("column.status"), 12),
+                                Column::text(tr("column.storage"), 14),
+                            ]);
+                            for (s_name, _a, _i) in &map_vec {
+                                swarms.push(self.swarm_row(s_name));
                             }
                             self.state = TuiState::ShowActiveSwarms(map_vec);
-                            self.show_active_swarms(name_vec);
+                            self.show_active_swarms(swarms);
                         }
                         // TODO: move this logic into AppData
                         ToApp::GetCIDsForTags(s_id, n_id, tags, all_first_pages) => {
-                            eprintln!("App received request for tags: {:?}", tags);
+                            log_debug!("App received request for tags: {:?}", tags);
                             for (c_id, data) in all_first_pages {
                                 let mut tags_iter = data.ref_bytes().iter();
                                 // TODO: Does it work for current Link byte structure?
                                 let tags_count = tags_iter.next().unwrap();
-                                eprintln!("App GetCIDsForTags CID-{c_id}: Tags #{tags_count}");
+                                log_debug!("App GetCIDsForTags CID-{c_id}: Tags #{tags_count}");
                                 if *tags_count == 0 {
                                     continue;
                                 }
@@ -682,8 +966,20 @@ impl CatalogLogic {
                             }
                         }
                         ToApp::Neighbors(s_id, neighbors) => {
+                            if let Some(stats) = self.stats_for_id(s_id) {
+                                stats.neighbors = neighbors.len();
+                            }
+                            self.chat_room(s_id).neighbors = neighbors.clone();
+                            self.check_connect_attempt(s_id, neighbors.len()).await;
+                            if let Some(s_name) = self.mark_reconnected(s_id, neighbors.len()) {
+                                let _ = self
+                                    .to_app_mgr_send
+                                    .send(ToAppMgr::FromApp(LibRequest::ReadFirstPages(s_id, None)))
+                                    .await;
+                                self.swarm_reconnected(s_name).await;
+                            }
                             if !self.home_swarm_enforced {
-                                eprintln!("!home_swarm_enforced");
+                                log_debug!("!home_swarm_enforced");
                                 // let _ = self
                                 //     .to_app_mgr_send
                                 //     .send(ToAppMgr::SetActiveApp(self.my_name.clone()))
@@ -697,7 +993,7 @@ impl CatalogLogic {
                             if s_id == self.active_swarm.swarm_id {
                                 let mut new_neighbors =
                                     if self.my_name == self.active_swarm.swarm_name {
-                                        eprintln!("App got neighbors: {:?}", neighbors);
+                                        log_debug!("App got neighbors: {:?}", neighbors);
                                         // if !neighbors.is_empty() {
                                         //     // eprintln!("{} Sending Neighbors0: {:?}", s_id, neighbors);
                                         //     let _ =
@@ -709,7 +1005,7 @@ impl CatalogLogic {
                                         let mut new_neighbors = vec![self.my_name.founder];
                                         for n in neighbors {
                                             if n == self.active_swarm.swarm_name.founder {
-                                                eprintln!("Removing {} from neighbors list", n);
+                                                log_debug!("Removing {} from neighbors list", n);
                                                 continue;
                                             }
                                             new_neighbors.push(n);
@@ -750,9 +1046,10 @@ impl CatalogLogic {
                                         .send(ToCatalogView::Neighbors(new_neighbors, false));
                                 }
                             } else {
-                                eprintln!(
+                                log_debug!(
                                     "Not sending neighbors, because my {} != {}",
-                                    self.active_swarm.swarm_id, s_id
+                                    self.active_swarm.swarm_id,
+                                    s_id
                                 );
                                 self.pending_notifications
                                     .entry(s_id)
@@ -761,6 +1058,10 @@ impl CatalogLogic {
                             }
                         }
                         ToApp::NeighborLeft(s_id, n_id) => {
+                            if let Some(stats) = self.stats_for_id(s_id) {
+                                stats.neighbors = stats.neighbors.saturating_sub(1);
+                            }
+                            self.chat_neighbor_left(s_id, n_id).await;
                             if !self.home_swarm_enforced {
                                 // eprintln!("!home_swarm_enforced");
                                 // let _ = self
@@ -774,10 +1075,10 @@ impl CatalogLogic {
                                 continue;
                             }
                             if s_id == self.active_swarm.swarm_id {
-                                eprintln!("Neighbor left: {}", n_id);
+                                log_debug!("Neighbor left: {}", n_id);
                                 let _ = self.to_tui.send(ToCatalogView::NeighborLeft(n_id));
                             } else {
-                                eprintln!("Neighbor left: {} for swarm {}", n_id, s_id);
+                                log_debug!("Neighbor left: {} for swarm {}", n_id, s_id);
                             }
                         }
                         ToApp::NewContent(s_id, c_id, d_type, main_page) => {
@@ -787,11 +1088,22 @@ impl CatalogLogic {
                             }
                             if s_id == self.active_swarm.swarm_id && self.home_swarm_enforced {
                                 self.update_active_content_tags(s_id, c_id, d_type, main_page);
+                                self.resume_session().await;
                             } else {
-                                eprintln!(
+                                log_debug!(
                                     "Not sending new content, because my {} != {}( home swarm enforced: {})",
                                     self.active_swarm.swarm_id, s_id,self.home_swarm_enforced
                                 );
+                                if s_id != self.active_swarm.swarm_id {
+                                    self.watch_activity(
+                                        s_id,
+                                        c_id,
+                                        d_type,
+                                        Some(main_page.clone()),
+                                        true,
+                                    )
+                                    .await;
+                                }
                                 self.pending_notifications
                                     .entry(s_id)
                                     .or_insert(vec![])
@@ -799,10 +1111,10 @@ impl CatalogLogic {
                             }
                         }
                         ToApp::ContentChanged(s_id, c_id, d_type, main_page_option) => {
-                            eprintln!("recv ToApp::ContentChanged({:?})", c_id);
+                            log_debug!("recv ToApp::ContentChanged({:?})", c_id);
                             let mut data_requested = false;
                             if c_id == 0 {
-                                eprintln!("Requesting ReadData for CID-0");
+                                log_debug!("Requesting ReadData for CID-0");
                                 data_requested = true;
                                 let _ = self
                                     .to_app_mgr_send
@@ -812,7 +1124,7 @@ impl CatalogLogic {
                             if s_id == self.active_swarm.swarm_id && self.home_swarm_enforced {
                                 if let Some(main_page) = main_page_option {
                                     if main_page.is_empty() && main_page.get_hash() > 0 {
-                                        eprintln!("We should check if Tags have changed");
+                                        log_debug!("We should check if Tags have changed");
                                         if !data_requested {
                                             // data_requested = true;
                                             let _ = self
@@ -829,14 +1141,25 @@ impl CatalogLogic {
                                     }
                                 }
                             } else {
-                                eprintln!(
+                                log_debug!(
                                     "Not sending changed content, because my {:?} != {:?}",
-                                    self.active_swarm.swarm_id, s_id
+                                    self.active_swarm.swarm_id,
+                                    s_id
                                 );
+                                if s_id != self.active_swarm.swarm_id {
+                                    self.watch_activity(
+                                        s_id,
+                                        c_id,
+                                        d_type,
+                                        main_page_option,
+                                        false,
+                                    )
+                                    .await;
+                                }
                             }
                         }
                         ToApp::ReadSuccess(s_id, s_name, c_id, d_type, start_page, d_vec) => {
-                            eprintln!(
+                            log_debug!(
                                 "Received ReadSuccess {} CID-{} (len: {})",
                                 s_id,
                                 c_id,
@@ -858,7 +1181,7 @@ impl CatalogLogic {
                                 //     //TODO
                                 //     eprintln!("Should show this data, without switching swarm");
                                 // } else {
-                                eprintln!(
+                                log_debug!(
                                     "shelving it (active swarm: {:?})",
                                     self.active_swarm.swarm_id
                                 );
@@ -872,10 +1195,15 @@ impl CatalogLogic {
                             }
                         }
                         ToApp::ReadInProgress(s_id, c_id) => {
-                            let _ = self
-                                .notification_sender
-                                .send(Some(format!("{} {} Read in progress…", s_id, c_id)))
-                                .await;
+                            let action = self
+                                .swarm_name_for(s_id)
+                                .map(|s_name| NoteAction::OpenContent(s_name, c_id));
+                            self.notify(
+                                Severity::Info,
+                                trf("content.read_in_progress", &[&s_id, &c_id]),
+                                action,
+                            )
+                            .await;
                         }
                         ToApp::ReadError(s_id, c_id, error) => {
                             // eprintln!("Received ReadError for {} CID-{}: {}", s_id, c_id, error);
@@ -896,15 +1224,28 @@ impl CatalogLogic {
                             // }
                         }
                         ToApp::FirstPages(s_id, first_pages) => {
+                            // Library provides first pages once swarm's
+                            // datastore is in sync with it's neighbors
+                            if let Some(stats) = self.stats_for_id(s_id) {
+                                if stats.status != SwarmStatus::Synced {
+                                    stats.mark_synced();
+                                }
+                            }
                             //TODO: we need to store this information in SwarmShell
                             // we also need to know what Contents are already displayed
                             // and we should only send ToPresentation those contents
                             // that are not already visible, but should be
                             if s_id == self.active_swarm.swarm_id {
-                                eprintln!("App received first pages: {}", first_pages.len());
+                                log_debug!("App received first pages: {}", first_pages.len());
                                 for (c_id, d_type, main_page) in first_pages {
                                     self.update_active_content_tags(s_id, c_id, d_type, main_page);
                                 }
+                                self.resume_session().await;
+                                // Swarm is in sync, so street of last session
+                                // is not going to show up any more
+                                if self.restoring.take().is_some() {
+                                    log_info!("Street of last session is gone, not restoring it");
+                                }
                             } else {
                                 self.pending_notifications
                                     .entry(s_id)
@@ -913,34 +1254,41 @@ impl CatalogLogic {
                             }
                         }
                         ToApp::Disconnected(is_reconnecting, s_id, s_name) => {
-                            self.swarm_disconnected(is_reconnecting, s_id, s_name).await
+                            let left = !is_reconnecting && self.leaving.contains(&s_name);
+                            self.swarm_disconnected(is_reconnecting, s_id, s_name.clone())
+                                .await;
+                            if left {
+                                self.swarm_left(s_name).await;
+                            }
                             // TODO: maybe later we can allow offline read-only mode
                             // but for now we need to implement something simple
                             //
                         }
                         ToApp::HeapData(_s_id, app_msg, _singed_by) => {
-                            eprintln!("Catalog recv HeapData({})", app_msg.m_type());
+                            log_debug!("Catalog recv HeapData({})", app_msg.m_type());
                         }
-                        ToApp::CustomNeighborRequest(_s_id, _g_id, req_id, _c_data) => {
-                            //TODO
-                            eprintln!("Catalog: CustomNeighborRequest({req_id})");
+                        ToApp::CustomNeighborRequest(s_id, g_id, req_id, c_data) => {
+                            log_debug!("Catalog: CustomNeighborRequest({req_id})");
+                            self.serve_neighbor_request(s_id, g_id, req_id, c_data.bytes())
+                                .await;
                         }
-                        ToApp::CustomNeighborResponse(_s_id, _g_id, resp_id, _c_data) => {
-                            //TODO
-                            eprintln!("Catalog: CustomNeighborResponse({resp_id})");
+                        ToApp::CustomNeighborResponse(_s_id, g_id, resp_id, c_data) => {
+                            log_debug!("Catalog: CustomNeighborResponse({resp_id})");
+                            self.serve_neighbor_response(g_id, resp_id, c_data.bytes())
+                                .await;
                         }
                         ToApp::Quit => {
-                            eprintln!("Done serving ApplicationLogic");
+                            log_debug!("Done serving ApplicationLogic");
                             break 'outer;
                         }
                         other => {
-                            eprintln!("Unhandled msg: {:?}", other);
+                            log_debug!("Unhandled msg: {:?}", other);
                         }
                     },
                     InternalMsg::Catalog(from_tui) => match from_tui {
                         FromCatalogView::VisibleStreetsCountAndMaxTiles(v_streets, tiles_max) => {
                             //TODO: store this in order to know what information to send
-                            eprintln!("OK we see {} streets at once", v_streets);
+                            log_debug!("OK we see {} streets at once", v_streets);
                             self.visible_streets.0 = v_streets as usize;
                             self.tiles_on_screen = tiles_max;
                         }
@@ -953,7 +1301,7 @@ impl CatalogLogic {
                                     }
                                 }
                                 TileType::Neighbor(g_id) => {
-                                    eprintln!("NeighborSelected");
+                                    log_debug!("NeighborSelected");
                                     let _ = self
                                         .to_app_mgr_send
                                         .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(
@@ -973,11 +1321,11 @@ impl CatalogLogic {
                                 }
                                 TileType::Content(dtype, c_id) => {
                                     if dtype.is_link() {
-                                        eprintln!("About to follow a link");
+                                        log_debug!("About to follow a link");
                                         self.follow_link(c_id).await;
                                     } else {
                                         self.query_content_for_indexer(c_id).await;
-                                        eprintln!("About to show CID {} dtype: {:?}", c_id, dtype);
+                                        log_debug!("About to show CID {} dtype: {:?}", c_id, dtype);
                                     }
                                 }
                             }
@@ -989,13 +1337,13 @@ impl CatalogLogic {
                                     let curr_state = self.state.clone();
                                     match curr_state {
                                         TuiState::MainSt => {
-                                            eprintln!("We should draw new streets on screen UP");
+                                            log_debug!("We should draw new streets on screen UP");
                                             if self.active_swarm.tag_ring.len() > 1 {
                                                 let street_names =
                                                     self.street_names_with_content(true);
                                                 // DONE: present a no-tag street only if it has contents
                                                 self.visible_streets.1 = street_names.clone();
-                                                eprintln!(
+                                                log_debug!(
                                                     "Sending StreetNames: {:?}",
                                                     street_names
                                                 );
@@ -1017,11 +1365,11 @@ impl CatalogLogic {
                                             //TODO
                                             new_state =
                                                 self.present_neighbors(pg_id.wrapping_sub(1)).await;
-                                            eprintln!("NeighborSt #{}", pg_id.wrapping_sub(1));
+                                            log_debug!("NeighborSt #{}", pg_id.wrapping_sub(1));
                                         }
                                         TuiState::Street(name, pg_id) => {
                                             //TODO
-                                            eprintln!(
+                                            log_debug!(
                                                 "UP {name:?} St. #{}, MAX:{}",
                                                 pg_id.wrapping_sub(1),
                                                 u16::MAX
@@ -1031,7 +1379,7 @@ impl CatalogLogic {
                                                 .await;
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_debug!(
                                                 "Got out of screen when in state: {:?}",
                                                 other
                                             );
@@ -1039,7 +1387,7 @@ impl CatalogLogic {
                                         }
                                     }
                                     if let Some(state) = new_state {
-                                        eprintln!("UP New state: {state:?}");
+                                        log_debug!("UP New state: {state:?}");
                                         self.state = state;
                                     }
                                 }
@@ -1048,13 +1396,13 @@ impl CatalogLogic {
                                     let curr_state = self.state.clone();
                                     match curr_state {
                                         TuiState::MainSt => {
-                                            eprintln!("We should draw new streets on screen DOWN");
+                                            log_debug!("We should draw new streets on screen DOWN");
                                             if self.active_swarm.tag_ring.len() > 1 {
                                                 let street_names =
                                                     self.street_names_with_content(false);
 
                                                 self.visible_streets.1 = street_names.clone();
-                                                eprintln!(
+                                                log_debug!(
                                                     "Sending StreetNames: {:?}",
                                                     street_names
                                                 );
@@ -1080,17 +1428,17 @@ impl CatalogLogic {
                                             // ));
                                             new_state =
                                                 self.present_neighbors(pg_id.wrapping_add(1)).await;
-                                            eprintln!("NeighborSt #{}", pg_id.wrapping_add(1));
+                                            log_debug!("NeighborSt #{}", pg_id.wrapping_add(1));
                                         }
                                         TuiState::Street(name, pg_id) => {
                                             //TODO
-                                            eprintln!("{name:?} St. #{}", pg_id.wrapping_add(1));
+                                            log_debug!("{name:?} St. #{}", pg_id.wrapping_add(1));
                                             new_state = self
                                                 .present_street(name, pg_id.wrapping_add(1))
                                                 .await;
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_debug!(
                                                 "Got out of screen when in state: {:?}",
                                                 other
                                             );
@@ -1098,7 +1446,7 @@ impl CatalogLogic {
                                         }
                                     }
                                     if let Some(state) = new_state {
-                                        eprintln!("DOWN New state: {state:?}");
+                                        log_debug!("DOWN New state: {state:?}");
                                         self.state = state;
                                     }
                                 }
@@ -1140,7 +1488,7 @@ impl CatalogLogic {
                                                 } else {
                                                     self.active_swarm.tag_ring[0].clone()
                                                 };
-                                            eprintln!("Street names: {street_names:?}");
+                                            log_debug!("Street names: {street_names:?}");
                                             let idx = (row >> 1) as usize;
                                             let s_len = street_names.len();
                                             let st_name = if idx < s_len {
@@ -1160,7 +1508,7 @@ impl CatalogLogic {
                                             self.present_main_street().await;
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_debug!(
                                                 "Got out of screen when in state: {:?}",
                                                 other
                                             );
@@ -1168,14 +1516,14 @@ impl CatalogLogic {
                                         }
                                     }
                                     if let Some(state) = new_state {
-                                        eprintln!("LEFT New state: {state:?}");
+                                        log_debug!("LEFT New state: {state:?}");
                                         self.state = state;
                                     }
                                 }
                                 Direction::Right => {
                                     //TODO: similar to going to the left, but now we cycle in other
                                     // direction.
-                                    eprintln!("R:{row}, C:{col}");
+                                    log_debug!("R:{row}, C:{col}");
                                     let new_state;
                                     let curr_state = self.state.clone();
                                     // std::mem::replace(&mut self.state, TuiState::MainSt);
@@ -1187,7 +1535,7 @@ impl CatalogLogic {
                                                 } else {
                                                     self.active_swarm.tag_ring[0].clone()
                                                 };
-                                            eprintln!("Street names: {street_names:?}");
+                                            log_debug!("Street names: {street_names:?}");
                                             let idx = (row >> 1) as usize;
                                             let s_len = street_names.len();
                                             let st_name = if idx < s_len {
@@ -1211,7 +1559,7 @@ impl CatalogLogic {
                                             new_state = self.present_neighbors(0).await;
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_debug!(
                                                 "Got out of screen when in state: {:?}",
                                                 other
                                             );
@@ -1219,7 +1567,7 @@ impl CatalogLogic {
                                         }
                                     }
                                     if let Some(state) = new_state {
-                                        eprintln!("RIGHT New state: {state:?}");
+                                        log_debug!("RIGHT New state: {state:?}");
                                         self.state = state;
                                     }
                                 }
@@ -1227,7 +1575,7 @@ impl CatalogLogic {
                         }
                         FromCatalogView::CreateContent(d_type, data) => {
                             if !self.home_swarm_enforced {
-                                eprintln!("Push back AppendContent");
+                                log_debug!("Push back AppendContent");
                                 self.buffered_from_tui
                                     .push(FromCatalogView::CreateContent(d_type, data));
                                 continue;
@@ -1235,7 +1583,7 @@ impl CatalogLogic {
                             //TODO: we need to sync this data with Swarm
                             //TODO: we need to create logic that converts user data like String
                             //      into SyncData|CastData before we can send it to Swarm
-                            eprintln!("Requesting AppendContent {}", data.get_hash());
+                            log_debug!("Requesting AppendContent {}", data.get_hash());
                             let _ = self
                                 .to_app_mgr_send
                                 .send(ToAppMgr::AppendContent(
@@ -1247,12 +1595,12 @@ impl CatalogLogic {
                         }
                         FromCatalogView::UpdateContent(c_id, d_type, d_id, data) => {
                             if !self.home_swarm_enforced {
-                                eprintln!("Push back AppendContent");
+                                log_debug!("Push back AppendContent");
                                 self.buffered_from_tui
                                     .push(FromCatalogView::UpdateContent(c_id, d_type, d_id, data));
                                 continue;
                             }
-                            eprintln!("Requesting ChangeContent");
+                            log_debug!("Requesting ChangeContent");
                             let _ = self
                                 .to_app_mgr_send
                                 .send(ToAppMgr::ChangeContent(
@@ -1264,7 +1612,7 @@ impl CatalogLogic {
                                 .await;
                         }
                         FromCatalogView::AddTags(tags) => {
-                            eprintln!("Received FromPresentation::AddTags({:?})", tags);
+                            log_debug!("Received FromPresentation::AddTags({:?})", tags);
                             // TODO: first check if we can add a tag for given swarm
                             // and also check if given tag is not already added
                             //TODO: we need to temporarily add given tag to manifest,
@@ -1286,7 +1634,7 @@ impl CatalogLogic {
                                 // and instead send a list of Data objects to update/create
                                 let data_vec = self.active_swarm.manifest.to_data();
                                 // TODO: this is not the data we want to send!
-                                eprintln!("app logic received add tags request,\nsending change content to app mgr…");
+                                log_debug!("app logic received add tags request,\nsending change content to app mgr…");
                                 let _ = self
                                     .to_app_mgr_send
                                     .send(ToAppMgr::ChangeContent(
@@ -1299,7 +1647,7 @@ impl CatalogLogic {
                             }
                         }
                         FromCatalogView::ChangeTag(tag_id, tag) => {
-                            eprintln!("Received FromPresentation::ChangeTag({tag_id},{tag:?})",);
+                            log_debug!("Received FromPresentation::ChangeTag({tag_id},{tag:?})",);
                             // DONE: we need to read existing text for a Tag that is being changed,
                             // and update it within active_starm.tag_to_cid.
                             // Preferably we should clone it so in case update fails, we have
@@ -1331,7 +1679,7 @@ impl CatalogLogic {
                                 // and instead send a list of Data objects to update/create
                                 let data_vec = self.active_swarm.manifest.to_data();
                                 // TODO: this is not the data we want to send!
-                                eprintln!("app logic received change tag request,\nsending change content to app mgr…");
+                                log_debug!("app logic received change tag request,\nsending change content to app mgr…");
                                 let _ = self
                                     .to_app_mgr_send
                                     .send(ToAppMgr::ChangeContent(
@@ -1344,7 +1692,7 @@ impl CatalogLogic {
                             }
                         }
                         FromCatalogView::AddDataType(tag) => {
-                            eprintln!("Received FromPresentation::AddDataType({})", tag.0);
+                            log_debug!("Received FromPresentation::AddDataType({})", tag.0);
                             // TODO: first check if we can add a tag for given swarm
                             // and also check if given tag is not already added
                             //TODO: we need to temporarily add given tag to manifest,
@@ -1367,7 +1715,7 @@ impl CatalogLogic {
                                 // and instead send a list of Data objects to update/create
                                 let data_vec = self.active_swarm.manifest.to_data();
                                 // TODO: this is not the data we want to send!
-                                eprintln!("app logic received add data type request,\nsending change content to app mgr…");
+                                log_debug!("app logic received add data type request,\nsending change content to app mgr…");
                                 let _ = self
                                     .to_app_mgr_send
                                     .send(ToAppMgr::ChangeContent(
@@ -1380,9 +1728,9 @@ impl CatalogLogic {
                             }
                         }
                         FromCatalogView::NeighborSelected(s_name) => {
-                            eprintln!("Selected neighbor swarm: {}", s_name);
+                            log_debug!("Selected neighbor swarm: {}", s_name);
                             if self.active_swarm.swarm_name == s_name {
-                                eprintln!("Already showing selected swarm");
+                                log_debug!("Already showing selected swarm");
                                 continue;
                             }
                             let _ = self
@@ -1391,36 +1739,26 @@ impl CatalogLogic {
                                 .await;
                         }
                         FromCatalogView::ShowContextMenu(ttype) => {
-                            match ttype {
-                                TileType::Home(_g_id) => {
-                                    self.state = TuiState::ContextMenuOn(ttype);
-                                    //TODO: select proper set_id depending on g_id value
-                                    let _ = self.to_tui.send(ToCatalogView::DisplayCMenu(1));
-                                }
-                                TileType::Neighbor(_g_id) => {
-                                    //TODO
-                                    self.state = TuiState::ContextMenuOn(ttype);
-                                    let _ = self.to_tui.send(ToCatalogView::DisplayCMenu(4));
-                                }
-                                TileType::Content(_dtype, _c_id) => {
-                                    //TODO
-                                    self.state = TuiState::ContextMenuOn(ttype);
-                                    let _ = self.to_tui.send(ToCatalogView::DisplayCMenu(2));
-                                }
-                                TileType::Field => {
-                                    //TODO
-                                    self.state = TuiState::ContextMenuOn(ttype);
-                                    let _ = self.to_tui.send(ToCatalogView::DisplayCMenu(3));
-                                }
-                                TileType::Application => {
-                                    //TODO
-                                }
+                            let context = MenuContext {
+                                change_denied: self.change_denied(),
+                                clipboard_full: self.clipboard.is_some(),
+                            };
+                            let menu = menu_for(&ttype, &context);
+                            if menu.is_empty() {
+                                log_debug!("No Context Menu for {:?}", ttype);
+                            } else {
+                                let _ = self
+                                    .to_tui
+                                    .send(ToCatalogView::DisplayCMenu(menu_items(&menu)));
+                                let actions = menu.into_iter().map(|(action, _r)| action).collect();
+                                self.state = TuiState::ContextMenuOn(ttype, actions);
                             }
                         }
                         FromCatalogView::SelectedIndices(indices) => {
-                            eprintln!(
+                            log_debug!(
                                 "FromPresentation::SelectedIndices({:?}), {:?}",
-                                indices, self.state
+                                indices,
+                                self.state
                             );
                             let mut new_state = TuiState::MainSt;
                             match &self.state {
@@ -1451,7 +1789,7 @@ impl CatalogLogic {
                                         let prev_indices = creator_context.get_tags();
                                         new_state = TuiState::Creator(new_context);
                                         if !indices.is_empty() {
-                                            eprintln!(
+                                            log_debug!(
                                                 "We should show street tagged with: {:?}",
                                                 self.active_swarm.manifest.tag_names(Some(vec![
                                                     prev_indices[indices[0]]
@@ -1481,12 +1819,12 @@ impl CatalogLogic {
                                 //     tags,
                                 TuiState::CreatorSelectDtype(c_context) => {
                                     if indices.len() != 1 {
-                                        eprintln!("Incorrect indices size for DType change");
+                                        log_debug!("Incorrect indices size for DType change");
                                         continue;
                                     }
 
                                     if c_context.content_id().is_some() {
-                                        eprintln!("cid is some");
+                                        log_debug!("cid is some");
                                         let mut new_context = c_context.clone();
                                         let index = indices[0] as u8;
                                         new_context.set_data_type(index);
@@ -1502,7 +1840,7 @@ impl CatalogLogic {
                                                 .manifest
                                                 .dtype_string(c_context.data_type().byte())
                                         };
-                                        eprintln!(
+                                        log_debug!(
                                             "{} new dtype_name: {}",
                                             c_context.data_type().byte(),
                                             dtype_name
@@ -1515,7 +1853,7 @@ impl CatalogLogic {
                                             tag_names,
                                         ));
                                     } else {
-                                        eprintln!("cid is none");
+                                        log_debug!("cid is none");
                                         let mut new_context = c_context.clone();
                                         new_context.set_data_type(indices[0] as u8);
                                         new_state = TuiState::Creator(new_context);
@@ -1528,7 +1866,7 @@ impl CatalogLogic {
                                             .active_swarm
                                             .manifest
                                             .dtype_string(indices[0] as u8);
-                                        eprintln!("DType name: '{}'", dtype_name);
+                                        log_debug!("DType name: '{}'", dtype_name);
 
                                         let _ = self.to_tui.send(ToCatalogView::DisplayCreator(
                                             read_only,
@@ -1557,42 +1895,6 @@ impl CatalogLogic {
                                         }
                                     }
                                 }
-                                TuiState::ChooseActionForTag(tag_id, tag_text) => {
-                                    match indices[0] {
-                                        0 => {
-                                            // TODO
-                                            eprintln!("Should go to street with id {tag_id}");
-                                        }
-                                        1 => {
-                                            new_state = TuiState::ChangeTag(*tag_id);
-                                            let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
-                    (false, true),
-                    " Max size: 32  Oneline  Change Street name    (TAB to finish)".to_string(),
-                    Some(tag_text.clone()),
-                    false,
-                    Some(32),
-                ));
-                                            eprintln!("Should change street name for id {tag_id}");
-                                        }
-                                        2 => {
-                                            new_state = TuiState::AddTag;
-                                            let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
-                    (false, true),
-                    " Max size: 32  Oneline  Define new Tag name    (TAB to finish)".to_string(),
-                    None,
-                    false,
-                    Some(32),
-                ));
-                                            // eprintln!("Should create new street");
-                                        }
-                                        3 => {
-                                            // Do nothing
-                                        }
-                                        other => {
-                                            eprintln!("Don't know what to do with Tag {other}");
-                                        }
-                                    }
-                                }
                                 TuiState::StorageRulesMenu(rule_id_opt, is_condition) => {
                                     if let Some(rule_id) = rule_id_opt {
                                         if *is_condition {
@@ -1613,17 +1915,17 @@ impl CatalogLogic {
                                                 }
                                             }
                                         }
-                                        eprintln!("ns= StorageRulesMenu");
+                                        log_debug!("ns= StorageRulesMenu");
                                         new_state =
                                             TuiState::StorageRulesMenu(*rule_id_opt, *is_condition);
                                         self.run_storage_rules_creator(*rule_id);
                                     }
                                 }
                                 other => {
-                                    eprintln!("{:?} got Selected indices", other);
+                                    log_debug!("{:?} got Selected indices", other);
                                 }
                             }
-                            eprintln!("Setting state to: {:?}", new_state);
+                            log_debug!("Setting state to: {:?}", new_state);
                             self.state = new_state;
                         }
 
@@ -1645,24 +1947,6 @@ impl CatalogLogic {
                                             .await;
                                     }
                                 }
-                                TuiState::ChangeTag(tag_id) => {
-                                    if let EditorResult::Text(text) = e_result {
-                                        if !text.is_empty() {
-                                            // eprintln!("We should change Tag {tag_id} to {text}");
-                                            let _ = self
-                                                .to_user_send
-                                                .send(InternalMsg::Catalog(
-                                                    FromCatalogView::ChangeTag(
-                                                        tag_id,
-                                                        Tag::new(text).unwrap(),
-                                                    ),
-                                                ))
-                                                .await;
-                                        }
-                                    } else {
-                                        eprintln!("Not supposed to change tag {tag_id}");
-                                    }
-                                }
                                 TuiState::AddDType => {
                                     if let EditorResult::Text(text) = e_result {
                                         // let _ = self.from_tui_send.send(FromPresentation::AddDataType(
@@ -1700,7 +1984,7 @@ impl CatalogLogic {
                                     // tag_ids,
                                 ) => {
                                     if let EditorResult::Text(text) = e_result {
-                                        eprintln!(
+                                        log_debug!(
                                             "Got some text after Edit from Creator {}",
                                             c_context.is_read_only()
                                         );
@@ -1721,7 +2005,7 @@ impl CatalogLogic {
                                                 new_context.set_description(descr);
                                                 new_state = TuiState::Creator(new_context);
                                             } else {
-                                                eprintln!("Description too long!");
+                                                log_debug!("Description too long!");
                                             }
                                             // eprintln!("Requesting display creator again");
                                             let _ =
@@ -1751,7 +2035,7 @@ impl CatalogLogic {
                                     notes,
                                 ) => {
                                     if let EditorResult::Text(text) = e_result {
-                                        eprintln!("Got edit result while indexing");
+                                        log_debug!("Got edit result while indexing");
                                         if let Some(d_id) = d_id_opt {
                                             // let mut bytes = Vec::with_capacity(1024);
                                             // if d_id == 0 {
@@ -1795,9 +2079,9 @@ impl CatalogLogic {
                                     }
                                 }
                                 TuiState::AddSearch => {
-                                    eprintln!("Received add Search result");
+                                    log_debug!("Received add Search result");
                                     if let EditorResult::Text(text) = e_result {
-                                        eprintln!("Some: {}", text);
+                                        log_debug!("Some: {}", text);
                                         //TODO
                                         let _ = self
                                             .to_app_mgr_send
@@ -1812,40 +2096,96 @@ impl CatalogLogic {
                                             .await;
                                     }
                                 }
+                                TuiState::NeighborFriendRequest(neighbor_id) => {
+                                    if let EditorResult::Text(text) = e_result {
+                                        self.send_neighbor_request(
+                                            neighbor_id,
+                                            NeighborRequest::FriendRequest(text),
+                                        )
+                                        .await;
+                                    }
+                                }
+                                TuiState::NeighborAskContent(neighbor_id) => {
+                                    if let EditorResult::Text(text) = e_result {
+                                        if let Ok(c_id) = text.trim().parse::<ContentID>() {
+                                            self.send_neighbor_request(
+                                                neighbor_id,
+                                                NeighborRequest::Content(c_id),
+                                            )
+                                            .await;
+                                        } else {
+                                            self.notify(
+                                                Severity::Warning,
+                                                trf("content.not_a_cid", &[&text]),
+                                                None,
+                                            )
+                                            .await;
+                                        }
+                                    }
+                                }
+                                TuiState::LogViewer => {
+                                    // Nothing to do, we were just showing recent problems
+                                }
+                                TuiState::AcceptInvitation => {
+                                    if let EditorResult::Text(text) = e_result {
+                                        self.accept_invitation(text, &config_dir).await;
+                                    }
+                                }
+                                TuiState::ChatWrite => {
+                                    if let EditorResult::Text(text) = e_result {
+                                        if !text.trim().is_empty() {
+                                            let s_id = self.active_swarm.swarm_id;
+                                            self.send_chat(s_id, ChatKind::Text, text).await;
+                                        }
+                                    }
+                                    self.show_chat_log();
+                                    new_state = TuiState::ChatLog;
+                                }
+                                TuiState::ChatLog => {
+                                    self.open_chat_menu();
+                                    new_state =
+                                        std::mem::replace(&mut self.state, TuiState::MainSt);
+                                }
+                                TuiState::NeighborsMenu(_neighbors) => {
+                                    // We were just showing our public address
+                                    self.open_neighbors_panel(&config_dir);
+                                    new_state =
+                                        std::mem::replace(&mut self.state, TuiState::MainSt);
+                                }
+                                TuiState::NeighborEdit(neighbors, n_idx_opt) => {
+                                    if let EditorResult::Text(text) = e_result {
+                                        self.store_neighbor(
+                                            neighbors,
+                                            n_idx_opt,
+                                            text,
+                                            &config_dir,
+                                        )
+                                        .await;
+                                    }
+                                    self.open_neighbors_panel(&config_dir);
+                                    new_state =
+                                        std::mem::replace(&mut self.state, TuiState::MainSt);
+                                }
                                 _other => {
-                                    eprintln!("Unexpected EditResult {:?}", _other);
+                                    log_warn!("Unexpected EditResult {:?}", _other);
                                 }
                             }
                             self.state = new_state;
                         }
-                        FromCatalogView::CMenuAction(action) => {
+                        FromCatalogView::CMenuAction(choice) => {
                             let prev_state = std::mem::replace(&mut self.state, TuiState::MainSt);
-                            eprintln!("CMenuAction on: {prev_state:?}");
+                            log_debug!("CMenuAction on: {prev_state:?}");
                             match prev_state {
-                                TuiState::ContextMenuOn(ttype) => match ttype {
-                                    TileType::Home(_g_id) => {
-                                        self.run_cmenu_action_on_home(action).await;
-                                    }
-                                    TileType::Content(d_type, c_id) => {
-                                        self.run_cmenu_action_on_content(c_id, d_type, action)
-                                            .await;
-                                    }
-                                    TileType::Field => {
-                                        self.run_cmenu_action_on_field(action).await;
-                                    }
-                                    TileType::Neighbor(n_id) => {
-                                        self.run_cmenu_action_on_neighbor(n_id, action).await;
-                                    }
-                                    other => {
-                                        eprintln!(
-                                            "{:?} tile does not support Context Menu action",
-                                            other
-                                        );
-                                        self.state = TuiState::ContextMenuOn(other);
-                                    }
-                                },
+                                TuiState::ContextMenuOn(ttype, actions) => {
+                                    let Some(action) = choice.and_then(|i| actions.get(i).copied())
+                                    else {
+                                        log_debug!("Context Menu closed on {:?}", ttype);
+                                        continue;
+                                    };
+                                    self.run_menu_action(ttype, action).await;
+                                }
                                 other => {
-                                    eprintln!(
+                                    log_debug!(
                                         "{:?} state does not support Context Menu action",
                                         other
                                     );
@@ -1853,6 +2193,17 @@ impl CatalogLogic {
                                 }
                             }
                         }
+                        FromCatalogView::RunCommand(command, argument) => {
+                            log_debug!("Command {} '{}'", command.name(), argument);
+                            if let Some(switch) = self
+                                .run_command(command, argument.trim(), &config_dir)
+                                .await
+                            {
+                                let _ = self.to_tui.send(ToCatalogView::Quit);
+                                return_val = Some(switch);
+                                break 'outer;
+                            }
+                        }
                         FromCatalogView::CreatorResult(c_result) => {
                             //TODO
                             match c_result {
@@ -1878,7 +2229,7 @@ impl CatalogLogic {
                                                 let _ = self.to_tui.send(
                                                     ToCatalogView::DisplaySelector(
                                                         true,
-                                                        "Types of Application".to_string(),
+                                                        tr("selector.app_types"),
                                                         AppType::list_of_types(),
                                                         vec![0],
                                                     ),
@@ -1887,8 +2238,7 @@ impl CatalogLogic {
                                                 let _ = self.to_tui.send(
                                                     ToCatalogView::DisplaySelector(
                                                         true,
-                                                        "Catalog Application's Data Types"
-                                                            .to_string(),
+                                                        tr("selector.catalog_dtypes"),
                                                         self.active_swarm.manifest.dtype_names(),
                                                         vec![c_context.data_type().byte() as usize],
                                                     ),
@@ -1905,7 +2255,7 @@ impl CatalogLogic {
                                             // }
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_warn!(
                                             "{:?}: Unexpected state for CreatorResult::SelectDType",
                                             self.state
                                         );
@@ -1948,7 +2298,7 @@ impl CatalogLogic {
                                             let _ =
                                                 self.to_tui.send(ToCatalogView::DisplaySelector(
                                                     quit_on_first_select,
-                                                    "Catalog Application's Tags".to_string(),
+                                                    tr("selector.catalog_tags"),
                                                     self.active_swarm.manifest.tag_names(filter),
                                                     long_ids,
                                                 ));
@@ -1962,7 +2312,7 @@ impl CatalogLogic {
                                             // }
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_warn!(
                                                 "Unexpected state for CreatorResult::SelectDType"
                                             );
                                             new_state = other.clone();
@@ -1989,12 +2339,15 @@ impl CatalogLogic {
                                                                    // tag_ids.clone(),
                                             );
                                             let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
-                                            (c_context.is_read_only(),self.my_name ==self.active_swarm.swarm_name),
-                                    " Max size: 128  Multiline  Content Description    (TAB to finish)".to_string(),
-                                    Some(c_context.description().text()),
-                                    true,
-                                    Some(128),
-                                    ));
+                                                (
+                                                    c_context.is_read_only(),
+                                                    self.my_name == self.active_swarm.swarm_name,
+                                                ),
+                                                tr("editor.content_description"),
+                                                Some(c_context.description().text()),
+                                                true,
+                                                Some(128),
+                                            ));
                                         }
                                         TuiState::StorageRulesMenu(rule_id_opt, c_or_p) => {
                                             // TODO: here we should open Editor
@@ -2010,17 +2363,17 @@ For now, if you want to reorder rules,
 you should edit 'storage.rules' text file in config dir."#
                                                 .to_string();
                                             let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
-                    (false, true),
-                    " Line#1: GnomeId  Line#2: SwarmName(opt, max: 32 bytes) (TAB to finish)".to_string(),
-                    Some(text),
-                    true,
-                    None,
-                ));
+                                                (false, true),
+                                                tr("editor.link_target"),
+                                                Some(text),
+                                                true,
+                                                None,
+                                            ));
                                             new_state =
                                                 TuiState::StorageRulesMenu(*rule_id_opt, *c_or_p);
                                         }
                                         other => {
-                                            eprintln!(
+                                            log_warn!(
                                                 "Unexpected state for CreatorResult::SelectDType"
                                             );
                                             new_state = other.clone();
@@ -2031,7 +2384,7 @@ you should edit 'storage.rules' text file in config dir."#
                                 CreatorResult::Cancel => {
                                     self.state = TuiState::MainSt;
                                     // TODO send request to presentation to show village
-                                    eprintln!("Cancel ");
+                                    log_debug!("Cancel ");
                                 }
                                 CreatorResult::Create => {
                                     if let TuiState::Creator(
@@ -2045,7 +2398,7 @@ you should edit 'storage.rules' text file in config dir."#
                                         //TODO: send request to app_mgr
                                         // eprintln!("CreatorResult::Create(d_type, data)");
                                         if !self.home_swarm_enforced {
-                                            eprintln!("Push back AppendContent");
+                                            log_debug!("Push back AppendContent");
                                             self.buffered_from_tui.push(
                                                 FromCatalogView::CreatorResult(
                                                     CreatorResult::Create,
@@ -2117,7 +2470,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                     ))
                                                     .await;
                                             } else {
-                                                eprintln!(
+                                                log_debug!(
                                                     "Requesting AppendContent {:?} {}",
                                                     data,
                                                     data.get_hash()
@@ -2132,7 +2485,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                     .await;
                                             }
                                         } else {
-                                            eprintln!(
+                                            log_warn!(
                                                 "Failed to build Data from bytes (too many bytes?)"
                                             );
                                         }
@@ -2143,7 +2496,7 @@ you should edit 'storage.rules' text file in config dir."#
                                             self.update_storage_rules(rule_id_opt, &config_dir)
                                                 .await;
                                         } else {
-                                            eprintln!(
+                                            log_debug!(
                                                 "Got TUI Create request when in {:?}",
                                                 self.state
                                             );
@@ -2153,16 +2506,34 @@ you should edit 'storage.rules' text file in config dir."#
                                 }
                             }
                         }
+                        FromCatalogView::DebugPanel => {
+                            self.show_debug_panel();
+                        }
+                        FromCatalogView::LogViewer => {
+                            self.show_log_viewer(&config_dir);
+                        }
+                        FromCatalogView::NotificationHistory => {
+                            self.show_notification_history();
+                        }
+                        FromCatalogView::ActivityFeed => {
+                            self.show_activity_feed();
+                        }
+                        FromCatalogView::DismissNotification => {
+                            let _ = self.notification_sender.send(NoteCmd::Dismiss).await;
+                        }
+                        FromCatalogView::Quit(tile) => {
+                            if self.save_session && self.home_swarm_enforced {
+                                write_session(&config_dir, &self.current_session(tile));
+                            }
+                            let _ = self.to_app_mgr_send.send(ToAppMgr::Quit).await;
+                        }
                         FromCatalogView::KeyPress(key) => {
                             // This is only for testing
                             // if matches!(key, Key::F) {
                             //     eprintln!("Key::F");
                             //     break 'outer;
                             // }
-                            if self.handle_key(key).await {
-                                // eprintln!("Sending ToAppMgr::Quit");
-                                let _ = self.to_app_mgr_send.send(ToAppMgr::Quit).await;
-                            }
+                            self.handle_key(key).await;
                         }
                         FromCatalogView::ContentInquiry(c_id) => {
                             if !self.home_swarm_enforced {
@@ -2185,25 +2556,29 @@ you should edit 'storage.rules' text file in config dir."#
                             match &self.state {
                                 TuiState::RemovePage(c_id) => {
                                     if let Some(page_id) = i_result {
-                                        eprintln!(
+                                        log_debug!(
                                             "We should request Page #{} removal from CID-{}",
-                                            page_id, c_id
+                                            page_id,
+                                            c_id
                                         );
                                         if page_id > 0 {
-                                            let _ = self
-                                                .to_app_mgr_send
-                                                .send(ToAppMgr::RemoveData(
-                                                    self.active_swarm.swarm_id,
-                                                    *c_id,
-                                                    page_id as u16,
-                                                ))
-                                                .await;
+                                            let _ =
+                                                self.to_tui.send(ToCatalogView::DisplayConfirm(
+                                                    tr("dialog.remove_page_title"),
+                                                    trf("dialog.remove_page", &[&page_id, c_id]),
+                                                ));
+                                            new_state = Some(TuiState::ConfirmRemovePage(
+                                                *c_id,
+                                                page_id as u16,
+                                            ));
                                         } else {
                                             // TODO: make this logic built-into dapp-lib
-                                            eprintln!("Unable to remove Page #0");
+                                            log_warn!("Unable to remove Page #0");
                                         }
                                     }
-                                    new_state = Some(TuiState::MainSt);
+                                    if new_state.is_none() {
+                                        new_state = Some(TuiState::MainSt);
+                                    }
                                 }
                                 TuiState::Indexing(
                                     c_id,
@@ -2219,7 +2594,7 @@ you should edit 'storage.rules' text file in config dir."#
                                             0 => {
                                                 let read_only =
                                                     self.my_name != self.active_swarm.swarm_name;
-                                                eprintln!(
+                                                log_debug!(
                                                     "Should show Content header RO: {}",
                                                     read_only
                                                 );
@@ -2262,7 +2637,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                 ));
                                             }
                                             other => {
-                                                eprintln!("Should show Note #{}", other);
+                                                log_debug!("Should show Note #{}", other);
                                                 new_state = Some(TuiState::Indexing(
                                                     *c_id,
                                                     Some(other as u16),
@@ -2273,7 +2648,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                     notes.clone(),
                                                 ));
                                                 let read_only = true;
-                                                let header = format!("Page #{}", other);
+                                                let header = trf("content.page", &[&other]);
                                                 let contents_opt =
                                                     if let Some(text) = notes.clone().get(other) {
                                                         Some(text.clone())
@@ -2298,22 +2673,38 @@ you should edit 'storage.rules' text file in config dir."#
                                         }
                                     } else {
                                         self.state = TuiState::MainSt;
-                                        eprintln!("Going back to Village");
+                                        log_debug!("Going back to Village");
                                     }
                                 }
                                 TuiState::ShowActiveSwarms(mapping) => {
                                     if let Some(idx) = i_result {
-                                        eprintln!(
-                                            "Now we should activate swarm with id: {:?}",
-                                            idx
-                                        );
-                                        //TODO: we need a mapping from idx -> gnome_id
-                                        if let Some((swarm_name, app_type_opt, s_id)) =
+                                        if let Some((swarm_name, _app_type_opt, _s_id)) =
                                             mapping.get(idx)
                                         {
-                                            eprintln!(
+                                            let swarm_name = swarm_name.clone();
+                                            new_state =
+                                                Some(TuiState::SwarmActions(mapping.clone(), idx));
+                                            self.show_swarm_actions(&swarm_name, &config_dir);
+                                        }
+                                    } else {
+                                        log_debug!("Going back to Village");
+                                        self.state = TuiState::MainSt;
+                                    }
+                                }
+                                TuiState::SwarmActions(mapping, s_idx) => {
+                                    let Some((swarm_name, app_type_opt, s_id)) =
+                                        mapping.get(*s_idx)
+                                    else {
+                                        self.state = TuiState::MainSt;
+                                        continue;
+                                    };
+                                    match i_result {
+                                        Some(0) => {
+                                            log_debug!(
                                                 "{} Activate: {} {:?}",
-                                                s_id, swarm_name, app_type_opt
+                                                s_id,
+                                                swarm_name,
+                                                app_type_opt
                                             );
                                             let _ = self
                                                 .to_app_mgr_send
@@ -2330,7 +2721,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                 // TODO: Do not start a new swarm
                                                 // when it is running
 
-                                                eprintln!("Not Catalog: {:?}", app_type_opt);
+                                                log_debug!("Not Catalog: {:?}", app_type_opt);
                                                 // let _ = self
                                                 //     .to_app_mgr_send
                                                 //     .send(ToAppMgr::StartNewSwarm(
@@ -2347,14 +2738,39 @@ you should edit 'storage.rules' text file in config dir."#
                                                 break 'outer;
                                             }
                                         }
-                                    } else {
-                                        eprintln!("Going back to Village");
-                                        self.state = TuiState::MainSt;
+                                        Some(1) => {
+                                            let swarm_name = swarm_name.clone();
+                                            self.reconnect_swarm(swarm_name, &config_dir).await;
+                                            new_state = Some(TuiState::MainSt);
+                                        }
+                                        Some(2) => {
+                                            let swarm_name = swarm_name.clone();
+                                            self.toggle_autojoin(swarm_name, &config_dir).await;
+                                            new_state = Some(self.request_swarm_list().await);
+                                        }
+                                        Some(3) => {
+                                            let swarm_name = swarm_name.clone();
+                                            self.toggle_watch_swarm(swarm_name, &config_dir).await;
+                                            new_state = Some(self.request_swarm_list().await);
+                                        }
+                                        Some(4) => {
+                                            let _ =
+                                                self.to_tui.send(ToCatalogView::DisplayConfirm(
+                                                    tr("dialog.leave_swarm_title"),
+                                                    trf("dialog.leave_swarm", &[swarm_name]),
+                                                ));
+                                            new_state = Some(TuiState::ConfirmLeaveSwarm(
+                                                swarm_name.clone(),
+                                            ));
+                                        }
+                                        _other => {
+                                            new_state = Some(self.request_swarm_list().await);
+                                        }
                                     }
                                 }
                                 TuiState::ListSearches(s_list) => {
                                     if let Some(idx) = i_result {
-                                        eprintln!(
+                                        log_debug!(
                                             "Supposed to show search results of {:?}",
                                             i_result
                                         );
@@ -2367,13 +2783,13 @@ you should edit 'storage.rules' text file in config dir."#
                                                 .await;
                                         }
                                     } else {
-                                        eprintln!("No search item selected.");
+                                        log_debug!("No search item selected.");
                                     }
                                 }
                                 TuiState::SearchResults(phrase, is_permanent, links) => {
                                     if let Some(idx) = i_result {
                                         if idx == 0 {
-                                            eprintln!(
+                                            log_debug!(
                                                 "we should set '{phrase}' is_permanent flag to {}",
                                                 !is_permanent
                                             );
@@ -2388,7 +2804,7 @@ you should edit 'storage.rules' text file in config dir."#
                                                 .await;
                                             continue;
                                         }
-                                        eprintln!("Supposed to open Content@{:?}", idx);
+                                        log_debug!("Supposed to open Content@{:?}", idx);
                                         if let Some((s_name, c_id)) = links.get(idx) {
                                             new_state = Some(TuiState::ReadLinkToFollow(
                                                 0,
@@ -2411,28 +2827,31 @@ you should edit 'storage.rules' text file in config dir."#
                                             //     ))
                                             //     .await;
                                         } else {
-                                            eprintln!("Could not find corresponding s_name");
+                                            log_warn!("Could not find corresponding s_name");
                                         }
                                     } else {
-                                        eprintln!("No search item selected.");
+                                        log_debug!("No search item selected.");
                                     }
                                 }
                                 TuiState::SettingsMenu => {
                                     if let Some(page_id) = i_result {
-                                        eprintln!("Got Indexing in Settings Menu {page_id}");
+                                        log_debug!("Got Indexing in Settings Menu {page_id}");
                                         match page_id {
                                             0 => {
                                                 // TODO
-                                                eprintln!("We should open a Storage rules menu");
+                                                log_debug!("We should open a Storage rules menu");
                                                 self.open_storage_rules_panel();
                                             }
                                             1 => {
+                                                self.open_neighbors_panel(&config_dir);
+                                            }
+                                            2 => {
                                                 // TODO
-                                                eprintln!("Cancel selected");
+                                                log_debug!("Cancel selected");
                                                 self.state = TuiState::MainSt;
                                             }
                                             other => {
-                                                eprintln!("Unhandled option in Settings: {other}");
+                                                log_debug!("Unhandled option in Settings: {other}");
                                                 self.state = TuiState::MainSt;
                                             }
                                         }
@@ -2445,19 +2864,277 @@ you should edit 'storage.rules' text file in config dir."#
                                         self.state = TuiState::MainSt;
                                     }
                                 }
+                                TuiState::NotificationHistory => {
+                                    new_state = Some(TuiState::MainSt);
+                                    match i_result {
+                                        Some(0) => {
+                                            self.notifications_paused = !self.notifications_paused;
+                                            let _ = self
+                                                .notification_sender
+                                                .send(NoteCmd::Pause(self.notifications_paused))
+                                                .await;
+                                        }
+                                        Some(1) => {
+                                            self.notifications.clear();
+                                        }
+                                        Some(idx) => {
+                                            // Entries are presented newest first
+                                            let action = self
+                                                .notifications
+                                                .iter()
+                                                .rev()
+                                                .nth(idx - 2)
+                                                .and_then(|note| note.action.clone());
+                                            if let Some(action) = action {
+                                                new_state = None;
+                                                self.run_note_action(action, &config_dir).await;
+                                            }
+                                        }
+                                        None => {}
+                                    }
+                                }
+                                TuiState::ActivityFeed => {
+                                    new_state = Some(TuiState::MainSt);
+                                    match i_result {
+                                        Some(0) => {
+                                            self.activity.clear();
+                                        }
+                                        Some(idx) => {
+                                            // Entries are presented newest first
+                                            let target = self
+                                                .activity
+                                                .iter()
+                                                .rev()
+                                                .nth(idx - 1)
+                                                .map(|a| (a.s_name.clone(), a.c_id));
+                                            if let Some((s_name, c_id)) = target {
+                                                new_state = None;
+                                                self.run_note_action(
+                                                    NoteAction::OpenContent(s_name, c_id),
+                                                    &config_dir,
+                                                )
+                                                .await;
+                                            }
+                                        }
+                                        None => {}
+                                    }
+                                }
+                                TuiState::DebugPanel => {
+                                    new_state = Some(TuiState::MainSt);
+                                    if let Some(experiment) =
+                                        i_result.and_then(|idx| EXPERIMENTS.get(idx).copied())
+                                    {
+                                        self.run_experiment(experiment).await;
+                                    }
+                                }
+                                TuiState::ChatMenu(actions) => {
+                                    let action = i_result.and_then(|idx| actions.get(idx).copied());
+                                    self.run_chat_action(action).await;
+                                }
+                                TuiState::InvitationMenu => match i_result {
+                                    Some(0) => {
+                                        new_state = Some(TuiState::MainSt);
+                                        self.show_invitation();
+                                    }
+                                    Some(1) => {
+                                        new_state = Some(TuiState::AcceptInvitation);
+                                        let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                                            (false, true),
+                                            tr("editor.invitation_token"),
+                                            None,
+                                            false,
+                                            Some(1024),
+                                        ));
+                                    }
+                                    Some(2) => {
+                                        new_state = Some(TuiState::MainSt);
+                                        self.show_public_ips();
+                                    }
+                                    _other => {
+                                        new_state = Some(TuiState::MainSt);
+                                    }
+                                },
+                                TuiState::NeighborsMenu(neighbors) => {
+                                    let neighbors = neighbors.clone();
+                                    self.run_neighbors_menu_action(
+                                        i_result,
+                                        neighbors,
+                                        &config_dir,
+                                    );
+                                }
+                                TuiState::NeighborActions(neighbors, n_idx) => {
+                                    let (neighbors, n_idx) = (neighbors.clone(), *n_idx);
+                                    self.run_neighbor_action(
+                                        i_result,
+                                        neighbors,
+                                        n_idx,
+                                        &config_dir,
+                                    )
+                                    .await;
+                                }
                                 other => {
-                                    eprintln!("Got Indexing response when in state {:?}", other);
+                                    log_debug!("Got Indexing response when in state {:?}", other);
                                 }
                             }
                             if let Some(new_state) = new_state {
                                 self.state = new_state;
                             }
                         }
-                        FromCatalogView::SwitchToApp(app_type, s_id, s_name) => {
-                            eprintln!("logic got SwitchToApp");
-                            return_val = Some((app_type, s_id, s_name.clone()));
-                            // TODO: here we need to notify app manager
-                            // that user wants to switch to a new swarm
+                        FromCatalogView::ChoiceResult(choice) => {
+                            let mut new_state = TuiState::MainSt;
+                            match &self.state {
+                                TuiState::ChooseActionForTag(tag_id, tag_text) => {
+                                    match choice {
+                                        Some(0) => {
+                                            // TODO
+                                            log_debug!("Should go to street with id {tag_id}");
+                                        }
+                                        Some(1) => {
+                                            new_state = TuiState::ChangeTag(*tag_id);
+                                            let _ = self.to_tui.send(ToCatalogView::DisplayPrompt(
+                                                tr("dialog.change_street_title"),
+                                                trf("dialog.change_street", &[tag_text]),
+                                                tag_text.clone(),
+                                                32,
+                                            ));
+                                            log_debug!("Should change street name for id {tag_id}");
+                                        }
+                                        Some(2) => {
+                                            new_state = TuiState::AddTag;
+                                            let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                                                (false, true),
+                                                tr("editor.new_tag"),
+                                                None,
+                                                false,
+                                                Some(32),
+                                            ));
+                                            // eprintln!("Should create new street");
+                                        }
+                                        Some(3) => {
+                                            self.toggle_watch_tag(tag_text.clone(), &config_dir)
+                                                .await;
+                                        }
+                                        _other => {
+                                            log_debug!("No action chosen for Tag {tag_id}");
+                                        }
+                                    }
+                                }
+                                other => {
+                                    log_debug!("Got Choice response when in state {:?}", other);
+                                }
+                            }
+                            self.state = new_state;
+                        }
+                        FromCatalogView::ConfirmResult(answer) => {
+                            let mut new_state = TuiState::MainSt;
+                            let prev_state = std::mem::replace(&mut self.state, TuiState::MainSt);
+                            match prev_state {
+                                TuiState::ConfirmRemovePage(c_id, page_id) => match answer {
+                                    Answer::Yes => {
+                                        let _ = self
+                                            .to_app_mgr_send
+                                            .send(ToAppMgr::RemoveData(
+                                                self.active_swarm.swarm_id,
+                                                c_id,
+                                                page_id,
+                                            ))
+                                            .await;
+                                    }
+                                    Answer::No => {
+                                        // Let user pick another Page
+                                        self.query_content_for_indexer(c_id).await;
+                                        new_state = TuiState::RemovePage(c_id);
+                                    }
+                                    Answer::Cancel => {}
+                                },
+                                TuiState::ConfirmLeaveSwarm(swarm_name) => {
+                                    if answer == Answer::Yes {
+                                        self.leave_swarm(swarm_name, &config_dir).await;
+                                    }
+                                    new_state = self.request_swarm_list().await;
+                                }
+                                TuiState::ConfirmChangeTag(tag_id, tag) => match answer {
+                                    Answer::Yes => {
+                                        let _ = self
+                                            .to_user_send
+                                            .send(InternalMsg::Catalog(FromCatalogView::ChangeTag(
+                                                tag_id, tag,
+                                            )))
+                                            .await;
+                                    }
+                                    Answer::No => {
+                                        // Let user correct new name
+                                        let _ = self.to_tui.send(ToCatalogView::DisplayPrompt(
+                                            tr("dialog.change_street_title"),
+                                            trf("dialog.change_street", &[&tag.0]),
+                                            tag.0.clone(),
+                                            32,
+                                        ));
+                                        new_state = TuiState::ChangeTag(tag_id);
+                                    }
+                                    Answer::Cancel => {}
+                                },
+                                other => {
+                                    log_debug!("Got Confirm response when in state {:?}", other);
+                                }
+                            }
+                            self.state = new_state;
+                        }
+                        FromCatalogView::PromptResult(text) => {
+                            let mut new_state = TuiState::MainSt;
+                            let prev_state = std::mem::replace(&mut self.state, TuiState::MainSt);
+                            match prev_state {
+                                TuiState::ChangeTag(tag_id) => {
+                                    let text =
+                                        text.map(|t| t.trim().to_string()).unwrap_or_default();
+                                    let mut t_names =
+                                        self.active_swarm.manifest.tag_names(Some(vec![tag_id]));
+                                    if !text.is_empty() && !t_names.is_empty() && t_names[0] != text
+                                    {
+                                        match Tag::new(text.clone()) {
+                                            Ok(tag) => {
+                                                let _ = self.to_tui.send(
+                                                    ToCatalogView::DisplayConfirm(
+                                                        tr("dialog.change_street_title"),
+                                                        trf(
+                                                            "dialog.change_street_confirm",
+                                                            &[&t_names.remove(0), &text],
+                                                        ),
+                                                    ),
+                                                );
+                                                new_state = TuiState::ConfirmChangeTag(tag_id, tag);
+                                            }
+                                            Err(_e) => {
+                                                self.notify(
+                                                    Severity::Warning,
+                                                    trf("command.bad_name", &[&text]),
+                                                    None,
+                                                )
+                                                .await;
+                                                // Let user correct new name
+                                                let _ =
+                                                    self.to_tui.send(ToCatalogView::DisplayPrompt(
+                                                        tr("dialog.change_street_title"),
+                                                        trf("dialog.change_street", &[&t_names[0]]),
+                                                        text,
+                                                        32,
+                                                    ));
+                                                new_state = TuiState::ChangeTag(tag_id);
+                                            }
+                                        }
+                                    }
+                                }
+                                other => {
+                                    log_debug!("Got Prompt response when in state {:?}", other);
+                                }
+                            }
+                            self.state = new_state;
+                        }
+                        FromCatalogView::SwitchToApp(app_type, s_id, s_name) => {
+                            log_debug!("logic got SwitchToApp");
+                            return_val = Some((app_type, s_id, s_name.clone()));
+                            // TODO: here we need to notify app manager
+                            // that user wants to switch to a new swarm
                             // TODO: Do not start a new swarm if already running!
                             let _ = self
                                 .to_app_mgr_send
@@ -2470,21 +3147,30 @@ you should edit 'storage.rules' text file in config dir."#
                         // eprintln!("We should present options for {}: {}", tag_idx, tag_text);
                         let _ = self.present_options_for_tag(tag_idx, tag_text).await;
                     }
+                    InternalMsg::NeighborRequestTimeout(nonce) => {
+                        self.neighbor_request_timed_out(nonce).await;
+                    }
+                    InternalMsg::ConnectTimeout(nonce) => {
+                        self.connect_timed_out(nonce).await;
+                    }
                     _other => {
-                        eprintln!("Catalog logic received unexpected InternalMsg");
+                        log_warn!("Catalog logic received unexpected InternalMsg");
                     }
                 }
             }
         }
-        eprintln!("out of 'outer");
+        log_debug!("out of 'outer");
         // TODO: move below inside CatalogLogic::new
-        // empty note terminates notifier service
-        let _res = self.notification_sender.send(Some(format!(""))).await;
+        // Stop terminates notifier service
+        let _res = self.notification_sender.send(NoteCmd::Stop).await;
+        if let Some(stop) = self.pub_ips_refresh_stop.take() {
+            let _ = stop.send(()).await;
+        }
         // (tui_mgr, config) = toolbox.ret tui_join.await;
         toolset = tui_join.await;
         // tui_mgr.new_display(false);
         if let Some((app_type, s_id, s_name)) = return_val {
-            eprintln!("return_val is: {s_id}-{s_name}");
+            log_debug!("return_val is: {s_id}-{s_name}");
             // TODO: we need a dedicated messago for swarm switching
             // now we fake that a new swarm is active, when it is not!
             // let _ = self
@@ -2493,7 +3179,7 @@ you should edit 'storage.rules' text file in config dir."#
             //     .await;
             Some((Some(app_type), s_name, self.to_app, toolset, self.clipboard))
         } else {
-            eprintln!("return_val is none");
+            log_debug!("return_val is none");
             toolset.discard();
             None
         }
@@ -2513,7 +3199,7 @@ you should edit 'storage.rules' text file in config dir."#
             //     d_vec.len()
             // );
             let manifest = Manifest::from(d_vec);
-            eprintln!("Process data manifest tags: {:?}", manifest.tags);
+            log_debug!("Process data manifest tags: {:?}", manifest.tags);
             // let mut streets_left_to_present = self.visible_streets.0;
             // let mut street_names = Vec::with_capacity(streets_left_to_present);
             // let mut street_idx = 0;
@@ -2558,12 +3244,14 @@ you should edit 'storage.rules' text file in config dir."#
             // for i in 0..streets_left_to_present {
             //     street_names.push(Tag(format!("Generic street #{}", i)));
             // }
+            self.known_tags
+                .insert(self.active_swarm.swarm_id, manifest.tags.clone());
             self.active_swarm.manifest = manifest;
-            eprintln!("All tags: {:?}", tag_ring);
+            log_debug!("All tags: {:?}", tag_ring);
             self.active_swarm.tag_ring = tag_ring;
             if street_names != self.visible_streets.1 {
                 self.visible_streets.1 = street_names.clone();
-                eprintln!("Sending StreetNames: {:?}", street_names);
+                log_debug!("Sending StreetNames: {:?}", street_names);
                 let mut streets_with_contents = vec![];
                 for street in street_names {
                     let contents = self.active_swarm.get_cids_for_tag(street.clone());
@@ -2584,7 +3272,7 @@ you should edit 'storage.rules' text file in config dir."#
             if self.my_name == self.active_swarm.swarm_name && !self.pub_ips.is_empty() {
                 let ips = std::mem::replace(&mut self.pub_ips, vec![]);
                 if self.active_swarm.manifest.update_pub_ips(ips) {
-                    eprintln!("Now we should sync updated Manifest to Swarm");
+                    log_debug!("Now we should sync updated Manifest to Swarm");
                     let data_vec = self.active_swarm.manifest.to_data();
                     let _ = self
                         .to_app_mgr_send
@@ -2611,7 +3299,7 @@ you should edit 'storage.rules' text file in config dir."#
                         all_notes.push(String::new());
                         let mut all_headers = Vec::with_capacity(d_vec.len());
                         let first_data = d_vec.remove(0);
-                        eprintln!("1Reading tags & header for: {}", rc_id);
+                        log_debug!("1Reading tags & header for: {}", rc_id);
                         let (tag_ids, description) =
                             read_tags_and_header(d_type, first_data.clone());
                         let mut bytes = first_data.bytes();
@@ -2635,7 +3323,7 @@ you should edit 'storage.rules' text file in config dir."#
                             }
                         }
                         if header.is_empty() {
-                            header = "No description".to_string();
+                            header = tr("content.no_description");
                         }
                         // eprintln!("Pushing '{}' to all_headers", header);
                         all_headers.push(header);
@@ -2649,7 +3337,7 @@ you should edit 'storage.rules' text file in config dir."#
                                 }
                             }
                             if header.is_empty() {
-                                header = "Nothing to show".to_string();
+                                header = tr("content.nothing_to_show");
                             }
                             // eprintln!("Pushing '{}' to all_headers", header);
                             all_headers.push(header);
@@ -2673,7 +3361,7 @@ you should edit 'storage.rules' text file in config dir."#
                             );
                         }
                     } else {
-                        eprintln!("ReadSuccess on {}, was expecting: {}", c_id, rc_id);
+                        log_debug!("ReadSuccess on {}, was expecting: {}", c_id, rc_id);
                     }
                 }
                 TuiState::ReadLinkToFollow(rc_id, _opt) => {
@@ -2682,7 +3370,7 @@ you should edit 'storage.rules' text file in config dir."#
                         let first_data = d_vec.remove(0);
                         let link_res = data_to_link(first_data);
                         if link_res.is_err() {
-                            eprintln!("Unable to ReadLink: {}", link_res.err().unwrap());
+                            log_warn!("Unable to ReadLink: {}", link_res.err().unwrap());
                         } else if let Some((
                             app_type,
                             s_name,
@@ -2693,7 +3381,7 @@ you should edit 'storage.rules' text file in config dir."#
                         )) = link_res.unwrap().link_params()
                         {
                             // 3 set target swarm active
-                            eprintln!("ReadLinkToFollow ({app_type:?})");
+                            log_debug!("ReadLinkToFollow ({app_type:?})");
                             let _ = self
                                 .to_app_mgr_send
                                 .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name.clone())))
@@ -2703,14 +3391,14 @@ you should edit 'storage.rules' text file in config dir."#
                                 self.state =
                                     TuiState::ReadLinkToFollow(c_id, Some((s_name, target_c_id)));
                             } else {
-                                eprintln!("Other App: {:?}", app_type);
+                                log_debug!("Other App: {:?}", app_type);
                                 return Some((app_type, s_name));
                             }
                         } else {
-                            eprintln!("Unable to read link params");
+                            log_warn!("Unable to read link params");
                         }
                     } else {
-                        eprintln!("ReadSuccess on {}, was expecting link: {}", c_id, rc_id);
+                        log_debug!("ReadSuccess on {}, was expecting link: {}", c_id, rc_id);
                     }
                 }
                 TuiState::Creator(c_ctx) => {
@@ -2758,7 +3446,7 @@ you should edit 'storage.rules' text file in config dir."#
                     }
                 }
                 other => {
-                    eprintln!("ReadSuccess when in state: {:?}", other);
+                    log_debug!("ReadSuccess when in state: {:?}", other);
                 }
             }
             if let Some(state) = new_state {
@@ -2775,19 +3463,22 @@ you should edit 'storage.rules' text file in config dir."#
         // – (Change name)
         // – (Add new street)
         // – Cancel
-        eprintln!("present_options_for_tag({tag_idx},{tag_text})");
+        log_debug!("present_options_for_tag({tag_idx},{tag_text})");
         self.state = TuiState::ChooseActionForTag(tag_idx, tag_text.clone());
         let options = vec![
-            "Go to street".to_string(),
-            "Change name".to_string(),
-            "Add new street".to_string(),
-            "Cancel".to_string(),
+            tr("tag.go_to_street"),
+            tr("tag.change_name"),
+            tr("tag.add_street"),
+            if self.watch_list.tags.contains(&tag_text) {
+                tr("tag.unwatch")
+            } else {
+                tr("tag.watch")
+            },
         ];
-        let _ = self.to_tui.send(ToCatalogView::DisplaySelector(
-            true,
-            format!("What to do with {tag_text}"),
+        let _ = self.to_tui.send(ToCatalogView::DisplayChoice(
+            tag_text.clone(),
+            trf("tag.what_to_do", &[&tag_text]),
             options,
-            vec![],
         ));
     }
 
@@ -2814,7 +3505,7 @@ you should edit 'storage.rules' text file in config dir."#
     ) {
         // eprintln!("ToApp::NewContent({:?},{:?})", c_id, d_type);
         // eprintln!("CID-{} Main page: {:?}", c_id, main_page);
-        eprintln!("2Reading tags & header for: {}", c_id);
+        log_debug!("2Reading tags & header for: {}", c_id);
         let (tag_ids, header) = read_tags_and_header(d_type, main_page.clone());
 
         let ids_len = tag_ids.len();
@@ -2828,7 +3519,7 @@ you should edit 'storage.rules' text file in config dir."#
         }
         if tags.len() != ids_len {
             // eprintln!("Manifest not synced, shelving NewContent message");
-            eprintln!(
+            log_debug!(
                 "Manifest has {} TAGS, {} has {}, shelving",
                 tags.len(),
                 c_id,
@@ -2842,7 +3533,7 @@ you should edit 'storage.rules' text file in config dir."#
             let (added, removed) =
                 self.active_swarm
                     .update_tag_to_cid(c_id, d_type, tags, header.clone());
-            eprintln!("added: {:?}\nremoved: {:?}", added, removed);
+            log_debug!("added: {:?}\nremoved: {:?}", added, removed);
             if !added.is_empty() {
                 let _ = self
                     .to_tui
@@ -2866,7 +3557,11 @@ you should edit 'storage.rules' text file in config dir."#
 
     fn open_config_panel(&mut self) {
         // eprintln!("We should open Configuration panel");
-        let options = vec!["Disk storage rules".to_string(), "Cancel".to_string()];
+        let options = vec![
+            tr("settings.storage_rules"),
+            tr("settings.bootstrap_neighbors"),
+            tr("common.cancel"),
+        ];
         let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
         self.state = TuiState::SettingsMenu;
     }
@@ -2874,16 +3569,303 @@ you should edit 'storage.rules' text file in config dir."#
     fn open_storage_rules_panel(&mut self) {
         // TODO: we should have those settings read from disk and held for reference.
         // eprintln!("We should open Configuration panel");
-        let mut options = vec!["Append new rule...".to_string()];
+        let mut rules = ListData::new(vec![
+            Column::text(tr("column.condition"), 0),
+            Column::text(tr("column.policy"), 0),
+        ]);
+        rules.pin(vec![tr("storage.append_rule")]);
         for i in 1..self.storage_rules.len() {
             if let Some((cond, pol)) = self.storage_rules.get(i) {
-                options.push(format!("{} => {}", cond.get_string(), pol.get_string()));
+                rules.push(vec![cond.get_string(), pol.get_string()]);
             }
         }
-        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+        let _ = self.to_tui.send(ToCatalogView::DisplayList(
+            tr("settings.storage_rules"),
+            rules,
+        ));
         self.state = TuiState::StorageRulesMenu(None, true);
     }
 
+    fn open_neighbors_panel(&mut self, config_dir: &Path) {
+        let neighbors = read_neighbors(config_dir);
+        let mut options = vec![
+            tr("neighbors.our_address"),
+            tr("neighbors.add"),
+            tr("neighbors.import"),
+        ];
+        for neighbor in &neighbors {
+            options.push(neighbor.to_line());
+        }
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+        self.state = TuiState::NeighborsMenu(neighbors);
+    }
+
+    fn run_neighbors_menu_action(
+        &mut self,
+        i_result: Option<usize>,
+        neighbors: Vec<NeighborEntry>,
+        config_dir: &Path,
+    ) {
+        match i_result {
+            Some(0) => {
+                self.show_my_address();
+            }
+            Some(1) => {
+                self.state = TuiState::NeighborEdit(neighbors, None);
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    tr("editor.neighbor_line"),
+                    None,
+                    false,
+                    Some(64),
+                ));
+            }
+            Some(2) => {
+                self.state = TuiState::NeighborEdit(neighbors, None);
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    tr("editor.import_neighbor"),
+                    None,
+                    false,
+                    Some(1024),
+                ));
+            }
+            Some(idx) => {
+                let n_idx = idx - 3;
+                if let Some(neighbor) = neighbors.get(n_idx) {
+                    let options = vec![
+                        trf("neighbors.edit", &[&neighbor.to_line()]),
+                        tr("neighbors.try_connect"),
+                        tr("neighbors.remove"),
+                        tr("common.back"),
+                    ];
+                    let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+                    self.state = TuiState::NeighborActions(neighbors, n_idx);
+                } else {
+                    self.open_neighbors_panel(config_dir);
+                }
+            }
+            None => {
+                self.state = TuiState::MainSt;
+            }
+        }
+    }
+
+    async fn run_neighbor_action(
+        &mut self,
+        i_result: Option<usize>,
+        mut neighbors: Vec<NeighborEntry>,
+        n_idx: usize,
+        config_dir: &Path,
+    ) {
+        match i_result {
+            Some(0) => {
+                let line = neighbors.get(n_idx).map(|n| n.to_line());
+                self.state = TuiState::NeighborEdit(neighbors, Some(n_idx));
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    tr("editor.neighbor_line"),
+                    line,
+                    false,
+                    Some(64),
+                ));
+                return;
+            }
+            Some(1) => {
+                if let Some(neighbor) = neighbors.get(n_idx) {
+                    log_debug!("Trying to connect with {}", neighbor.to_line());
+                    let _ = self
+                        .to_app_mgr_send
+                        .send(ToAppMgr::StorageNeighbors(vec![(
+                            GnomeId::any(),
+                            neighbor.to_network_settings(),
+                        )]))
+                        .await;
+                    let nonce = self.next_nonce;
+                    self.next_nonce = self.next_nonce.wrapping_add(1);
+                    let s_id = self.active_swarm.swarm_id;
+                    let count = self.stats_for_id(s_id).map(|s| s.neighbors).unwrap_or(0);
+                    self.connect_attempt = Some((nonce, neighbor.clone(), s_id, count));
+                    spawn(connect_timeout(
+                        self.to_user_send.clone(),
+                        nonce,
+                        CONNECT_TIMEOUT,
+                    ));
+                    self.notify(
+                        Severity::Info,
+                        trf("neighbors.connecting", &[&neighbor.ip, &neighbor.port]),
+                        None,
+                    )
+                    .await;
+                }
+            }
+            Some(2) => {
+                if n_idx < neighbors.len() {
+                    let removed = neighbors.remove(n_idx);
+                    replace_neighbor(config_dir, &removed, None);
+                    self.notify(
+                        Severity::Info,
+                        trf("neighbors.removed", &[&removed.ip, &removed.port]),
+                        None,
+                    )
+                    .await;
+                }
+            }
+            _other => {}
+        }
+        self.open_neighbors_panel(config_dir);
+    }
+
+    async fn store_neighbor(
+        &mut self,
+        neighbors: Vec<NeighborEntry>,
+        n_idx_opt: Option<usize>,
+        text: String,
+        config_dir: &Path,
+    ) {
+        let old = n_idx_opt.and_then(|n_idx| neighbors.get(n_idx));
+        // New neighbors can also be given as an invitation token
+        let entries = match decode_invitation(&text) {
+            Some((_s_name, addresses)) if old.is_none() => addresses,
+            _other => NeighborEntry::from_line(&text).into_iter().collect(),
+        };
+        if entries.is_empty() {
+            log_warn!("Unable to parse neighbor line: {}", text);
+            self.notify(Severity::Warning, tr("neighbors.invalid_line"), None)
+                .await;
+            return;
+        }
+        if let Some(old) = old {
+            replace_neighbor(config_dir, old, entries.first());
+        } else {
+            add_neighbors(config_dir, &entries);
+        }
+        self.notify(Severity::Info, tr("neighbors.saved"), None)
+            .await;
+    }
+
+    // Connection attempt succeeded once swarm got a new neighbor
+    async fn check_connect_attempt(&mut self, s_id: SwarmID, neighbors: usize) {
+        let connected = matches!(
+            &self.connect_attempt,
+            Some((_nonce, _neighbor, a_id, count)) if *a_id == s_id && neighbors > *count
+        );
+        if !connected {
+            return;
+        }
+        if let Some((_nonce, neighbor, _s_id, _count)) = self.connect_attempt.take() {
+            self.notify(
+                Severity::Info,
+                trf("neighbors.connected", &[&neighbor.ip, &neighbor.port]),
+                None,
+            )
+            .await;
+        }
+    }
+
+    async fn connect_timed_out(&mut self, nonce: u32) {
+        if !matches!(&self.connect_attempt, Some((n, _neighbor, _s_id, _count)) if *n == nonce) {
+            return;
+        }
+        if let Some((_nonce, neighbor, _s_id, _count)) = self.connect_attempt.take() {
+            self.notify(
+                Severity::Warning,
+                trf("neighbors.connect_failed", &[&neighbor.ip, &neighbor.port]),
+                None,
+            )
+            .await;
+        }
+    }
+
+    fn show_invitation(&self) {
+        let pub_ips = if self.my_name == self.active_swarm.swarm_name {
+            self.active_swarm.manifest.get_pub_ips()
+        } else {
+            self.known_pub_ips.clone()
+        };
+        let addresses: Vec<NeighborEntry> = pub_ips
+            .iter()
+            .filter(|ns| ns.pub_port != 0)
+            .map(NeighborEntry::from_network_settings)
+            .collect();
+        if addresses.is_empty() {
+            log_debug!("Creating invitation without any public address");
+        }
+        let token = encode_invitation(&self.my_name, &addresses);
+        let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+            (true, false),
+            trf("invitation.title", &[&self.my_name, &addresses.len()]),
+            Some(token),
+            false,
+            None,
+        ));
+    }
+
+    async fn accept_invitation(&mut self, token: String, config_dir: &Path) {
+        let Some((s_name, addresses)) = decode_invitation(&token) else {
+            log_warn!("Unable to decode invitation: {}", token);
+            self.notify(Severity::Warning, tr("invitation.invalid"), None)
+                .await;
+            return;
+        };
+        log_debug!("Accepting invitation to {} {:?}", s_name, addresses);
+        add_neighbors(config_dir, &addresses);
+        let storage_neighbors: Vec<(GnomeId, NetworkSettings)> = addresses
+            .iter()
+            .map(|address| (s_name.founder, address.to_network_settings()))
+            .collect();
+        if !storage_neighbors.is_empty() {
+            let _ = self
+                .to_app_mgr_send
+                .send(ToAppMgr::StorageNeighbors(storage_neighbors))
+                .await;
+        }
+        self.notify(
+            Severity::Info,
+            trf("invitation.visiting", &[&s_name]),
+            Some(NoteAction::SwitchToSwarm(s_name.clone())),
+        )
+        .await;
+        let founder = s_name.founder;
+        let _ = self
+            .to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
+            .await;
+        let _ = self.to_tui.send(ToCatalogView::SwapTiles(founder));
+    }
+
+    fn show_my_address(&self) {
+        let mut text = String::with_capacity(256);
+        // Enough to connect from within our local network
+        let ip = local_ip().unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        for port in listening_ports() {
+            let entry = NeighborEntry {
+                ip,
+                port,
+                nat: 0,
+                port_alloc: 0,
+                transport: 1,
+            };
+            text.push_str(&trf("address.local", &[&entry.to_line()]));
+        }
+        if self.known_pub_ips.is_empty() {
+            text.push_str(&tr("address.not_discovered"));
+        }
+        for ns in &self.known_pub_ips {
+            text.push_str(&format!(
+                "IP: {} PORT: {} NAT: {:?} Port alloc: {:?}({})\n",
+                ns.pub_ip, ns.pub_port, ns.nat_type, ns.port_allocation.0, ns.port_allocation.1
+            ));
+        }
+        let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+            (true, false),
+            trf("address.seen_by_others", &[&self.my_name.founder]),
+            Some(text),
+            false,
+            None,
+        ));
+    }
+
     fn run_storage_rules_creator(&mut self, idx: usize) {
         self.state = TuiState::StorageRulesMenu(Some(idx), true);
 
@@ -2891,7 +3873,7 @@ you should edit 'storage.rules' text file in config dir."#
         let (d_type, tags) = if let Some((cond, pol)) = self.storage_rules.get(idx) {
             (cond.get_string(), pol.get_string())
         } else {
-            ("Rule condition".to_string(), "Rule params".to_string())
+            (tr("storage.rule_condition"), tr("storage.rule_params"))
         };
         let description = String::new();
         let _ = self.to_tui.send(ToCatalogView::DisplayCreator(
@@ -2914,10 +3896,10 @@ you should edit 'storage.rules' text file in config dir."#
                 // - ForumApp
                 // - SearchMatch
                 // - Default
-                eprintln!("Should select Condition when editing Storage rules");
+                log_debug!("Should select Condition when editing Storage rules");
                 let _ = self.to_tui.send(ToCatalogView::DisplaySelector(
                     true,
-                    "Select condition for Storage rule".to_string(),
+                    tr("storage.select_condition"),
                     StorageCondition::string_vec(),
                     vec![cond.get_id()],
                 ));
@@ -2939,10 +3921,10 @@ you should edit 'storage.rules' text file in config dir."#
                 // - Matching(rest: Forget)
                 // - Matching+Manifest(rest: FirstPages)
                 // - Matching+Manifest(rest: Forget)
-                eprintln!("Should select Policy when editing Storage rules");
+                log_debug!("Should select Policy when editing Storage rules");
                 let _ = self.to_tui.send(ToCatalogView::DisplaySelector(
                     true,
-                    "Select policy for Storage rule".to_string(),
+                    tr("storage.select_policy"),
                     StoragePolicy::string_vec(),
                     vec![pol.get_id()],
                 ));
@@ -2960,26 +3942,26 @@ you should edit 'storage.rules' text file in config dir."#
             let mut sn_text = None;
             if let Some(g_text) = lines.next() {
                 // parse first line as FounderID
-                eprintln!("Line#1: {g_text}");
+                log_debug!("Line#1: {g_text}");
                 let trimmed = g_text.trim();
                 if let Some(gid) = GnomeId::from_string(trimmed.to_string()) {
                     g_id = Some(gid);
-                    eprintln!("GnomeId: {gid}");
+                    log_debug!("GnomeId: {gid}");
                 }
             }
             if let Some(name) = lines.next() {
                 // parse optional second line as SName text
-                eprintln!("Line#2: {name}");
+                log_debug!("Line#2: {name}");
                 let trimmed = name.trim();
                 if trimmed.len() <= 32 {
-                    eprintln!("len OK");
+                    log_debug!("len OK");
                     sn_text = Some(trimmed.to_string());
                 }
             }
             if let Some((cond, _pol)) = self.storage_rules.get(r_id) {
                 let updated_cond = cond.update(g_id, sn_text);
                 self.storage_rules[r_id].0 = updated_cond;
-                eprintln!("Rule found & updated");
+                log_debug!("Rule found & updated");
             }
             self.run_storage_rules_creator(r_id);
         }
@@ -3075,108 +4057,589 @@ you should edit 'storage.rules' text file in config dir."#
         }
         let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
             (true, false), // (read_only, can_edit)
-            format!("Publiczne IP dla {}", self.active_swarm.swarm_name),
+            trf("address.public_ips_for", &[&self.active_swarm.swarm_name]),
             Some(public_ips),
             false, // allow_newlines
             None,  // byte_limit
         ));
     }
-    fn show_active_swarms(&mut self, active_swarms: Vec<String>) {
-        // eprintln!("We should show active Swarms");
-        // eprintln!("We should create mapping swarm_id => gnome_id");
+    fn show_active_swarms(&mut self, active_swarms: ListData) {
+        let _ = self.to_tui.send(ToCatalogView::DisplayList(
+            tr("swarm.active"),
+            active_swarms,
+        ));
+    }
+    fn stats_for_id(&mut self, s_id: SwarmID) -> Option<&mut SwarmStats> {
+        self.swarm_stats
+            .values_mut()
+            .find(|stats| stats.swarm_id == s_id && stats.status != SwarmStatus::Disconnected)
+    }
+
+    // Columns as defined when showing active swarms
+    fn swarm_row(&self, s_name: &SwarmName) -> Vec<String> {
+        let role = if s_name.founder == self.my_name.founder {
+            tr("swarm.founder")
+        } else {
+            tr("swarm.member")
+        };
+        let (s_id, app_type, neighbors, status) = if let Some(stats) = self.swarm_stats.get(s_name)
+        {
+            (
+                stats.swarm_id.0,
+                stats.app_type,
+                stats.neighbors,
+                stats.status,
+            )
+        } else {
+            (0, None, 0, SwarmStatus::Syncing)
+        };
+        let app = match app_type {
+            Some(AppType::Catalog) => "Catalog".to_string(),
+            Some(AppType::Forum) => "Forum".to_string(),
+            Some(other) => format!("{:?}", other),
+            None => tr("common.unknown"),
+        };
+        vec![
+            s_id.to_string(),
+            s_name.to_string(),
+            app,
+            role,
+            neighbors.to_string(),
+            format!("{:?}", status),
+            self.storage_policy_for(s_name, app_type),
+        ]
+    }
+
+    // Only conditions without parameters are evaluated here,
+    // rules with parameters are left for dapp-lib to decide.
+    fn storage_policy_for(&self, s_name: &SwarmName, app_type: Option<AppType>) -> String {
+        for (cond, pol) in self.storage_rules.iter().skip(1) {
+            let matches = match cond.get_id() {
+                0 => s_name.founder == self.my_name.founder,
+                3 => app_type.is_some_and(|a| a.is_catalog()),
+                4 => matches!(app_type, Some(AppType::Forum)),
+                6 => true,
+                _other => false,
+            };
+            if matches {
+                return pol.get_string();
+            }
+        }
+        StoragePolicy::Forget.get_string()
+    }
+
+    fn show_swarm_actions(&self, s_name: &SwarmName, config_dir: &Path) {
+        let pinned = read_autojoin(config_dir).contains(s_name);
+        let options = vec![
+            trf("swarm.open", &[&s_name]),
+            tr("swarm.reconnect"),
+            if pinned {
+                tr("swarm.unpin")
+            } else {
+                tr("swarm.pin")
+            },
+            if self.watch_list.swarms.contains(s_name) {
+                tr("swarm.unwatch")
+            } else {
+                tr("swarm.watch")
+            },
+            tr("swarm.leave"),
+            tr("common.back"),
+        ];
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+    }
+
+    async fn request_swarm_list(&mut self) -> TuiState {
         let _ = self
-            .to_tui
-            .send(ToCatalogView::DisplayIndexer(active_swarms));
+            .to_app_mgr_send
+            .send(ToAppMgr::ProvideGnomeToSwarmMapping)
+            .await;
+        TuiState::ShowActiveSwarms(vec![])
     }
-    async fn swarm_disconnected(
-        &mut self,
-        is_reconnecting: bool,
-        s_id: SwarmID,
-        s_name: SwarmName,
-    ) {
-        if self.active_swarm.swarm_id == s_id {
-            let _ = self
-                .notification_sender
-                .send(Some(format!("{} disconnected", s_name)))
-                .await;
-            let _ = self.to_tui.send(ToCatalogView::SwapTiles(GnomeId::any()));
-            self.active_swarm.clear_tag_to_cid();
-            self.state = TuiState::ShowActiveSwarms(vec![]);
+
+    async fn join_pinned_swarms(&mut self, config_dir: &Path) {
+        let pinned = read_autojoin(config_dir);
+        // Swarm of last session is joined after all pinned ones
+        let last = self
+            .session
+            .as_ref()
+            .map(|session| session.swarm.clone())
+            .unwrap_or_else(|| self.my_name.clone());
+        if pinned.is_empty() {
+            if last != self.my_name {
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(last)))
+                    .await;
+            }
+            return;
+        }
+        for s_name in pinned {
+            if s_name == self.my_name || s_name == last || self.swarm_stats.contains_key(&s_name) {
+                continue;
+            }
+            log_debug!("Auto-joining {}", s_name);
+            self.auto_joining.push(s_name.clone());
             let _ = self
                 .to_app_mgr_send
-                .send(ToAppMgr::ProvideGnomeToSwarmMapping)
+                .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
                 .await;
-            // TODO: In this case if active swarm != our own swarm
-            // we can simply switch to our own & send a notification
-            // that current swarm got disconnected
-            // If our own swarm is not operational or
-            // if it was our own swarm then we could provide user
-            // with a list of swarms that are still operational
-            // to choose from.
-            // If the number of operational swarms has changed while
-            // we were being presented that list, it should get updated.
-            // And when the list becomes empty we might
-            // close swarm selector and post a note
-            // informing that all swarms are disconnected, also
-            // if the list is empty we simply reset presentation layer
-            // to display only greenfields
-            //
-            // When reconnecting we could also lookup our storage for
-            // public IPs to connect to
-            eprintln!(
-                "Active {} {} disconnected (Reconnecting: {})",
-                s_id, s_name, is_reconnecting
-            );
-        } else if s_name == self.my_name {
-            // TODO: Here our owned swarm got disconnected,
-            // but our current swarm is operational,
-            // so we might just post a notification
-            eprintln!(
-                "Owned {} {} disconnected (Reconnecting: {})",
-                s_id, s_name, is_reconnecting
-            );
-        } else {
-            // Here we log that a swarm got disconnected, but we do not
-            // need to inform user about it, since this should be
-            // common occurence
-            eprintln!(
-                "{} {} disconnected (Reconnecting: {})",
-                s_id, s_name, is_reconnecting
+        }
+        // Make sure we end up back home, or where we were last time
+        let _ = self
+            .to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(last)))
+            .await;
+    }
+
+    // Show street and tile of last session,
+    // as soon as that street has any contents
+    async fn resume_session(&mut self) {
+        let Some(session) = self.restoring.take() else {
+            return;
+        };
+        if session.swarm != self.active_swarm.swarm_name {
+            log_info!(
+                "Not restoring last session in {}, {} is active",
+                session.swarm,
+                self.active_swarm.swarm_name
             );
+            return;
+        }
+        if !matches!(self.state, TuiState::MainSt) {
+            // User is in a menu or overlay, we try again with next data
+            log_debug!("Postponing session restore in state {:?}", self.state);
+            self.restoring = Some(session);
+            return;
+        }
+        if let Some((name, pg)) = &session.street {
+            let tag = Tag(name.clone());
+            if !self
+                .active_swarm
+                .tag_to_cid
+                .get(&tag)
+                .is_some_and(|cids| !cids.is_empty())
+            {
+                self.restoring = Some(session);
+                return;
+            }
+            if let Some(state) = self.present_street(tag, *pg).await {
+                self.state = state;
+            }
+        }
+        if let Some(tile) = session.tile {
+            let _ = self.to_tui.send(ToCatalogView::SelectTile(tile));
         }
+        log_info!("Restored last session in {}", session.swarm);
     }
-    async fn run_cmenu_action_on_content(
-        &mut self,
-        c_id: ContentID,
-        d_type: DataType,
-        action: usize,
+
+    // Where we are now, to be restored on next start
+    fn current_session(&self, tile: (u8, u8)) -> Session {
+        let street = match &self.state {
+            TuiState::Street(tag, pg) => Some((tag.0.clone(), *pg)),
+            _other => None,
+        };
+        Session {
+            app_type: AppType::Catalog,
+            swarm: self.active_swarm.swarm_name.clone(),
+            street,
+            tile: Some(tile),
+            topic: None,
+            clipboard: self.clipboard.clone(),
+        }
+    }
+
+    async fn reconnect_swarm(&mut self, s_name: SwarmName, config_dir: &Path) {
+        log_debug!("Reconnecting {}", s_name);
+        let config = AppConf::new(config_dir).await;
+        let neighbors: Vec<(GnomeId, NetworkSettings)> = config
+            .storage_neighbors
+            .into_iter()
+            .filter(|(g_id, _ns)| *g_id == s_name.founder)
+            .collect();
+        if !neighbors.is_empty() {
+            let _ = self
+                .to_app_mgr_send
+                .send(ToAppMgr::StorageNeighbors(neighbors))
+                .await;
+        }
+        if let Some(stats) = self.swarm_stats.get_mut(&s_name) {
+            stats.status = SwarmStatus::Syncing;
+        }
+        self.notify(Severity::Info, trf("swarm.reconnecting", &[&s_name]), None)
+            .await;
+        let founder = s_name.founder;
+        let _ = self
+            .to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
+            .await;
+        let _ = self.to_tui.send(ToCatalogView::SwapTiles(founder));
+    }
+
+    async fn toggle_autojoin(&mut self, s_name: SwarmName, config_dir: &Path) {
+        let mut pinned = read_autojoin(config_dir);
+        let note = if let Some(pos) = pinned.iter().position(|s| *s == s_name) {
+            pinned.remove(pos);
+            trf("swarm.unpinned", &[&s_name])
+        } else {
+            let note = trf("swarm.pinned", &[&s_name]);
+            pinned.push(s_name);
+            note
+        };
+        write_autojoin(config_dir, &pinned);
+        self.notify(Severity::Info, note, None).await;
+    }
+
+    async fn toggle_watch_swarm(&mut self, s_name: SwarmName, config_dir: &Path) {
+        let note = if let Some(pos) = self.watch_list.swarms.iter().position(|s| *s == s_name) {
+            self.watch_list.swarms.remove(pos);
+            trf("swarm.unwatched", &[&s_name])
+        } else {
+            let note = trf("swarm.watched", &[&s_name]);
+            self.watch_list.swarms.push(s_name);
+            note
+        };
+        write_watch_list(config_dir, &self.watch_list);
+        self.notify(Severity::Info, note, None).await;
+    }
+
+    async fn toggle_watch_tag(&mut self, tag: String, config_dir: &Path) {
+        let note = if let Some(pos) = self.watch_list.tags.iter().position(|t| *t == tag) {
+            self.watch_list.tags.remove(pos);
+            trf("tag.unwatched", &[&tag])
+        } else {
+            let note = trf("tag.watched", &[&tag]);
+            self.watch_list.tags.push(tag);
+            note
+        };
+        write_watch_list(config_dir, &self.watch_list);
+        self.notify(Severity::Info, note, None).await;
+    }
+
+    // Called for contents of swarms other than active one.
+    // Street names are only known for swarms we have visited.
+    async fn watch_activity(
+        &mut self,
+        s_id: SwarmID,
+        c_id: ContentID,
+        d_type: DataType,
+        main_page: Option<Data>,
+        is_new: bool,
+    ) {
+        if c_id == 0 || self.watch_list.is_empty() {
+            return;
+        }
+        let Some(s_name) = self.swarm_name_for(s_id) else {
+            return;
+        };
+        // A swarm that has just synced is still catching up with it's contents
+        if !self
+            .swarm_stats
+            .get(&s_name)
+            .is_some_and(|stats| stats.is_settled())
+        {
+            return;
+        }
+        let (tags, header) = match main_page {
+            Some(main_page) if !main_page.is_empty() => {
+                let (tag_ids, header) = read_tags_and_header(d_type, main_page);
+                let mut tags = vec![];
+                if let Some(known) = self.known_tags.get(&s_id) {
+                    for id in tag_ids {
+                        if let Some(tag) = known.get(&id) {
+                            tags.push(tag.0.clone());
+                        }
+                    }
+                }
+                (tags, header)
+            }
+            _other => (vec![], String::new()),
+        };
+        let swarm_watched = self.watch_list.swarms.contains(&s_name);
+        let tag_watched = tags.iter().any(|t| self.watch_list.tags.contains(t));
+        if !swarm_watched && !tag_watched {
+            return;
+        }
+        log_debug!("Activity in watched {}: CID-{}", s_name, c_id);
+        let activity = Activity::new(s_name.clone(), c_id, header, tags, is_new);
+        if self.activity.len() >= ACTIVITY_FEED {
+            self.activity.pop_front();
+        }
+        self.activity.push_back(activity.clone());
+        self.notify(
+            Severity::Info,
+            activity.summary(),
+            Some(NoteAction::OpenContent(s_name, c_id)),
+        )
+        .await;
+    }
+
+    fn show_activity_feed(&mut self) {
+        self.state = TuiState::ActivityFeed;
+        let mut options = Vec::with_capacity(self.activity.len() + 1);
+        options.push(tr("activity.clear"));
+        for activity in self.activity.iter().rev() {
+            options.push(activity.line());
+        }
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+    }
+
+    // Swarm is not joined again on next start, and once library
+    // confirms it is stopped we forget what we know about it.
+    async fn leave_swarm(&mut self, s_name: SwarmName, config_dir: &Path) {
+        if s_name == self.my_name {
+            self.notify(Severity::Warning, tr("swarm.cannot_leave_own"), None)
+                .await;
+            return;
+        }
+        let mut pinned = read_autojoin(config_dir);
+        pinned.retain(|s| *s != s_name);
+        write_autojoin(config_dir, &pinned);
+        let running = self
+            .swarm_stats
+            .get(&s_name)
+            .filter(|stats| stats.status != SwarmStatus::Disconnected)
+            .map(|stats| stats.swarm_id);
+        let Some(s_id) = running else {
+            self.swarm_left(s_name).await;
+            return;
+        };
+        if !self.leaving.contains(&s_name) {
+            self.leaving.push(s_name.clone());
+        }
+        let _ = self.to_app_mgr_send.send(ToAppMgr::LeaveSwarm(s_id)).await;
+        self.notify(Severity::Info, trf("swarm.leaving", &[&s_name]), None)
+            .await;
+    }
+
+    async fn swarm_left(&mut self, s_name: SwarmName) {
+        self.leaving.retain(|s| *s != s_name);
+        self.swarm_stats.remove(&s_name);
+        self.notify(Severity::Info, trf("swarm.left", &[&s_name]), None)
+            .await;
+    }
+
+    async fn swarm_disconnected(
+        &mut self,
+        is_reconnecting: bool,
+        s_id: SwarmID,
+        s_name: SwarmName,
+    ) {
+        let stats = self
+            .swarm_stats
+            .entry(s_name.clone())
+            .or_insert(SwarmStats::new(s_id, None));
+        stats.neighbors = 0;
+        stats.synced_at = None;
+        stats.status = if is_reconnecting {
+            SwarmStatus::Reconnecting
+        } else {
+            SwarmStatus::Disconnected
+        };
+        // Whatever was shelved for this swarm is outdated now,
+        // it will be read again once it syncs
+        self.pending_notifications.remove(&s_id);
+        if let Some(room) = self.chats.get_mut(&s_id) {
+            room.neighbors.clear();
+            room.members.clear();
+            if room.joined {
+                room.joined = false;
+                room.push_info(tr("chat.channel_gone"));
+                self.notify(
+                    Severity::Warning,
+                    trf("chat.channel_gone_in", &[&s_name]),
+                    Some(NoteAction::SwitchToSwarm(s_name.clone())),
+                )
+                .await;
+            }
+        }
+        if self.active_swarm.swarm_id == s_id {
+            log_debug!(
+                "Active {} {} disconnected (Reconnecting: {})",
+                s_id,
+                s_name,
+                is_reconnecting
+            );
+            self.active_swarm.clear_tag_to_cid();
+            if is_reconnecting {
+                self.reenter_after_reconnect = Some(s_name.clone());
+            }
+            let home_operational = s_name != self.my_name
+                && self
+                    .swarm_stats
+                    .get(&self.my_name)
+                    .is_some_and(|stats| stats.status == SwarmStatus::Synced);
+            if home_operational {
+                self.notify(
+                    Severity::Warning,
+                    trf("swarm.disconnected_going_home", &[&s_name]),
+                    None,
+                )
+                .await;
+                self.state = TuiState::MainSt;
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(
+                        self.my_name.clone(),
+                    )))
+                    .await;
+                let _ = self
+                    .to_tui
+                    .send(ToCatalogView::SwapTiles(self.my_name.founder));
+                return;
+            }
+            // Our home is not available, so we let user pick
+            // one of the swarms that are still operational
+            let _ = self.to_tui.send(ToCatalogView::SwapTiles(GnomeId::any()));
+            if self
+                .swarm_stats
+                .values()
+                .any(|stats| stats.status == SwarmStatus::Synced)
+            {
+                self.notify(
+                    Severity::Warning,
+                    trf("swarm.disconnected", &[&s_name]),
+                    None,
+                )
+                .await;
+                self.state = TuiState::ShowActiveSwarms(vec![]);
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::ProvideGnomeToSwarmMapping)
+                    .await;
+            } else {
+                self.all_swarms_disconnected().await;
+            }
+        } else if s_name == self.my_name {
+            log_debug!(
+                "Owned {} {} disconnected (Reconnecting: {})",
+                s_id,
+                s_name,
+                is_reconnecting
+            );
+            let note = if is_reconnecting {
+                tr("swarm.home_disconnected_reconnecting")
+            } else {
+                tr("swarm.home_disconnected")
+            };
+            self.notify(Severity::Warning, note, None).await;
+        } else {
+            // Here we log that a swarm got disconnected, but we do not
+            // need to inform user about it, since this should be
+            // common occurence
+            log_debug!(
+                "{} {} disconnected (Reconnecting: {})",
+                s_id,
+                s_name,
+                is_reconnecting
+            );
+            // Swarm manager shows it's status, so it needs a refresh
+            let showing_swarms = match &self.state {
+                TuiState::ShowActiveSwarms(_mapping) => true,
+                TuiState::SwarmActions(mapping, s_idx) => mapping
+                    .get(*s_idx)
+                    .is_some_and(|(name, _a, _i)| *name == s_name),
+                _other => false,
+            };
+            if showing_swarms {
+                self.state = self.request_swarm_list().await;
+            }
+        }
+    }
+
+    async fn all_swarms_disconnected(&mut self) {
+        log_debug!("All swarms are disconnected");
+        self.state = TuiState::MainSt;
+        self.offline = true;
+        let _ = self.to_tui.send(ToCatalogView::Offline(true));
+        self.notify(Severity::Error, tr("swarm.all_disconnected"), None)
+            .await;
+    }
+
+    // Returns a name of swarm that just got back neighbors after
+    // it was reconnecting. It still needs to sync with them.
+    fn mark_reconnected(&mut self, s_id: SwarmID, neighbors: usize) -> Option<SwarmName> {
+        if neighbors == 0 {
+            return None;
+        }
+        for (s_name, stats) in self.swarm_stats.iter_mut() {
+            if stats.swarm_id == s_id && stats.status == SwarmStatus::Reconnecting {
+                stats.status = SwarmStatus::Syncing;
+                return Some(s_name.clone());
+            }
+        }
+        None
+    }
+
+    async fn swarm_reconnected(&mut self, s_name: SwarmName) {
+        log_debug!("{} reconnected", s_name);
+        if self.reenter_after_reconnect.as_ref() != Some(&s_name) {
+            return;
+        }
+        self.reenter_after_reconnect = None;
+        // Only go back if user did not walk away in the meantime
+        let still_waiting = self.active_swarm.swarm_name == self.my_name
+            || self.active_swarm.swarm_name == s_name
+            || self.active_swarm.swarm_name.founder.is_any();
+        if !still_waiting || !self.state.is_showing_street() {
+            self.notify(
+                Severity::Info,
+                trf("swarm.back_online", &[&s_name]),
+                Some(NoteAction::SwitchToSwarm(s_name.clone())),
+            )
+            .await;
+            return;
+        }
+        self.notify(
+            Severity::Info,
+            trf("swarm.reconnected_going_back", &[&s_name]),
+            None,
+        )
+        .await;
+        let founder = s_name.founder;
+        let _ = self
+            .to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
+            .await;
+        let _ = self.to_tui.send(ToCatalogView::SwapTiles(founder));
+    }
+    async fn run_cmenu_action_on_content(
+        &mut self,
+        c_id: ContentID,
+        d_type: DataType,
+        action: MenuAction,
     ) {
         match action {
-            1 => {
+            MenuAction::AppendNote => {
                 //TODO
                 self.state = TuiState::AppendData(c_id);
                 let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
                     (false, true),
-                    "Add Note".to_string(),
+                    tr("editor.add_note"),
                     None,
                     true,
                     Some(1024),
                 ));
             }
-            2 => {
+            MenuAction::RemoveNote => {
                 // eprintln!("Setting state to RemovePage({})", c_id);
                 self.query_content_for_indexer(c_id).await;
                 self.state = TuiState::RemovePage(c_id);
             }
-            3 => {
-                eprintln!("We should copy a Link of selected Content");
+            MenuAction::CopyLink => {
+                log_debug!("We should copy a Link of selected Content");
                 self.clipboard = Some((self.active_swarm.swarm_name.clone(), c_id));
-                let _ = self
-                    .notification_sender
-                    .send(Some(format!("Zawartość {} skopiowana", c_id)))
-                    .await;
+                self.notify(
+                    Severity::Info,
+                    trf("content.copied", &[&c_id]),
+                    Some(NoteAction::OpenContent(
+                        self.active_swarm.swarm_name.clone(),
+                        c_id,
+                    )),
+                )
+                .await;
             }
-            4 => {
+            MenuAction::EditLink => {
                 if d_type.is_link() {
                     // we should edit a link, if it is a link
                     let _ = self
@@ -3199,238 +4662,846 @@ you should edit 'storage.rules' text file in config dir."#
                 }
             }
             other => {
-                //TODO
-                eprintln!("{} Context Menu action on Content", other);
+                log_debug!("{:?} Context Menu action on Content", other);
             }
         }
     }
-    async fn run_cmenu_action_on_home(&mut self, action: usize) {
+    async fn run_cmenu_action_on_home(&mut self, action: MenuAction) {
         //TODO
-        eprintln!(
-            "We should perform action: {} when in {:?}",
-            action, self.state
+        log_debug!(
+            "We should perform action: {:?} when in {:?}",
+            action,
+            self.state
         );
         match action {
-            1 => {
+            MenuAction::ShowTags => {
                 self.state = TuiState::PresentTags;
                 let _ = self.to_tui.send(ToCatalogView::DisplaySelector(
                     true,
-                    "Catalog Application's Tags".to_string(),
+                    tr("selector.catalog_tags"),
                     self.active_swarm.manifest.tag_names(None),
                     vec![],
                 ));
             }
-            2 => {
+            MenuAction::ShowDataTypes => {
                 let _ = self.to_tui.send(ToCatalogView::DisplaySelector(
                     true,
-                    "Catalog Application's Data Types".to_string(),
+                    tr("selector.catalog_dtypes"),
                     self.active_swarm.manifest.dtype_names(),
                     vec![],
                 ));
             }
-            3 => {
+            MenuAction::AddTag => {
                 self.state = TuiState::AddTag;
                 let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
                     (false, true),
-                    " Max size: 32  Oneline  Define new Tag name    (TAB to finish)".to_string(),
+                    tr("editor.new_tag"),
                     None,
                     false,
                     Some(32),
                 ));
             }
-            4 => {
+            MenuAction::AddDataType => {
                 self.state = TuiState::AddDType;
                 let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
                     (false, true),
-                    " Max size: 32  Oneline  Define new Data Type    (TAB to finish)".to_string(),
+                    tr("editor.new_dtype"),
                     None,
                     false,
                     Some(32),
                 ));
             }
-            5 => {
+            MenuAction::CreateContent => {
                 self.run_creator();
             }
-            6 => {
-                self.show_public_ips();
+            MenuAction::Invitations => {
+                self.state = TuiState::InvitationMenu;
+                let options = vec![
+                    tr("invitation.create"),
+                    tr("invitation.accept"),
+                    tr("invitation.public_ips"),
+                ];
+                let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
             }
-            7 => {
-                eprintln!("Show active Swarms");
+            MenuAction::ActiveSwarms => {
+                log_debug!("Show active Swarms");
                 self.state = TuiState::ShowActiveSwarms(vec![]);
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::ProvideGnomeToSwarmMapping)
                     .await;
             }
-            8 => {
-                eprintln!("Open Add a new Search window");
+            MenuAction::AddSearch => {
+                log_debug!("Open Add a new Search window");
                 self.state = TuiState::AddSearch;
                 let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
                     (false, true),
-                    " Max size: 1024 Multiline  Add a new Search   (TAB to finish)".to_string(),
+                    tr("editor.add_search"),
                     None,
                     true,
                     Some(1024),
                 ));
             }
-            _other => {
-                //TODO
+            other => {
+                log_debug!("{:?} Context Menu action on Home", other);
             }
         }
     }
-    async fn run_cmenu_action_on_neighbor(&mut self, neighbor_id: GnomeId, action: usize) {
-        // TODO
+    async fn run_cmenu_action_on_neighbor(&mut self, neighbor_id: GnomeId, action: MenuAction) {
         match action {
-            1 => {
-                // żądanie
-                let c_data = CastData::new(vec![1, 11, 21, 31, 41, 51, 61, 71, 81, 91]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        1,
-                        c_data,
-                    )))
+            MenuAction::Ping => {
+                self.send_neighbor_request(neighbor_id, NeighborRequest::Ping)
                     .await;
-                eprintln!("Request sent: {_r:?}");
             }
-            2 => {
-                // odpowiedź
-                let c_data = CastData::new(vec![92, 82, 72, 62, 52, 42, 32, 22, 12, 2]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborResponse(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        2,
-                        c_data,
-                    )))
+            MenuAction::VillageSummary => {
+                self.send_neighbor_request(neighbor_id, NeighborRequest::VillageSummary)
                     .await;
             }
-            3 => {
-                // żądanie
-                let c_data = CastData::new(vec![3, 13, 23, 33, 43, 53, 63, 73, 83, 93]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        3,
-                        c_data,
-                    )))
-                    .await;
-                eprintln!("Request sent: {_r:?}");
+            MenuAction::FriendRequest => {
+                self.state = TuiState::NeighborFriendRequest(neighbor_id);
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    trf("editor.friend_request", &[&neighbor_id]),
+                    Some(trf("editor.friend_greeting", &[&self.my_name.founder])),
+                    false,
+                    Some(256),
+                ));
             }
-            4 => {
-                // odpowiedź
-                let c_data = CastData::new(vec![94, 84, 74, 64, 54, 44, 34, 24, 14, 4]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborResponse(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        4,
-                        c_data,
-                    )))
-                    .await;
+            MenuAction::AskForContent => {
+                self.state = TuiState::NeighborAskContent(neighbor_id);
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    trf("editor.ask_for_cid", &[&neighbor_id]),
+                    None,
+                    false,
+                    Some(5),
+                ));
             }
-            5 => {
-                // żądanie
-                let c_data = CastData::new(vec![5, 15, 25, 35, 45, 55, 65, 75, 85, 95]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        5,
-                        c_data,
-                    )))
-                    .await;
-                eprintln!("Request sent: {_r:?}");
+            other => {
+                log_warn!("Unexpected Neighbor context item {:?}", other);
             }
-            6 => {
-                // odpowiedź
-                let c_data = CastData::new(vec![96, 86, 76, 66, 56, 46, 36, 26, 16, 6]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborResponse(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        6,
-                        c_data,
-                    )))
-                    .await;
+        }
+    }
+
+    async fn send_neighbor_request(&mut self, neighbor_id: GnomeId, request: NeighborRequest) {
+        let nonce = self.next_nonce;
+        self.next_nonce = self.next_nonce.wrapping_add(1);
+        let Some(c_data) = request.encode(nonce) else {
+            log_warn!("Unable to encode {:?}", request);
+            return;
+        };
+        let _ = self
+            .to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
+                self.active_swarm.swarm_name.clone(),
+                neighbor_id,
+                request.id(),
+                c_data,
+            )))
+            .await;
+        self.pending_neighbor_requests.insert(
+            nonce,
+            (
+                self.active_swarm.swarm_id,
+                neighbor_id,
+                request.id(),
+                Instant::now(),
+            ),
+        );
+        spawn(neighbor_request_timeout(
+            self.to_user_send.clone(),
+            nonce,
+            NEIGHBOR_REQUEST_TIMEOUT,
+        ));
+        self.notify(
+            Severity::Info,
+            trf("neighbor.request_sent", &[&request.name(), &neighbor_id]),
+            None,
+        )
+        .await;
+    }
+
+    async fn serve_neighbor_request(
+        &mut self,
+        s_id: SwarmID,
+        neighbor_id: GnomeId,
+        req_id: u8,
+        bytes: Vec<u8>,
+    ) {
+        let Some(s_name) = self.swarm_name_for(s_id) else {
+            log_warn!("Neighbor request for unknown swarm {}", s_id);
+            return;
+        };
+        let (nonce, response) = match NeighborRequest::decode(req_id, bytes.clone()) {
+            Ok((nonce, request)) => (nonce, self.response_for(s_id, neighbor_id, request).await),
+            Err(version) => {
+                log_warn!("Unsupported neighbor request {} v{}", req_id, version);
+                let nonce = if bytes.len() >= 5 {
+                    u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]])
+                } else {
+                    0
+                };
+                (nonce, NeighborResponse::Unsupported(PROTOCOL_VERSION))
             }
-            7 => {
-                // żądanie
-                let c_data = CastData::new(vec![7, 17, 27, 37, 47, 57, 67, 77, 87, 97]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        250,
-                        c_data,
-                    )))
-                    .await;
-                eprintln!("Request sent: {_r:?}");
+        };
+        if let Some(c_data) = response.encode(nonce) {
+            let _ = self
+                .to_app_mgr_send
+                .send(ToAppMgr::FromApp(LibRequest::CustomNeighborResponse(
+                    s_name,
+                    neighbor_id,
+                    response.id(),
+                    c_data,
+                )))
+                .await;
+        }
+    }
+
+    async fn response_for(
+        &mut self,
+        s_id: SwarmID,
+        neighbor_id: GnomeId,
+        request: NeighborRequest,
+    ) -> NeighborResponse {
+        match request {
+            NeighborRequest::Ping => NeighborResponse::Pong,
+            NeighborRequest::VillageSummary => {
+                let summary = if s_id == self.active_swarm.swarm_id {
+                    let contents: HashSet<ContentID> = self
+                        .active_swarm
+                        .tag_to_cid
+                        .values()
+                        .flat_map(|set| set.iter().map(|(_d, c_id, _h)| *c_id))
+                        .collect();
+                    trf(
+                        "neighbor.summary",
+                        &[
+                            &self.active_swarm.swarm_name,
+                            &self.active_swarm.manifest.tag_names(None).len(),
+                            &contents.len(),
+                        ],
+                    )
+                } else {
+                    format!("{}", self.my_name)
+                };
+                NeighborResponse::VillageSummary(summary)
             }
-            8 => {
-                // odpowiedź
-                let c_data = CastData::new(vec![98, 88, 78, 68, 58, 48, 38, 28, 18, 8]).unwrap();
-                let _r = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::FromApp(LibRequest::CustomNeighborResponse(
-                        self.my_name.clone(),
-                        neighbor_id,
-                        251,
-                        c_data,
-                    )))
+            NeighborRequest::FriendRequest(text) => {
+                self.notify(
+                    Severity::Info,
+                    trf("neighbor.friend_request_from", &[&neighbor_id, &text]),
+                    Some(NoteAction::AcceptFriend(neighbor_id)),
+                )
+                .await;
+                NeighborResponse::FriendRequestReceived
+            }
+            NeighborRequest::Content(c_id) => {
+                let header = if s_id == self.active_swarm.swarm_id {
+                    self.active_swarm
+                        .tag_to_cid
+                        .values()
+                        .flat_map(|set| set.iter())
+                        .find(|(_d, c, _h)| *c == c_id)
+                        .map(|(_d, _c, header)| header.clone())
+                } else {
+                    None
+                };
+                NeighborResponse::Content(c_id, header)
+            }
+            NeighborRequest::Chat(message) => {
+                NeighborResponse::ChatReceived(self.receive_chat(s_id, neighbor_id, message).await)
+            }
+        }
+    }
+
+    async fn serve_neighbor_response(&mut self, neighbor_id: GnomeId, resp_id: u8, bytes: Vec<u8>) {
+        let Some((nonce, response)) = NeighborResponse::decode(resp_id, bytes) else {
+            log_warn!("Unable to decode neighbor response {}", resp_id);
+            return;
+        };
+        let Some(&(s_id, expected_from, req_id, sent_at)) =
+            self.pending_neighbor_requests.get(&nonce)
+        else {
+            log_warn!(
+                "Late or unexpected response {} from {}",
+                resp_id,
+                neighbor_id
+            );
+            return;
+        };
+        let answers_request =
+            resp_id == req_id || matches!(response, NeighborResponse::Unsupported(_));
+        if expected_from != neighbor_id || !answers_request {
+            // Keep waiting for the real answer
+            log_warn!(
+                "Dropping response {} from {} to request {} sent to {}",
+                resp_id,
+                neighbor_id,
+                req_id,
+                expected_from
+            );
+            return;
+        }
+        self.pending_neighbor_requests.remove(&nonce);
+        let note = match response {
+            NeighborResponse::ChatReceived(joined) => {
+                if let Some(room) = self.chats.get_mut(&s_id) {
+                    if joined {
+                        room.members.insert(neighbor_id);
+                    } else {
+                        room.members.remove(&neighbor_id);
+                    }
+                }
+                return;
+            }
+            NeighborResponse::Pong => trf(
+                "neighbor.pong",
+                &[&neighbor_id, &sent_at.elapsed().as_millis()],
+            ),
+            NeighborResponse::VillageSummary(summary) => summary,
+            NeighborResponse::FriendRequestReceived => {
+                trf("neighbor.got_friend_request", &[&neighbor_id])
+            }
+            NeighborResponse::Content(c_id, Some(header)) => format!("CID-{}: {}", c_id, header),
+            NeighborResponse::Content(c_id, None) => {
+                trf("neighbor.unknown_cid", &[&neighbor_id, &c_id])
+            }
+            NeighborResponse::Unsupported(version) => trf(
+                "neighbor.unsupported",
+                &[&neighbor_id, &request_name(req_id), &version],
+            ),
+        };
+        self.notify(Severity::Info, note, None).await;
+    }
+
+    async fn neighbor_request_timed_out(&mut self, nonce: u32) {
+        if let Some((_s_id, neighbor_id, req_id, _sent_at)) =
+            self.pending_neighbor_requests.remove(&nonce)
+        {
+            if req_id == CHAT_ID {
+                // Chat goes to every neighbor, one of them being slow is no news
+                log_debug!("Chat message to {} not confirmed", neighbor_id);
+                return;
+            }
+            self.notify(
+                Severity::Warning,
+                trf("neighbor.timed_out", &[&request_name(req_id), &neighbor_id]),
+                None,
+            )
+            .await;
+        }
+    }
+
+    fn chat_room(&mut self, s_id: SwarmID) -> &mut ChatRoom {
+        self.chats
+            .entry(s_id)
+            .or_insert_with(|| ChatRoom::new(s_id))
+    }
+
+    fn open_chat_menu(&mut self) {
+        let s_id = self.active_swarm.swarm_id;
+        let actions = self.chat_room(s_id).available_actions();
+        let options = actions.iter().map(|a| a.text()).collect();
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+        self.state = TuiState::ChatMenu(actions);
+    }
+
+    async fn run_chat_action(&mut self, action: Option<ChatAction>) {
+        let s_id = self.active_swarm.swarm_id;
+        match action {
+            Some(ChatAction::Join) => {
+                let room = self.chat_room(s_id);
+                room.joined = true;
+                room.push_info(tr("chat.joined"));
+                self.send_chat(s_id, ChatKind::Joined, String::new()).await;
+                self.open_chat_menu();
+            }
+            Some(ChatAction::Leave) => {
+                self.send_chat(s_id, ChatKind::Left, String::new()).await;
+                let room = self.chat_room(s_id);
+                room.joined = false;
+                room.push_info(tr("chat.left"));
+                self.open_chat_menu();
+            }
+            Some(ChatAction::Write) => {
+                self.state = TuiState::ChatWrite;
+                let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+                    (false, true),
+                    tr("editor.chat_message"),
+                    None,
+                    false,
+                    Some(1000),
+                ));
+            }
+            Some(ChatAction::ShowLog) => {
+                self.show_chat_log();
+                self.state = TuiState::ChatLog;
+            }
+            Some(ChatAction::Back) | None => {
+                self.state = TuiState::MainSt;
+            }
+        }
+    }
+
+    fn show_chat_log(&mut self) {
+        let s_id = self.active_swarm.swarm_id;
+        let my_id = self.my_name.founder;
+        let room = self.chat_room(s_id);
+        let text = room.log_text(my_id);
+        let members = room.members.len();
+        let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+            (true, false),
+            trf("chat.title", &[&self.active_swarm.swarm_name, &members]),
+            Some(text),
+            true,
+            None,
+        ));
+    }
+
+    // Sends a message of ours to every neighbor in given swarm
+    async fn send_chat(&mut self, s_id: SwarmID, kind: ChatKind, text: String) {
+        let my_id = self.my_name.founder;
+        let room = self.chat_room(s_id);
+        let message = room.compose(my_id, kind, text);
+        if kind == ChatKind::Text {
+            room.push_message(&message);
+        }
+        if room.neighbors.is_empty() {
+            room.push_info(tr("chat.nobody"));
+        }
+        self.pass_chat(s_id, message, None).await;
+    }
+
+    // Shows a chat message when we are in the room and passes it on,
+    // returns whether we are in the room
+    async fn receive_chat(&mut self, s_id: SwarmID, from: GnomeId, message: ChatMessage) -> bool {
+        let viewing = matches!(self.state, TuiState::ChatLog | TuiState::ChatWrite);
+        let room = self.chat_room(s_id);
+        if message.author == from {
+            match message.kind {
+                ChatKind::Left => room.members.remove(&from),
+                _joined_or_text => room.members.insert(from),
+            };
+        }
+        let joined = room.joined;
+        if !room.remember(message.author, message.id) {
+            return joined;
+        }
+        if joined {
+            room.push_message(&message);
+            if message.kind == ChatKind::Text && !viewing {
+                if let Some(s_name) = self.swarm_name_for(s_id) {
+                    self.notify(
+                        Severity::Info,
+                        trf("chat.new_message", &[&message.author, &s_name]),
+                        Some(NoteAction::SwitchToSwarm(s_name.clone())),
+                    )
                     .await;
+                }
             }
-            o => {
-                eprintln!("Unexpected Neighbor context item {o}");
+        }
+        self.pass_chat(s_id, message, Some(from)).await;
+        joined
+    }
+
+    // Sends message to every neighbor in swarm, except the one we got it from
+    async fn pass_chat(&mut self, s_id: SwarmID, message: ChatMessage, from: Option<GnomeId>) {
+        let Some(s_name) = self.swarm_name_for(s_id) else {
+            log_warn!("Chat message for unknown swarm {}", s_id);
+            return;
+        };
+        let neighbors: Vec<GnomeId> = self
+            .chat_room(s_id)
+            .neighbors
+            .iter()
+            .filter(|n| Some(**n) != from)
+            .copied()
+            .collect();
+        let request = NeighborRequest::Chat(message);
+        for neighbor_id in neighbors {
+            let nonce = self.next_nonce;
+            self.next_nonce = self.next_nonce.wrapping_add(1);
+            let Some(c_data) = request.encode(nonce) else {
+                log_warn!("Unable to encode chat message");
+                return;
+            };
+            let _ = self
+                .to_app_mgr_send
+                .send(ToAppMgr::FromApp(LibRequest::CustomNeighborRequest(
+                    s_name.clone(),
+                    neighbor_id,
+                    CHAT_ID,
+                    c_data,
+                )))
+                .await;
+            self.pending_neighbor_requests
+                .insert(nonce, (s_id, neighbor_id, CHAT_ID, Instant::now()));
+            spawn(neighbor_request_timeout(
+                self.to_user_send.clone(),
+                nonce,
+                NEIGHBOR_REQUEST_TIMEOUT,
+            ));
+        }
+    }
+
+    async fn chat_neighbor_left(&mut self, s_id: SwarmID, n_id: GnomeId) {
+        let Some(room) = self.chats.get_mut(&s_id) else {
+            return;
+        };
+        room.neighbors.retain(|n| *n != n_id);
+        if !room.joined {
+            room.members.remove(&n_id);
+            return;
+        }
+        if room.members.remove(&n_id) {
+            room.push_info(trf("chat.member_left", &[&n_id]));
+        }
+        if room.neighbors.is_empty() {
+            room.push_info(tr("chat.channel_gone"));
+            if let Some(s_name) = self.swarm_name_for(s_id) {
+                self.notify(
+                    Severity::Warning,
+                    trf("chat.channel_gone_in", &[&s_name]),
+                    None,
+                )
+                .await;
             }
         }
     }
-    async fn run_cmenu_action_on_field(&mut self, action: usize) {
+
+    fn swarm_name_for(&self, s_id: SwarmID) -> Option<SwarmName> {
+        if s_id == self.active_swarm.swarm_id {
+            return Some(self.active_swarm.swarm_name.clone());
+        }
+        self.swarm_stats
+            .iter()
+            .find(|(_n, stats)| stats.swarm_id == s_id)
+            .map(|(s_name, _stats)| s_name.clone())
+    }
+
+    async fn run_cmenu_action_on_field(&mut self, action: MenuAction) {
         match action {
-            1 => {
+            MenuAction::PasteLink => {
                 //TODO: now we have to open up Creator filled with
                 // Link data provided in clipboard, and allow user to create a Link
                 // TODO: first we have to make sure clipboard holds SwarmName
                 // and not SwarmID
                 if let Some((s_name, c_id)) = self.clipboard.take() {
-                    let _ = self
-                        .notification_sender
-                        .send(Some(format!("O właśnie :D")))
+                    self.notify(Severity::Info, tr("content.link_pasted"), None)
                         .await;
                     let descr = Description::new(format!("Link to {}-{}", s_name, c_id)).unwrap();
                     self.run_link_creator(None, s_name, AppType::Catalog, c_id, descr, vec![]);
                 } else {
-                    let _ = self
-                        .notification_sender
-                        .send(Some(format!("Najpierw skopiuj Zawartość")))
+                    self.notify(Severity::Warning, tr("content.copy_first"), None)
                         .await;
                 }
             }
-            8 => {
+            MenuAction::Chat => {
+                self.open_chat_menu();
+            }
+            MenuAction::ListSearches => {
                 //TODO
-                eprintln!("Shold list all Searches running");
+                log_debug!("Shold list all Searches running");
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::FromApp(LibRequest::ListSearches))
                     .await;
             }
             other => {
-                //TODO
-                eprintln!("{} Context Menu action on Content", other);
+                log_debug!("{:?} Context Menu action on Field", other);
+            }
+        }
+    }
+    async fn run_menu_action(&mut self, tile: TileType, action: MenuAction) {
+        match tile {
+            TileType::Home(_g_id) => {
+                self.run_cmenu_action_on_home(action).await;
+            }
+            TileType::Content(d_type, c_id) => {
+                self.run_cmenu_action_on_content(c_id, d_type, action).await;
+            }
+            TileType::Field => {
+                self.run_cmenu_action_on_field(action).await;
+            }
+            TileType::Neighbor(n_id) => {
+                self.run_cmenu_action_on_neighbor(n_id, action).await;
+            }
+            other => {
+                log_debug!("{:?} tile does not support Context Menu action", other);
+            }
+        }
+    }
+    // Palette runs the same actions as context menus do,
+    // so it is bound by the same rules
+    async fn run_menu_command(&mut self, tile: TileType, action: MenuAction) {
+        let context = MenuContext {
+            change_denied: self.change_denied(),
+            clipboard_full: self.clipboard.is_some(),
+        };
+        let reason = menu_for(&tile, &context)
+            .into_iter()
+            .find(|(a, _r)| *a == action)
+            .and_then(|(_a, reason)| reason);
+        if let Some(reason) = reason {
+            self.notify(
+                Severity::Warning,
+                trf("command.unavailable", &[&reason]),
+                None,
+            )
+            .await;
+            return;
+        }
+        self.run_menu_action(tile, action).await;
+    }
+    // Commands from palette that have no key binding, those
+    // that have one are run by TUI as if that key was pressed.
+    // Returns an application to switch to, if user asked for one.
+    async fn run_command(
+        &mut self,
+        command: Command,
+        argument: &str,
+        config_dir: &Path,
+    ) -> Option<(AppType, SwarmID, SwarmName)> {
+        let home = TileType::Home(self.my_name.founder);
+        match command {
+            Command::ShowTags => self.run_menu_command(home, MenuAction::ShowTags).await,
+            Command::ShowDataTypes => self.run_menu_command(home, MenuAction::ShowDataTypes).await,
+            Command::CreateContent => self.run_menu_command(home, MenuAction::CreateContent).await,
+            Command::Invitations => self.run_menu_command(home, MenuAction::Invitations).await,
+            Command::ActiveSwarms => self.run_menu_command(home, MenuAction::ActiveSwarms).await,
+            Command::AddTag | Command::AddDataType if argument.is_empty() => {
+                let action = if command == Command::AddTag {
+                    MenuAction::AddTag
+                } else {
+                    MenuAction::AddDataType
+                };
+                self.run_menu_command(home, action).await;
+            }
+            Command::AddTag | Command::AddDataType => {
+                if let Some(reason) = self.change_denied() {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unavailable", &[&reason]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                }
+                let Ok(tag) = Tag::new(argument.to_string()) else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.bad_name", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                let msg = if command == Command::AddTag {
+                    FromCatalogView::AddTags(vec![tag])
+                } else {
+                    FromCatalogView::AddDataType(tag)
+                };
+                let _ = self.to_user_send.send(InternalMsg::Catalog(msg)).await;
+            }
+            Command::Search if argument.is_empty() => {
+                self.run_menu_command(home, MenuAction::AddSearch).await;
+            }
+            Command::Search => {
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::FromApp(LibRequest::Search(argument.to_string())))
+                    .await;
+            }
+            Command::ListSearches => {
+                self.run_menu_command(TileType::Field, MenuAction::ListSearches)
+                    .await
+            }
+            Command::Chat => {
+                self.run_menu_command(TileType::Field, MenuAction::Chat)
+                    .await
+            }
+            Command::PasteLink => {
+                self.run_menu_command(TileType::Field, MenuAction::PasteLink)
+                    .await
+            }
+            Command::OpenContent
+            | Command::CopyLink
+            | Command::AppendNote
+            | Command::RemovePage => {
+                let Some((d_type, c_id)) = self.content_named(argument) else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unknown_content", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                let action = match command {
+                    Command::CopyLink => MenuAction::CopyLink,
+                    Command::AppendNote => MenuAction::AppendNote,
+                    Command::RemovePage => MenuAction::RemoveNote,
+                    _open => {
+                        if d_type.is_link() {
+                            self.follow_link(c_id).await;
+                        } else {
+                            self.query_content_for_indexer(c_id).await;
+                        }
+                        return None;
+                    }
+                };
+                self.run_menu_command(TileType::Content(d_type, c_id), action)
+                    .await;
+            }
+            Command::Street => {
+                let tag = self
+                    .active_swarm
+                    .manifest
+                    .tag_names(None)
+                    .into_iter()
+                    .find(|name| name.eq_ignore_ascii_case(argument))
+                    .and_then(|name| Tag::new(name).ok());
+                let Some(tag) = tag else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unknown_street", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                if let Some(state) = self.present_street(tag, 0).await {
+                    self.state = state;
+                }
+            }
+            Command::SwitchSwarm | Command::LeaveSwarm => {
+                let Some((s_name, s_id, app_type)) = self.swarm_named(argument) else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unknown_swarm", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                if command == Command::LeaveSwarm {
+                    let _ = self.to_tui.send(ToCatalogView::DisplayConfirm(
+                        tr("dialog.leave_swarm_title"),
+                        trf("dialog.leave_swarm", &[&s_name]),
+                    ));
+                    self.state = TuiState::ConfirmLeaveSwarm(s_name);
+                    return None;
+                }
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name.clone())))
+                    .await;
+                match app_type {
+                    Some(app_type) if !app_type.is_catalog() => {
+                        return Some((app_type, s_id, s_name));
+                    }
+                    _catalog => {
+                        self.state = TuiState::MainSt;
+                        let _ = self.to_tui.send(ToCatalogView::SwapTiles(s_name.founder));
+                    }
+                }
+            }
+            Command::Reconnect
+            | Command::Pin
+            | Command::Unpin
+            | Command::Watch
+            | Command::Unwatch => {
+                let Some((s_name, _s_id, _app_type)) = self.swarm_named(argument) else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unknown_swarm", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                match command {
+                    Command::Reconnect => {
+                        self.state = TuiState::MainSt;
+                        self.reconnect_swarm(s_name, config_dir).await;
+                    }
+                    Command::Pin | Command::Unpin => {
+                        let pinned = read_autojoin(config_dir).contains(&s_name);
+                        if pinned != (command == Command::Pin) {
+                            self.toggle_autojoin(s_name, config_dir).await;
+                        } else {
+                            let id = if pinned {
+                                "swarm.pinned"
+                            } else {
+                                "swarm.unpinned"
+                            };
+                            self.notify(Severity::Info, trf(id, &[&s_name]), None).await;
+                        }
+                    }
+                    _watch => {
+                        let watched = self.watch_list.swarms.contains(&s_name);
+                        if watched != (command == Command::Watch) {
+                            self.toggle_watch_swarm(s_name, config_dir).await;
+                        } else {
+                            let id = if watched {
+                                "swarm.watched"
+                            } else {
+                                "swarm.unwatched"
+                            };
+                            self.notify(Severity::Info, trf(id, &[&s_name]), None).await;
+                        }
+                    }
+                }
+            }
+            Command::Ping
+            | Command::VillageSummary
+            | Command::FriendRequest
+            | Command::AskForContent => {
+                let Some(neighbor_id) = GnomeId::from_string(argument.to_string()) else {
+                    self.notify(
+                        Severity::Warning,
+                        trf("command.unknown_neighbor", &[&argument]),
+                        None,
+                    )
+                    .await;
+                    return None;
+                };
+                let action = match command {
+                    Command::Ping => MenuAction::Ping,
+                    Command::VillageSummary => MenuAction::VillageSummary,
+                    Command::FriendRequest => MenuAction::FriendRequest,
+                    _ask => MenuAction::AskForContent,
+                };
+                self.run_menu_command(TileType::Neighbor(neighbor_id), action)
+                    .await;
+            }
+            Command::BootstrapNeighbors => self.open_neighbors_panel(config_dir),
+            Command::VillageSettings => self.open_config_panel(),
+            other => {
+                log_debug!("{} command is run by TUI", other.name());
             }
         }
+        None
+    }
+    // Content of active swarm with given ID
+    fn content_named(&self, argument: &str) -> Option<(DataType, ContentID)> {
+        let c_id = argument.parse::<ContentID>().ok()?;
+        self.active_swarm
+            .tag_to_cid
+            .values()
+            .flat_map(|set| set.iter())
+            .find(|(_d, c, _h)| *c == c_id)
+            .map(|(d_type, c_id, _h)| (*d_type, *c_id))
+    }
+    // Swarm we know of, named with or without it's founder
+    fn swarm_named(&self, argument: &str) -> Option<(SwarmName, SwarmID, Option<AppType>)> {
+        self.swarm_stats
+            .iter()
+            .find(|(s_name, _stats)| s_name.name == argument || s_name.to_string() == argument)
+            .map(|(s_name, stats)| (s_name.clone(), stats.swarm_id, stats.app_type))
     }
     async fn follow_link(&mut self, c_id: ContentID) {
-        eprintln!("In follow_link");
+        log_debug!("In follow_link");
         // TODO
         // 1 read contents
         self.state = TuiState::ReadLinkToFollow(c_id, None);
@@ -3443,8 +5514,45 @@ you should edit 'storage.rules' text file in config dir."#
             )))
             .await;
     }
+    // Only founder may change swarm's data, unless swarm's Data policy
+    // (or Default one, when Data is not defined) lets us do that as well
+    fn change_denied(&self) -> Option<String> {
+        if self.my_name.founder == self.active_swarm.swarm_name.founder {
+            return None;
+        }
+        let policies = &self.active_swarm.manifest.policy_reg;
+        let requirement = policies
+            .get(&Policy::Data)
+            .or_else(|| policies.get(&Policy::Default));
+        match requirement {
+            None => Some(tr("cmenu.founder_only")),
+            Some(requirement) if self.meets(requirement) => None,
+            Some(_requirement) => Some(tr("cmenu.policy_denies")),
+        }
+    }
+    // Whether we have capabilities given requirement asks for.
+    // Data byte conditions depend on what is being changed,
+    // so those can only be checked by swarm and count as met here.
+    fn meets(&self, requirement: &Requirement) -> bool {
+        match requirement {
+            Requirement::And(left, right) => self.meets(left) && self.meets(right),
+            Requirement::Or(left, right) => self.meets(left) || self.meets(right),
+            Requirement::Has(Capabilities::Founder) => {
+                self.my_name.founder == self.active_swarm.swarm_name.founder
+            }
+            Requirement::Has(capability) => self
+                .active_swarm
+                .manifest
+                .capability_reg
+                .get(capability)
+                .map(|members| members.get_all_members().contains(&self.my_name.founder))
+                .unwrap_or(false),
+            Requirement::None => true,
+            _data_byte => true,
+        }
+    }
     async fn query_content_for_indexer(&mut self, c_id: ContentID) {
-        eprintln!("In query_content_for_indexer");
+        log_debug!("In query_content_for_indexer");
         //TODO: first we need to retrieve Pages in order to have something to present
         if self.state.is_showing_street() {
             self.state = TuiState::ReadRequestForIndexer(c_id);
@@ -3457,14 +5565,161 @@ you should edit 'storage.rules' text file in config dir."#
                 )))
                 .await;
         } else {
-            eprintln!("Can not run query when in state: {:?}", self.state);
+            log_warn!("Can not run query when in state: {:?}", self.state);
+        }
+    }
+
+    async fn handle_key(&mut self, key: Key) {
+        if !self.dev_mode {
+            log_debug!("Key {:?} ignored, developer mode is off", key);
+            return;
+        }
+        let experiment = self
+            .keymap
+            .action(KeyContext::Debug, &key)
+            .filter(|action| is_experiment(*action));
+        if let Some(experiment) = experiment {
+            self.run_experiment(experiment).await;
+        } else {
+            log_debug!("No experiment bound to {:?}", key);
+        }
+    }
+
+    fn show_debug_panel(&mut self) {
+        if !self.dev_mode {
+            log_debug!("Debug panel is only available in developer mode");
+            return;
         }
+        self.state = TuiState::DebugPanel;
+        let options = EXPERIMENTS
+            .iter()
+            .map(|e| experiment_line(*e, &self.keymap))
+            .collect();
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
+    }
+
+    async fn notify(&mut self, severity: Severity, text: String, action: Option<NoteAction>) {
+        let note = Notification::new(severity, text, action);
+        if self.notifications.len() >= NOTIFICATION_HISTORY {
+            self.notifications.pop_front();
+        }
+        self.notifications.push_back(note.clone());
+        let _ = self.notification_sender.send(NoteCmd::Show(note)).await;
+    }
+
+    fn show_notification_history(&mut self) {
+        self.state = TuiState::NotificationHistory;
+        let mut options = Vec::with_capacity(self.notifications.len() + 2);
+        if self.notifications_paused {
+            options.push(tr("notify.resume"));
+        } else {
+            options.push(tr("notify.pause"));
+        }
+        options.push(tr("notify.clear"));
+        for note in self.notifications.iter().rev() {
+            options.push(note.line());
+        }
+        let _ = self.to_tui.send(ToCatalogView::DisplayIndexer(options));
     }
 
-    async fn handle_key(&self, key: Key) -> bool {
-        match key {
-            Key::U => {
-                eprintln!("Keyboard request UploadData");
+    async fn run_note_action(&mut self, action: NoteAction, config_dir: &Path) {
+        log_debug!("Running notification action: {:?}", action);
+        match action {
+            NoteAction::OpenContent(s_name, c_id) => {
+                if s_name == self.active_swarm.swarm_name {
+                    self.state = TuiState::MainSt;
+                    self.query_content_for_indexer(c_id).await;
+                } else {
+                    let founder = s_name.founder;
+                    let _ = self
+                        .to_app_mgr_send
+                        .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name.clone())))
+                        .await;
+                    let _ = self.to_tui.send(ToCatalogView::SwapTiles(founder));
+                    // Content gets read once target swarm becomes active
+                    self.state = TuiState::ReadLinkToFollow(c_id, Some((s_name, c_id)));
+                }
+            }
+            NoteAction::SwitchToSwarm(s_name) => {
+                self.state = TuiState::MainSt;
+                let founder = s_name.founder;
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
+                    .await;
+                let _ = self.to_tui.send(ToCatalogView::SwapTiles(founder));
+            }
+            NoteAction::AcceptFriend(friend) => {
+                self.state = TuiState::MainSt;
+                self.accept_friend(friend, config_dir).await;
+            }
+            NoteAction::ShowLog => {
+                self.show_log_viewer(config_dir);
+            }
+        }
+    }
+
+    // Friend's village gets pinned, so that we sync it
+    // right away and on every start
+    async fn accept_friend(&mut self, friend: GnomeId, config_dir: &Path) {
+        let s_name = SwarmName {
+            founder: friend,
+            name: "/".to_string(),
+        };
+        let mut pinned = read_autojoin(config_dir);
+        if !pinned.contains(&s_name) {
+            pinned.push(s_name.clone());
+            write_autojoin(config_dir, &pinned);
+        }
+        let joined = self.swarm_stats.contains_key(&s_name);
+        if !joined && !self.auto_joining.contains(&s_name) {
+            log_debug!("Auto-joining {}", s_name);
+            self.auto_joining.push(s_name.clone());
+            // Switching back right away could happen before library
+            // starts joining, so we stay where we are once it is joined
+            if self.return_after_join.is_none() {
+                self.return_after_join =
+                    Some((s_name.clone(), self.active_swarm.swarm_name.clone()));
+            }
+            let _ = self
+                .to_app_mgr_send
+                .send(ToAppMgr::FromApp(LibRequest::SetActiveApp(s_name)))
+                .await;
+        }
+        self.notify(
+            Severity::Info,
+            trf("neighbor.friend_accepted", &[&friend]),
+            None,
+        )
+        .await;
+    }
+
+    // Recent warnings and errors, newest on top
+    fn show_log_viewer(&mut self, config_dir: &Path) {
+        let problems = recent_problems();
+        let text = if problems.is_empty() {
+            tr("log.empty")
+        } else {
+            problems
+                .into_iter()
+                .rev()
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+        self.state = TuiState::LogViewer;
+        let _ = self.to_tui.send(ToCatalogView::DisplayEditor(
+            (true, false),
+            trf("log.title", &[&log_path(config_dir).display()]),
+            Some(text),
+            true,
+            None,
+        ));
+    }
+
+    async fn run_experiment(&mut self, experiment: KeyAction) {
+        log_info!("Running experiment: {}", experiment.description());
+        match experiment {
+            KeyAction::BroadcastTestData => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::BroadcastSend(
@@ -3473,50 +5728,28 @@ you should edit 'storage.rules' text file in config dir."#
                     ))
                     .await;
             }
-            Key::J => {
-                // TODO: indirect send via AppMgr
-                // let _ = gmgr_send.send(ManagerRequest::JoinSwarm("trzat".to_string()));
-            }
-            Key::ShiftQ => {
-                // TODO: indirect send via AppMgr
-                // let _ = gmgr_send.send(ManagerRequest::Disconnect);
-                // keep_running = false;
-                return true;
-            }
-            Key::ShiftD => {
-                // We want to test if ChangeDiameter works
-                eprintln!("ChangeDiameter request from keyboard");
+            KeyAction::ChangeDiameter => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::ChangeDiameter(self.active_swarm.swarm_id, 10))
                     .await;
             }
-            // TODO: this should be served separately by sending to user_req
-            Key::B => {
+            KeyAction::StartBroadcast => {
                 let _ = self.to_app_mgr_send.send(ToAppMgr::StartBroadcast).await;
-                // let res = service_request.send(Request::StartBroadcast);
-                // b_req_sent = res.is_ok();
             }
-            Key::ShiftB => {
-                eprintln!("ShiftB");
+            KeyAction::EndBroadcast => {
                 let _ = self.to_app_mgr_send.send(ToAppMgr::EndBroadcast).await;
-                // let res = service_request.send(Request::StartBroadcast);
-                // b_req_sent = res.is_ok();
             }
-            Key::CtrlB => {
+            KeyAction::UnsubscribeBroadcast => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::UnsubscribeBroadcast)
                     .await;
             }
-            Key::M => {
-                eprintln!("Key::M MulticastStart");
+            KeyAction::StartMulticast => {
                 let _ = self.to_app_mgr_send.send(ToAppMgr::StartMulticast).await;
-                // let res = service_request.send(Request::StartBroadcast);
-                // b_req_sent = res.is_ok();
             }
-            Key::N => {
-                eprintln!("Key::N MulticastSend");
+            KeyAction::MulticastTestData => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::MulticastSend(
@@ -3525,63 +5758,51 @@ you should edit 'storage.rules' text file in config dir."#
                     ))
                     .await;
             }
-            Key::ShiftM => {
-                eprintln!("ShiftM: End Multicast");
+            KeyAction::EndMulticast => {
                 let _ = self.to_app_mgr_send.send(ToAppMgr::EndMulticast).await;
-                // let res = service_request.send(Request::StartBroadcast);
-                // b_req_sent = res.is_ok();
             }
-            Key::O => {
-                eprintln!("Key::O: UnsubscribeMulticast");
+            KeyAction::UnsubscribeMulticast => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::UnsubscribeMulticast)
                     .await;
             }
-            Key::ShiftO => {
-                eprintln!("Key::ShiftO: SubscribeMulticast");
+            KeyAction::SubscribeMulticast => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::SubscribeMulticast)
                     .await;
             }
-            Key::ShiftN => {
-                // TODO: SendToBCastSource
-                eprintln!("ShiftN: SendToMCastSource");
+            KeyAction::SendToMCastSource => {
                 let data = CastData::new(vec![1, 2, 3, 4, 5, 6]).unwrap();
-                let bc_id = CastID(0);
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::SendToMCastSource(
                         self.active_swarm.swarm_id,
-                        bc_id,
+                        CastID(0),
                         data,
                     ))
                     .await;
             }
-            Key::ShiftF => {
-                let f_name = SwarmName::new(self.my_name.founder, "F".to_string()).unwrap();
-                eprintln!("ShiftF: Start a Forum swarm");
-                let _ = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::StartNewSwarm(AppType::Forum, f_name))
-                    .await;
-            }
-            Key::ShiftS => {
-                // TODO: SendToBCastSource
-                eprintln!("SendToBCastSource");
+            KeyAction::SendToBCastSource => {
                 let data = CastData::new(vec![1, 2, 3, 4, 5, 6]).unwrap();
-                let bc_id = CastID(0);
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::SendToBCastSource(
                         self.active_swarm.swarm_id,
-                        bc_id,
+                        CastID(0),
                         data,
                     ))
                     .await;
             }
-            Key::CtrlU => {
+            KeyAction::StartForum => {
+                let f_name = SwarmName::new(self.my_name.founder, "F".to_string()).unwrap();
+                let _ = self
+                    .to_app_mgr_send
+                    .send(ToAppMgr::StartNewSwarm(AppType::Forum, f_name))
+                    .await;
+            }
+            KeyAction::TransformLink => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::TransformLinkRequest(Box::new(
@@ -3589,50 +5810,20 @@ you should edit 'storage.rules' text file in config dir."#
                     )))
                     .await;
             }
-            Key::CtrlM => {
-                // Here we send request to read manifest data
+            KeyAction::ReadManifest => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::FromApp(LibRequest::ReadAllPages(
                         self.active_swarm.swarm_id,
                         0,
-                        // true,
                     )))
                     .await;
             }
-            Key::CtrlN => {
-                let _res = self
-                    .notification_sender
-                    .send(Some(format!("Testowa notka")))
-                    .await;
-                // let _ = self.to_app_mgr_send.send(ToAppMgr::ListNeighbors).await;
-            }
-            // Key::C => {
-            // TODO: rework this to support TagLen|Tags|DescrLen|Desc DID=0 structure
-            //     let mut data_vec = vec![Data::new(vec![70, 91]).unwrap(); 129];
-            //     data_vec[0] = Data::new(vec![0, 70, 91]).unwrap();
-            //     let _ = self
-            //         .to_app_mgr_send
-            //         .send(ToAppMgr::ChangeContent(
-            //             self.active_swarm.swarm_id,
-            //             1,
-            //             DataType::from(0),
-            //             data_vec,
-            //         ))
-            //         .await;
-            // }
-            Key::S => {
-                let _ = self
-                    .to_app_mgr_send
-                    .send(ToAppMgr::AppendContent(
-                        self.active_swarm.swarm_id,
-                        DataType::Data(0),
-                        Data::empty(0),
-                    ))
+            KeyAction::TestNotification => {
+                self.notify(Severity::Info, tr("debug.test_note"), None)
                     .await;
             }
-            Key::ShiftA => {
-                // TODO: extend this message with actual content
+            KeyAction::AppendEmptyContent => {
                 let _ = self
                     .to_app_mgr_send
                     .send(ToAppMgr::AppendContent(
@@ -3642,12 +5833,13 @@ you should edit 'storage.rules' text file in config dir."#
                     ))
                     .await;
             }
-            Key::ShiftU => {
+            KeyAction::StartUnicast => {
                 let _ = self.to_app_mgr_send.send(ToAppMgr::StartUnicast).await;
             }
-            _ => eprintln!(),
+            other => {
+                log_debug!("{:?} is not an experiment", other);
+            }
         }
-        false
     }
 
     async fn present_main_street(&self) {
@@ -3674,16 +5866,16 @@ you should edit 'storage.rules' text file in config dir."#
         let _ = self.to_app_mgr_send.send(ToAppMgr::ListNeighbors).await;
     }
     async fn present_neighbors(&self, mut pg_id: u16) -> Option<TuiState> {
-        eprint!("present_neighbors {pg_id} ");
+        log_debug!("present_neighbors {pg_id}");
         let curr_state = self.state.clone();
         if !curr_state.is_showing_neighbors_only() {
-            eprintln!("curr state: {curr_state:?}");
+            log_debug!("curr state: {curr_state:?}");
             let _ = self.to_tui.send(ToCatalogView::SwapTiles(
                 self.active_swarm.swarm_name.founder,
             ));
             let _ = self.to_app_mgr_send.send(ToAppMgr::ListNeighbors).await;
             let _ = self.to_tui.send(ToCatalogView::StreetNames(
-                vec![(Tag::new("Neighbors".to_string()).unwrap(), vec![])],
+                vec![(Tag::new(tr("street.neighbors")).unwrap(), vec![])],
                 true,
             ));
             return Some(TuiState::NeighborSt(0, vec![]));
@@ -3692,18 +5884,18 @@ you should edit 'storage.rules' text file in config dir."#
             //TODO
             let should_ask_for_neigh_list = nvec.is_empty();
             if should_ask_for_neigh_list {
-                eprintln!("asking for nlist");
+                log_debug!("asking for nlist");
                 let _ = self.to_tui.send(ToCatalogView::SwapTiles(
                     self.active_swarm.swarm_name.founder,
                 ));
                 let _ = self.to_app_mgr_send.send(ToAppMgr::ListNeighbors).await;
                 let _ = self.to_tui.send(ToCatalogView::StreetNames(
-                    vec![(Tag::new("Neighbors".to_string()).unwrap(), vec![])],
+                    vec![(Tag::new(tr("street.neighbors")).unwrap(), vec![])],
                     true,
                 ));
                 return Some(TuiState::NeighborSt(0, vec![]));
             }
-            eprintln!("applying nlogic");
+            log_debug!("applying nlogic");
             let max_pg_no = (nvec.len() / (self.tiles_on_screen - 1)) as u16;
             if pg_id == u16::MAX {
                 pg_id = max_pg_no;
@@ -3723,7 +5915,7 @@ you should edit 'storage.rules' text file in config dir."#
             let _ = self.to_tui.send(ToCatalogView::Neighbors(nvec_clone, true));
             Some(TuiState::NeighborSt(pg_id, nvec))
         } else {
-            eprintln!("curr state is different!");
+            log_debug!("curr state is different!");
             None
         }
     }
@@ -3731,7 +5923,7 @@ you should edit 'storage.rules' text file in config dir."#
         // DONE: we need extend this logic to be able to traverse Street pages
         let mut return_opt = Some(TuiState::Street(st_name.clone(), pg_no));
         self.visible_streets.1 = vec![st_name.clone()];
-        eprintln!("St sel: {st_name:?}");
+        log_debug!("St sel: {st_name:?}");
         let _ = self.to_tui.send(ToCatalogView::SwapTiles(
             self.active_swarm.swarm_name.founder,
         ));
@@ -3743,13 +5935,13 @@ you should edit 'storage.rules' text file in config dir."#
 
             csv.sort_by_key(|(_d, cid, _s)| *cid);
             let csv_len = csv.len();
-            eprintln!("csv_len: {csv_len}");
+            log_debug!("csv_len: {csv_len}");
             let last_page = pg_no == u16::MAX;
-            eprintln!("last: {last_page}, pg_no: {pg_no}");
+            log_debug!("last: {last_page}, pg_no: {pg_no}");
             let max_page_no = (csv_len / (self.tiles_on_screen - 1)) as u16;
             if last_page {
                 pg_no = max_page_no;
-                eprintln!(
+                log_debug!(
                     "{csv_len}/{}={} set to max: {max_page_no}",
                     self.tiles_on_screen - 1,
                     csv_len / (self.tiles_on_screen - 1)
@@ -3775,19 +5967,19 @@ you should edit 'storage.rules' text file in config dir."#
             //     pg_no = 0;
             //     return_opt = Some(TuiState::Street(st_name.clone(), 0));
             // }
-            eprintln!("1: {pg_no}");
+            log_debug!("1: {pg_no}");
             let num_to_drain = (pg_no as usize) * (self.tiles_on_screen - 1);
             // eprintln!("2");
             // let num_to_truncate =
             //     csv_len.saturating_sub(num_to_drain + self.tiles_on_screen + 1);
-            eprintln!("3");
+            log_debug!("3");
             // num_to_drain
             // };
-            eprint!("to drain: {num_to_drain}");
+            log_debug!("to drain: {num_to_drain}");
             // eprint!("to truncate: {num_to_truncate}");
             csv.drain(0..num_to_drain);
             csv.truncate(self.tiles_on_screen - 1);
-            eprintln!("csv new len: {}", csv.len());
+            log_debug!("csv new len: {}", csv.len());
             // if pg_no.is_none() {
             // if row % 2 == 0 {
             //     csv.truncate(self.tiles_on_screen - 1);
@@ -3821,9 +6013,9 @@ you should edit 'storage.rules' text file in config dir."#
             popped
         };
         // TODO: present a no-tag street only if it has contents
-        eprintln!("Selected Street names: {street_names:?}");
+        log_debug!("Selected Street names: {street_names:?}");
         if street_names.len() == 1 && street_names[0].is_empty() {
-            eprintln!("im in");
+            log_debug!("im in");
             let get_res = self.active_swarm.tag_to_cid.get(&Tag::empty());
 
             if get_res.is_none_or(|untagged| untagged.is_empty()) {
@@ -3875,3 +6067,48 @@ you should edit 'storage.rules' text file in config dir."#
 //     };
 //     (tag_ids, header)
 // }
+
+const PUB_IPS_REFRESH_PERIOD: Duration = Duration::from_secs(300);
+
+// Periodically ask AppMgr for our public IPs, so that our home
+// Manifest follows any change of our address.
+// Task ends once something is sent via stop channel or it gets closed.
+async fn refresh_public_ips(
+    to_app_mgr_send: ASender<ToAppMgr>,
+    stop: AReceiver<()>,
+    period: Duration,
+) {
+    loop {
+        sleep(period).await;
+        if stop.try_recv().is_ok() || stop.is_closed() {
+            break;
+        }
+        log_debug!("Refreshing our public IPs");
+        if to_app_mgr_send
+            .send(ToAppMgr::FromApp(LibRequest::ProvidePublicIPs))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    log_info!("Public IPs refresher is done");
+}
+
+const NEIGHBOR_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+const NOTIFICATION_HISTORY: usize = 100;
+const ACTIVITY_FEED: usize = 100;
+const WATCH_SETTLE_TIME: Duration = Duration::from_secs(30);
+
+async fn neighbor_request_timeout(sender: ASender<InternalMsg>, nonce: u32, timeout: Duration) {
+    sleep(timeout).await;
+    let _ = sender
+        .send(InternalMsg::NeighborRequestTimeout(nonce))
+        .await;
+}
+
+async fn connect_timeout(sender: ASender<InternalMsg>, nonce: u32, timeout: Duration) {
+    sleep(timeout).await;
+    let _ = sender.send(InternalMsg::ConnectTimeout(nonce)).await;
+}
@@ -0,0 +1,187 @@
+use super::chat::{ChatKind, ChatMessage};
+use crate::common::i18n::tr;
+use dapp_lib::prelude::{CastData, ContentID, GnomeId};
+
+// Every neighbor request and response carries following payload:
+// Version(1) | Nonce(4) | Body
+// Message type is sent as request/response id, response to a request
+// uses the same id as that request, except for Unsupported.
+// Nonce allows to match a response with a request we are waiting for.
+// Chat body is: Author(8) | MessageId(4) | Kind(1) | Text,
+// it's response tells whether neighbor is in the chat room.
+pub const PROTOCOL_VERSION: u8 = 1;
+pub const CHAT_ID: u8 = 5;
+const UNSUPPORTED_ID: u8 = 255;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeighborRequest {
+    Ping,
+    VillageSummary,
+    FriendRequest(String),
+    Content(ContentID),
+    Chat(ChatMessage),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeighborResponse {
+    Pong,
+    VillageSummary(String),
+    FriendRequestReceived,
+    Content(ContentID, Option<String>),
+    ChatReceived(bool),
+    Unsupported(u8),
+}
+
+impl NeighborRequest {
+    pub fn id(&self) -> u8 {
+        match self {
+            NeighborRequest::Ping => 1,
+            NeighborRequest::VillageSummary => 2,
+            NeighborRequest::FriendRequest(_t) => 3,
+            NeighborRequest::Content(_c) => 4,
+            NeighborRequest::Chat(_m) => CHAT_ID,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        request_name(self.id())
+    }
+
+    pub fn encode(&self, nonce: u32) -> Option<CastData> {
+        let mut bytes = header(nonce);
+        match self {
+            NeighborRequest::Ping | NeighborRequest::VillageSummary => {}
+            NeighborRequest::FriendRequest(text) => bytes.extend_from_slice(text.as_bytes()),
+            NeighborRequest::Content(c_id) => bytes.extend_from_slice(&c_id.to_be_bytes()),
+            NeighborRequest::Chat(message) => {
+                bytes.extend_from_slice(&message.author.0.to_be_bytes());
+                bytes.extend_from_slice(&message.id.to_be_bytes());
+                bytes.push(message.kind.byte());
+                bytes.extend_from_slice(message.text.as_bytes());
+            }
+        }
+        CastData::new(bytes).ok()
+    }
+
+    // On success returns a nonce and a request,
+    // on failure a version of request we could not understand.
+    pub fn decode(id: u8, bytes: Vec<u8>) -> Result<(u32, Self), u8> {
+        let (nonce, body) = split_header(&bytes)?;
+        let request = match id {
+            1 => NeighborRequest::Ping,
+            2 => NeighborRequest::VillageSummary,
+            3 => NeighborRequest::FriendRequest(
+                String::from_utf8(body.to_vec()).map_err(|_e| PROTOCOL_VERSION)?,
+            ),
+            4 => {
+                if body.len() < 2 {
+                    return Err(PROTOCOL_VERSION);
+                }
+                NeighborRequest::Content(u16::from_be_bytes([body[0], body[1]]))
+            }
+            CHAT_ID => {
+                if body.len() < 13 {
+                    return Err(PROTOCOL_VERSION);
+                }
+                let mut author = [0; 8];
+                author.copy_from_slice(&body[0..8]);
+                let kind = ChatKind::from_byte(body[12]).ok_or(PROTOCOL_VERSION)?;
+                NeighborRequest::Chat(ChatMessage {
+                    author: GnomeId(u64::from_be_bytes(author)),
+                    id: u32::from_be_bytes([body[8], body[9], body[10], body[11]]),
+                    kind,
+                    text: String::from_utf8(body[13..].to_vec()).map_err(|_e| PROTOCOL_VERSION)?,
+                })
+            }
+            _other => return Err(PROTOCOL_VERSION),
+        };
+        Ok((nonce, request))
+    }
+}
+
+impl NeighborResponse {
+    pub fn id(&self) -> u8 {
+        match self {
+            NeighborResponse::Pong => 1,
+            NeighborResponse::VillageSummary(_t) => 2,
+            NeighborResponse::FriendRequestReceived => 3,
+            NeighborResponse::Content(_c, _h) => 4,
+            NeighborResponse::ChatReceived(_j) => CHAT_ID,
+            NeighborResponse::Unsupported(_v) => UNSUPPORTED_ID,
+        }
+    }
+
+    pub fn encode(&self, nonce: u32) -> Option<CastData> {
+        let mut bytes = header(nonce);
+        match self {
+            NeighborResponse::Pong | NeighborResponse::FriendRequestReceived => {}
+            NeighborResponse::VillageSummary(text) => bytes.extend_from_slice(text.as_bytes()),
+            NeighborResponse::Content(c_id, header_opt) => {
+                bytes.extend_from_slice(&c_id.to_be_bytes());
+                if let Some(header) = header_opt {
+                    bytes.push(1);
+                    bytes.extend_from_slice(header.as_bytes());
+                } else {
+                    bytes.push(0);
+                }
+            }
+            NeighborResponse::ChatReceived(joined) => bytes.push(*joined as u8),
+            NeighborResponse::Unsupported(version) => bytes.push(*version),
+        }
+        CastData::new(bytes).ok()
+    }
+
+    pub fn decode(id: u8, bytes: Vec<u8>) -> Option<(u32, Self)> {
+        let (nonce, body) = split_header(&bytes).ok()?;
+        let response = match id {
+            1 => NeighborResponse::Pong,
+            2 => NeighborResponse::VillageSummary(String::from_utf8(body.to_vec()).ok()?),
+            3 => NeighborResponse::FriendRequestReceived,
+            4 => {
+                if body.len() < 3 {
+                    return None;
+                }
+                let c_id = u16::from_be_bytes([body[0], body[1]]);
+                let header_opt = if body[2] == 1 {
+                    Some(String::from_utf8(body[3..].to_vec()).ok()?)
+                } else {
+                    None
+                };
+                NeighborResponse::Content(c_id, header_opt)
+            }
+            CHAT_ID => NeighborResponse::ChatReceived(*body.first()? == 1),
+            UNSUPPORTED_ID => NeighborResponse::Unsupported(*body.first()?),
+            _other => return None,
+        };
+        Some((nonce, response))
+    }
+}
+
+pub fn request_name(id: u8) -> String {
+    tr(match id {
+        1 => "neighbor.ping",
+        2 => "neighbor.village_summary",
+        3 => "neighbor.friend_request",
+        4 => "neighbor.content_request",
+        CHAT_ID => "neighbor.chat_message",
+        _other => "neighbor.unknown_request",
+    })
+}
+
+fn header(nonce: u32) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.push(PROTOCOL_VERSION);
+    bytes.extend_from_slice(&nonce.to_be_bytes());
+    bytes
+}
+
+fn split_header(bytes: &[u8]) -> Result<(u32, &[u8]), u8> {
+    if bytes.len() < 5 {
+        return Err(PROTOCOL_VERSION);
+    }
+    if bytes[0] != PROTOCOL_VERSION {
+        return Err(bytes[0]);
+    }
+    let nonce = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+    Ok((nonce, &bytes[5..]))
+}
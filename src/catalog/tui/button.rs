@@ -1,4 +1,5 @@
-use animaterm::prelude::Glyph;
+use crate::common::text_width::cells;
+use crate::common::theme::{glyph, Role};
 use animaterm::prelude::Graphic;
 use animaterm::prelude::Manager;
 use std::collections::HashMap;
@@ -17,12 +18,11 @@ impl Button {
         alt_text: Option<&str>,
         mgr: &mut Manager,
     ) -> Self {
-        let mut g = Glyph::char(' ');
-        let mut gr = Glyph::char(' ');
-        gr.set_reverse(true);
+        let mut g = glyph(Role::Button, ' ');
+        let mut gr = glyph(Role::ButtonSelected, ' ');
         let mut frame_deselect = vec![g; size.0 * size.1];
         let mut frame_select = vec![gr; size.0 * size.1];
-        let mut c_iter = text.chars().into_iter();
+        let mut c_iter = cells(text, size.0.saturating_sub(2)).into_iter();
         let row = size.1 >> 1;
         let mut added = 1;
         while let Some(c) = c_iter.next() {
@@ -47,7 +47,7 @@ impl Button {
             gr.set_char(' ');
             frame_deselect = vec![g; size.0 * size.1];
             frame_select = vec![gr; size.0 * size.1];
-            let mut c_iter = text.chars().into_iter();
+            let mut c_iter = cells(text, size.0.saturating_sub(2)).into_iter();
             let row = size.1 >> 1;
             let mut added = 1;
             while let Some(c) = c_iter.next() {
@@ -81,14 +81,11 @@ impl Button {
         mgr.move_graphic(self.g_id, 2, (0, 0));
     }
     pub fn rename(&self, mgr: &mut Manager, new_name: &String) {
-        let mut ga = Glyph::char(' ');
-        ga.set_color(animaterm::Color::yellow());
-        ga.set_reverse(true);
-        let g = Glyph::char(' ');
-        let mut gr = Glyph::char(' ');
-        gr.set_reverse(true);
+        let ga = glyph(Role::ButtonActive, ' ');
+        let g = glyph(Role::Button, ' ');
+        let gr = glyph(Role::ButtonSelected, ' ');
 
-        let c_iter = new_name.chars().into_iter();
+        let c_iter = cells(new_name, self.size.0.saturating_sub(2)).into_iter();
         let row = self.size.1 >> 1;
         // TODO: fix deselecting button reverse
         let loop_params = [(1, gr), (0, g), (2, ga)];
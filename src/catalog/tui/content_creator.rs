@@ -1,4 +1,5 @@
 use super::button::Button;
+use super::help::Help;
 // use super::editor::Editor;
 // use super::selector::Selector;
 // use super::serve_editor;
@@ -8,6 +9,10 @@ use animaterm::prelude::Graphic;
 use animaterm::prelude::Manager;
 // use dapp_lib::prelude::DataType;
 // use dapp_lib::Data;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::cells;
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
 use std::collections::HashMap;
 // TODO: A full screen window with options to
 // - select data type
@@ -43,6 +48,8 @@ pub struct Creator {
     button_cancel: Button,
     width: usize,
     _height: usize,
+    keymap: Keymap,
+    help: Help,
 }
 
 impl Creator {
@@ -56,11 +63,15 @@ impl Creator {
         let g_id = mgr
             .add_graphic(Graphic::new(width, height, 0, library, None), 0, (0, 0))
             .unwrap();
-        let button_dtypes = Button::new((8, 3), 1, (2, 1), "Edytuj", None, mgr);
-        let button_descr = Button::new((8, 3), 1, (2, 5), "Edytuj", Some("Pokaż"), mgr);
-        let button_tags = Button::new((8, 3), 1, (2, 9), "Edytuj", Some("Pokaż"), mgr);
-        let button_apply = Button::new((8, 3), 1, (2, 13), "Zapisz", None, mgr);
-        let button_cancel = Button::new((8, 3), 1, (2, 17), "Anuluj", Some("Zamknij"), mgr);
+        let edit = tr("creator.edit");
+        let show = tr("creator.show");
+        let button_dtypes = Button::new((8, 3), 1, (2, 1), &edit, None, mgr);
+        let button_descr = Button::new((8, 3), 1, (2, 5), &edit, Some(&show), mgr);
+        let button_tags = Button::new((8, 3), 1, (2, 9), &edit, Some(&show), mgr);
+        let button_apply = Button::new((8, 3), 1, (2, 13), &tr("creator.save"), None, mgr);
+        let close = tr("creator.close");
+        let button_cancel =
+            Button::new((8, 3), 1, (2, 17), &tr("creator.cancel"), Some(&close), mgr);
         Creator {
             g_id,
             display_id,
@@ -71,9 +82,16 @@ impl Creator {
             button_cancel,
             width,
             _height: height,
+            keymap: Keymap::new(),
+            help: Help::new(mgr),
         }
     }
 
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
     pub fn cleanup(&self, main_display: usize, mgr: &mut Manager) {
         mgr.restore_display(self.display_id, true);
         mgr.restore_display(main_display, false);
@@ -83,7 +101,7 @@ impl Creator {
         // eprintln!("UT: '{}'", text);
         let mut g = Glyph::plain();
         let p = Glyph::plain();
-        let mut char_iter = text.chars();
+        let mut char_iter = cells(&text, self.width - 13).into_iter();
         for x in 12..self.width - 1 {
             if let Some(c) = char_iter.next() {
                 g.set_char(c);
@@ -97,14 +115,10 @@ impl Creator {
     fn update_description(&self, mgr: &mut Manager, text: &str) {
         let mut g = Glyph::plain();
         let p = Glyph::plain();
-        let mut char_iter = text.chars();
+        let mut char_iter = cells(text, self.width - 13).into_iter();
         for x in 12..self.width - 1 {
             if let Some(c) = char_iter.next() {
-                if c == '\n' {
-                    g.set_char(' ');
-                } else {
-                    g.set_char(c);
-                }
+                g.set_char(c);
                 mgr.set_glyph(self.g_id, g, x, 6);
             } else {
                 mgr.set_glyph(self.g_id, p, x, 6);
@@ -113,10 +127,10 @@ impl Creator {
     }
 
     fn update_d_type(&self, mgr: &mut Manager, text: &str) {
-        let d_text = &format!("DataType: {}", text);
+        let d_text = &trf("creator.data_type", &[&text]);
         let p = Glyph::plain();
         let mut g = Glyph::plain();
-        let mut char_iter = d_text.chars();
+        let mut char_iter = cells(d_text, self.width - 13).into_iter();
         for x in 12..self.width - 1 {
             if let Some(c) = char_iter.next() {
                 g.set_char(c);
@@ -179,9 +193,9 @@ impl Creator {
     ) -> CreatorResult {
         mgr.restore_display(self.display_id, true);
         self.update_d_type(mgr, &d_type);
-        let t_text = format!("Tags: {}", tags);
+        let t_text = trf("creator.tags", &[&tags]);
         self.update_tags(mgr, t_text);
-        let s_text = format!("Description: {}", description);
+        let s_text = trf("creator.description", &[&description]);
         self.update_description(mgr, &s_text);
         mgr.move_graphic(self.g_id, 3, (0, 0));
         let all_buttons = vec![
@@ -208,22 +222,26 @@ impl Creator {
 
         loop {
             if let Some(key) = mgr.read_key() {
-                match key {
-                    animaterm::Key::Escape => {
+                let Some(action) = self.keymap.action(KeyContext::Creator, &key) else {
+                    continue;
+                };
+                match action {
+                    KeyAction::Finish => {
                         available_buttons[selected_button].deselect(mgr, read_only);
                         return CreatorResult::Cancel;
                     }
-                    animaterm::Key::Down | animaterm::Key::CtrlN => {
+                    KeyAction::MoveDown => {
                         available_buttons[selected_button].deselect(mgr, read_only);
                         selected_button = self.next_button(read_only, selected_button);
                         available_buttons[selected_button].select(mgr, read_only);
                     }
-                    animaterm::Key::Up | animaterm::Key::CtrlP => {
+                    KeyAction::MoveUp => {
                         available_buttons[selected_button].deselect(mgr, read_only);
                         selected_button = self.prev_button(read_only, selected_button);
                         available_buttons[selected_button].select(mgr, read_only);
                     }
-                    animaterm::Key::Enter => {
+                    KeyAction::Help => self.help.show(KeyContext::Creator, mgr),
+                    KeyAction::Select => {
                         mgr.move_graphic(self.g_id, 0, (0, 0));
                         mgr.move_graphic(self.button_dtypes.g_id, 0, (0, 0));
                         mgr.move_graphic(self.button_descr.g_id, 0, (0, 0));
@@ -250,12 +268,12 @@ impl Creator {
                                 return CreatorResult::Cancel;
                             }
                             other => {
-                                eprintln!("{}", other);
+                                log_debug!("{}", other);
                             }
                         }
                     }
-                    _other => {
-                        //TODO
+                    other => {
+                        log_debug!("{} has no meaning in Creator", other.name());
                     }
                 }
             }
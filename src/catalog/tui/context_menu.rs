@@ -2,46 +2,50 @@ use std::collections::HashMap;
 
 use animaterm::prelude::*;
 
-#[derive(Copy, Clone)]
-struct FrameSet {
-    starting_frame: usize,
-    last_frame: usize,
+use super::help::Help;
+use crate::common::text_width::cells;
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+
+// Single line of a context menu. Disabled items are shown greyed
+// and can not be chosen, reason why is shown in menu's bottom line
+// when such item is highlighted.
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    pub label: String,
+    pub disabled: Option<String>,
 }
 
-impl FrameSet {
-    fn next_frame(&self, current: usize) -> usize {
-        if self.starting_frame == self.last_frame {
-            return self.starting_frame;
-        } else if current >= self.last_frame {
-            self.starting_frame + 1
-        } else {
-            current + 1
+impl MenuItem {
+    pub fn enabled(label: String) -> Self {
+        MenuItem {
+            label,
+            disabled: None,
         }
     }
-    fn prev_frame(&self, current: usize) -> usize {
-        if self.starting_frame == self.last_frame {
-            return self.starting_frame;
-        } else if current <= self.starting_frame + 1 {
-            self.last_frame
-        } else {
-            current - 1
+
+    pub fn disabled(label: String, reason: String) -> Self {
+        MenuItem {
+            label,
+            disabled: Some(reason),
         }
     }
 }
+
 pub struct CMenu {
     g_id: usize,
     width: usize,
     height: usize,
-    current_set: FrameSet,
-    last_defined_set: usize,
-    defined_sets: HashMap<usize, FrameSet>,
+    keymap: Keymap,
+    help: Help,
 }
 
 impl CMenu {
     pub fn new(mgr: &mut Manager) -> Self {
-        let (_cols, _rows) = mgr.screen_size();
-        let width = 15;
-        let height = 8;
+        let width = 20;
+        // Eight items and a line for the reason,
+        // longer menus are scrolled
+        let height = 9;
         let g = Glyph::char(' ');
         let frame = vec![g; width * height];
         let mut library = HashMap::new();
@@ -54,129 +58,127 @@ impl CMenu {
                 (0, 0),
             )
             .unwrap();
-        let mut defined_sets = HashMap::new();
-        defined_sets.insert(
-            0,
-            FrameSet {
-                starting_frame: 0,
-                last_frame: 0,
-            },
-        );
         CMenu {
             g_id,
             width,
             height,
-            current_set: FrameSet {
-                starting_frame: 0,
-                last_frame: 0,
-            },
-            last_defined_set: 0,
-            defined_sets,
+            keymap: Keymap::new(),
+            help: Help::new(mgr),
         }
     }
-    pub fn show(&mut self, mgr: &mut Manager, set_nr: usize, offset: (isize, isize)) -> usize {
-        self.current_set(set_nr);
-        let mut selection = self.current_set.starting_frame;
-        // eprintln!("Set nr: {}", set_nr);
-        mgr.set_graphic(self.g_id, self.current_set.starting_frame, false);
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
+    // Returns index of chosen item, or None when menu was closed
+    pub fn show(
+        &mut self,
+        mgr: &mut Manager,
+        items: &[MenuItem],
+        offset: (isize, isize),
+    ) -> Option<usize> {
+        if items.is_empty() {
+            return None;
+        }
+        // Keep whole menu on screen
+        let (cols, _rows) = mgr.screen_size();
+        let offset = (
+            offset.0.min(cols as isize - self.width as isize).max(0),
+            offset.1,
+        );
+        let mut selection = items.iter().position(|i| i.disabled.is_none()).unwrap_or(0);
+        let mut scroll = self.scroll_to(selection, 0);
+        self.draw(mgr, items, selection, scroll);
         mgr.move_graphic(self.g_id, 4, offset);
-        loop {
+        let chosen = loop {
             if let Some(key) = mgr.read_key() {
-                match key {
-                    Key::Up | Key::K | Key::CtrlP => {
-                        selection = self.current_set.prev_frame(selection);
-                        // eprintln!("UP {}", selection);
-                        mgr.set_graphic(self.g_id, selection, false);
+                match self.keymap.action(KeyContext::Menu, &key) {
+                    Some(KeyAction::MoveUp) => {
+                        selection = if selection == 0 {
+                            items.len() - 1
+                        } else {
+                            selection - 1
+                        };
+                        scroll = self.scroll_to(selection, scroll);
+                        self.draw(mgr, items, selection, scroll);
                     }
-                    Key::Down | Key::J | Key::CtrlN => {
-                        selection = self.current_set.next_frame(selection);
-                        // eprintln!("DOWN {:?}", selection);
-                        mgr.set_graphic(self.g_id, selection, false);
+                    Some(KeyAction::MoveDown) => {
+                        selection = (selection + 1) % items.len();
+                        scroll = self.scroll_to(selection, scroll);
+                        self.draw(mgr, items, selection, scroll);
                     }
-                    Key::Enter | Key::Space => {
-                        mgr.set_graphic(self.g_id, 0, false);
-                        mgr.move_graphic(self.g_id, 0, (offset.0 * -1, offset.1 * -1));
-                        return selection - self.current_set.starting_frame;
-                    }
-                    Key::Escape => {
-                        mgr.set_graphic(self.g_id, 0, false);
-                        mgr.move_graphic(self.g_id, 0, (offset.0 * -1, offset.1 * -1));
-                        return 0;
+                    Some(KeyAction::Select) => {
+                        if items[selection].disabled.is_none() {
+                            break Some(selection);
+                        }
                     }
-                    _ => {}
+                    Some(KeyAction::Finish) => break None,
+                    Some(KeyAction::Help) => self.help.show(KeyContext::Menu, mgr),
+                    _other => {}
                 }
             }
-        }
-        // selection
+        };
+        mgr.move_graphic(self.g_id, 0, (offset.0 * -1, offset.1 * -1));
+        chosen
     }
 
-    pub fn add_set(&mut self, mgr: &mut Manager, options: Vec<String>) -> usize {
-        //TODO
-        let new_frames_count = options.len() + 1;
-        if new_frames_count == 1 {
-            return self.last_defined_set;
-        } else if new_frames_count > self.height + 1 {
-            return self.last_defined_set;
+    // First visible item, so that selected one is shown
+    fn scroll_to(&self, selection: usize, scroll: usize) -> usize {
+        let visible = self.height - 1;
+        if selection < scroll {
+            selection
+        } else if selection >= scroll + visible {
+            selection + 1 - visible
+        } else {
+            scroll
         }
-        mgr.empty_frame(self.g_id);
-        if let Ok(AnimOk::FrameAdded(graphic_id, frame_id)) = mgr.read_result() {
-            if graphic_id == self.g_id {
-                let starting_frame = frame_id;
-                let mut last_frame = frame_id;
-                let n_options = options.clone();
-                let mut n_options = n_options.iter();
-                for y in 0..self.height {
-                    self.set_row(y, n_options.next(), mgr, false);
-                }
-                for (row_nr, text) in options.into_iter().enumerate() {
-                    mgr.clone_frame(self.g_id, Some(starting_frame));
-                    if let Ok(AnimOk::FrameAdded(graphic_id, frame_id)) = mgr.read_result() {
-                        if graphic_id == self.g_id {
-                            last_frame = frame_id;
-                            self.set_row(row_nr, Some(&text), mgr, true);
-                        }
-                    }
-                }
+    }
 
-                // eprintln!("Adding set start: {}, stop: {}", starting_frame, last_frame);
-                self.last_defined_set += 1;
-                self.defined_sets.insert(
-                    self.last_defined_set,
-                    FrameSet {
-                        starting_frame,
-                        last_frame,
-                    },
-                );
-            }
+    fn draw(&self, mgr: &mut Manager, items: &[MenuItem], selection: usize, scroll: usize) {
+        let visible = self.height - 1;
+        for y in 0..visible {
+            let idx = scroll + y;
+            let (text, role) = match items.get(idx) {
+                Some(item) => {
+                    let role = match (idx == selection, item.disabled.is_some()) {
+                        (true, true) => Role::MenuSelectedDisabled,
+                        (true, false) => Role::MenuSelected,
+                        (false, true) => Role::MenuDisabled,
+                        (false, false) => Role::Option,
+                    };
+                    (item.label.as_str(), role)
+                }
+                None => ("", Role::Option),
+            };
+            self.set_row(y, text, role, mgr);
+        }
+        // Arrows tell that there are more items above or below
+        if scroll > 0 {
+            mgr.set_glyph(self.g_id, glyph(Role::Option, '▲'), self.width - 1, 0);
         }
-        mgr.set_graphic(self.g_id, 0, false);
-        self.last_defined_set
+        if scroll + visible < items.len() {
+            mgr.set_glyph(
+                self.g_id,
+                glyph(Role::Option, '▼'),
+                self.width - 1,
+                visible - 1,
+            );
+        }
+        let reason = items[selection]
+            .disabled
+            .as_ref()
+            .map(|reason| format!(" {}", reason))
+            .unwrap_or_default();
+        self.set_row(self.height - 1, &reason, Role::MenuDisabled, mgr);
     }
 
-    fn current_set(&mut self, id: usize) {
-        self.current_set = if id > self.last_defined_set {
-            *self.defined_sets.get(&0).unwrap()
-        } else {
-            *self.defined_sets.get(&id).unwrap()
-        };
-    }
-    fn set_row(&self, row_nr: usize, text: Option<&String>, mgr: &mut Manager, reversed: bool) {
-        let text = if let Some(txt) = text {
-            txt.clone()
-        } else {
-            String::new()
-        };
-        let mut iter = text.chars();
+    fn set_row(&self, row_nr: usize, text: &str, role: Role, mgr: &mut Manager) {
+        let mut iter = cells(text, self.width).into_iter();
         for x in 0..self.width {
-            let mut glyph = if let Some(char) = iter.next() {
-                Glyph::char(char)
-            } else {
-                Glyph::plain()
-            };
-            if reversed {
-                glyph.set_reverse(true);
-            }
-            mgr.set_glyph(self.g_id, glyph, x, row_nr);
+            let c = iter.next().unwrap_or(' ');
+            mgr.set_glyph(self.g_id, glyph(role, c), x, row_nr);
         }
     }
 }
@@ -0,0 +1,471 @@
+use crate::catalog::tui::help::Help;
+use crate::common::i18n::tr;
+use crate::common::text_width::{cells, char_width, str_width, WIDE_FILLER};
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
+use animaterm::prelude::map_private_char_to_key;
+use animaterm::utilities::message_box;
+use animaterm::{Glyph, Manager};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Answer {
+    Yes,
+    No,
+    Cancel,
+}
+
+// How a dialog was closed
+enum Outcome {
+    // Index of a button that was pressed
+    Button(usize),
+    Escaped,
+}
+
+// Contents of a single dialog. Focus goes through a field
+// (either text input or list of options) if there is one,
+// and then through every button, left to right.
+struct Form {
+    text: Vec<Vec<char>>,
+    input: Option<Vec<char>>,
+    // Input can not grow beyond that many bytes
+    byte_limit: usize,
+    // Position of cursor within input, in chars
+    cursor: usize,
+    options: Vec<String>,
+    option: usize,
+    option_scroll: usize,
+    buttons: Vec<String>,
+    focus: usize,
+}
+
+impl Form {
+    fn has_field(&self) -> bool {
+        self.input.is_some() || !self.options.is_empty()
+    }
+
+    fn field_focused(&self) -> bool {
+        self.has_field() && self.focus == 0
+    }
+
+    fn focused_button(&self) -> Option<usize> {
+        if self.has_field() {
+            self.focus.checked_sub(1)
+        } else {
+            Some(self.focus)
+        }
+    }
+
+    fn focus_count(&self) -> usize {
+        self.buttons.len() + if self.has_field() { 1 } else { 0 }
+    }
+
+    fn first_button(&self) -> usize {
+        if self.has_field() {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+// Small centered window drawn over whatever is on screen,
+// with a message, an optional text input or list of options
+// and a row of buttons. Tab moves focus, Enter presses focused
+// button, Escape cancels and first letter of a button presses it.
+pub struct Dialog {
+    g_id: usize,
+    // Cells available inside the frame
+    width: usize,
+    height: usize,
+    keymap: Keymap,
+    help: Help,
+}
+
+impl Dialog {
+    pub fn new(mgr: &mut Manager) -> Self {
+        let (cols, rows) = mgr.screen_size();
+        let width = cols.min(64);
+        let height = rows.min(14);
+        let m_box = message_box(
+            Some(String::new()),
+            String::new(),
+            Glyph::plain(),
+            width,
+            height,
+        );
+        let offset = (
+            ((cols - width) >> 1) as isize,
+            ((rows - height) >> 1) as isize,
+        );
+        let g_id = mgr.add_graphic(m_box, 0, offset).unwrap();
+        Dialog {
+            g_id,
+            width: width - 2,
+            height: height - 2,
+            keymap: Keymap::new(),
+            help: Help::new(mgr),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
+    // Yes/No/Cancel question, No is focused first
+    // so that a stray Enter does not destroy anything
+    pub fn confirm(&self, title: &str, text: &str, mgr: &mut Manager) -> Answer {
+        let mut form = self.form(
+            text,
+            vec![tr("dialog.yes"), tr("dialog.no"), tr("dialog.cancel")],
+        );
+        form.focus = 1;
+        match self.show(title, &mut form, mgr) {
+            Outcome::Button(0) => Answer::Yes,
+            Outcome::Button(1) => Answer::No,
+            _other => Answer::Cancel,
+        }
+    }
+
+    // Single line of text, None when user cancelled
+    pub fn prompt(
+        &self,
+        title: &str,
+        text: &str,
+        initial: &str,
+        byte_limit: usize,
+        mgr: &mut Manager,
+    ) -> Option<String> {
+        let mut form = self.form(text, vec![tr("dialog.ok"), tr("dialog.cancel")]);
+        let mut bytes = 0;
+        let input: Vec<char> = initial
+            .chars()
+            .take_while(|c| {
+                bytes += c.len_utf8();
+                bytes <= byte_limit
+            })
+            .collect();
+        form.cursor = input.len();
+        form.input = Some(input);
+        form.byte_limit = byte_limit;
+        match self.show(title, &mut form, mgr) {
+            Outcome::Button(0) => form.input.map(|i| i.into_iter().collect()),
+            _other => None,
+        }
+    }
+
+    // Index of chosen option, None when user cancelled
+    pub fn choose(
+        &self,
+        title: &str,
+        text: &str,
+        options: &[String],
+        mgr: &mut Manager,
+    ) -> Option<usize> {
+        if options.is_empty() {
+            return None;
+        }
+        let mut form = self.form(text, vec![tr("dialog.ok"), tr("dialog.cancel")]);
+        form.options = options.to_vec();
+        match self.show(title, &mut form, mgr) {
+            Outcome::Button(0) => Some(form.option),
+            _other => None,
+        }
+    }
+
+    fn form(&self, text: &str, buttons: Vec<String>) -> Form {
+        Form {
+            text: wrap(text, self.width),
+            input: None,
+            byte_limit: 0,
+            cursor: 0,
+            options: vec![],
+            option: 0,
+            option_scroll: 0,
+            buttons,
+            focus: 0,
+        }
+    }
+
+    fn show(&self, title: &str, form: &mut Form, mgr: &mut Manager) -> Outcome {
+        mgr.move_graphic(self.g_id, 3, (0, 0));
+        self.set_title(title, mgr);
+        self.draw_text(form, mgr);
+        self.draw_field(form, mgr);
+        self.draw_buttons(form, mgr);
+        let outcome = self.run(form, mgr);
+        mgr.move_graphic(self.g_id, 0, (0, 0));
+        outcome
+    }
+
+    fn run(&self, form: &mut Form, mgr: &mut Manager) -> Outcome {
+        loop {
+            let Some(ch) = mgr.read_char() else {
+                continue;
+            };
+            if let Some(action) = self.keymap.action_for_char(KeyContext::Dialog, ch) {
+                match action {
+                    KeyAction::NextField => {
+                        form.focus = (form.focus + 1) % form.focus_count();
+                    }
+                    KeyAction::PrevField => {
+                        form.focus = (form.focus + form.focus_count() - 1) % form.focus_count();
+                    }
+                    KeyAction::MoveLeft => {
+                        if form.field_focused() {
+                            form.cursor = form.cursor.saturating_sub(1);
+                        } else if form.focus > form.first_button() {
+                            form.focus -= 1;
+                        }
+                    }
+                    KeyAction::MoveRight => {
+                        if form.field_focused() {
+                            let len = form.input.as_ref().map_or(0, |i| i.len());
+                            form.cursor = (form.cursor + 1).min(len);
+                        } else if form.focus + 1 < form.focus_count() {
+                            form.focus += 1;
+                        }
+                    }
+                    KeyAction::MoveUp => {
+                        if form.field_focused() {
+                            form.option = form.option.saturating_sub(1);
+                        } else if form.has_field() {
+                            form.focus = 0;
+                        }
+                    }
+                    KeyAction::MoveDown => {
+                        if form.field_focused() {
+                            if form.option + 1 < form.options.len() {
+                                form.option += 1;
+                            } else {
+                                form.focus = 1;
+                            }
+                        }
+                    }
+                    KeyAction::LineStart => form.cursor = 0,
+                    KeyAction::LineEnd => {
+                        form.cursor = form.input.as_ref().map_or(0, |i| i.len());
+                    }
+                    KeyAction::Delete => {
+                        if let Some(input) = form.input.as_mut() {
+                            if form.field_focused() && form.cursor < input.len() {
+                                input.remove(form.cursor);
+                            }
+                        }
+                    }
+                    KeyAction::KillToEnd => {
+                        if let Some(input) = form.input.as_mut() {
+                            if form.field_focused() {
+                                input.truncate(form.cursor);
+                            }
+                        }
+                    }
+                    KeyAction::Select => {
+                        return Outcome::Button(form.focused_button().unwrap_or(0));
+                    }
+                    KeyAction::Finish => return Outcome::Escaped,
+                    KeyAction::Help => self.help.show(KeyContext::Dialog, mgr),
+                    other => {
+                        log_debug!("{} has no meaning in Dialog", other.name());
+                        continue;
+                    }
+                }
+            } else if ch == '\u{7f}' {
+                //Backspace
+                if let Some(input) = form.input.as_mut() {
+                    if form.field_focused() && form.cursor > 0 {
+                        form.cursor -= 1;
+                        input.remove(form.cursor);
+                    }
+                }
+            } else if map_private_char_to_key(ch).is_some() || ch.is_control() {
+                log_debug!("Undefined control char: {:?}", ch);
+                continue;
+            } else if form.input.is_some() && form.field_focused() {
+                let input = form.input.as_mut().unwrap();
+                let bytes: usize = input.iter().map(|c| c.len_utf8()).sum();
+                if bytes + ch.len_utf8() <= form.byte_limit {
+                    input.insert(form.cursor, ch);
+                    form.cursor += 1;
+                }
+            } else if let Some(idx) = shortcut(&form.buttons, ch) {
+                return Outcome::Button(idx);
+            } else {
+                continue;
+            }
+            self.draw_field(form, mgr);
+            self.draw_buttons(form, mgr);
+        }
+    }
+
+    fn set_title(&self, title: &str, mgr: &mut Manager) {
+        let mut chars = cells(title, self.width - 1).into_iter();
+        let mut g = Glyph::plain();
+        for x in 1..self.width + 1 {
+            g.set_char(chars.next().unwrap_or('─'));
+            mgr.set_glyph(self.g_id, g, x, 0);
+        }
+    }
+
+    // Rows left for text after field and buttons
+    fn text_rows(&self, form: &Form) -> usize {
+        let field_rows = if form.input.is_some() {
+            2
+        } else if !form.options.is_empty() {
+            form.options.len().min(self.height / 2) + 1
+        } else {
+            0
+        };
+        self.height.saturating_sub(field_rows + 2)
+    }
+
+    fn field_rows(&self, form: &Form) -> (usize, usize) {
+        let first = 1 + self.text_rows(form) + 1;
+        let last = self.height - 2;
+        (first, last)
+    }
+
+    fn draw_text(&self, form: &Form, mgr: &mut Manager) {
+        let mut lines = form.text.iter().take(self.text_rows(form));
+        for y in 1..self.height + 1 {
+            let mut line = if y <= self.text_rows(form) {
+                lines.next().cloned().unwrap_or_default().into_iter()
+            } else {
+                vec![].into_iter()
+            };
+            for x in 1..self.width + 1 {
+                mgr.set_glyph(self.g_id, Glyph::char(line.next().unwrap_or(' ')), x, y);
+            }
+        }
+    }
+
+    fn draw_field(&self, form: &mut Form, mgr: &mut Manager) {
+        let (first, last) = self.field_rows(form);
+        if let Some(input) = &form.input {
+            let focused = form.field_focused();
+            // Scroll input so that cursor is always visible
+            let mut start = 0;
+            while input_width(input, start, form.cursor) >= self.width {
+                start += 1;
+            }
+            let mut x = 1;
+            for (i, c) in input.iter().enumerate().skip(start) {
+                let w = char_width(*c);
+                if w == 0 {
+                    continue;
+                }
+                if x + w > self.width + 1 {
+                    break;
+                }
+                let role = if focused && i == form.cursor {
+                    Role::EditorCursor
+                } else {
+                    Role::EditorText
+                };
+                mgr.set_glyph(self.g_id, glyph(role, *c), x, first);
+                if w == 2 {
+                    mgr.set_glyph(self.g_id, glyph(role, WIDE_FILLER), x + 1, first);
+                }
+                x += w;
+            }
+            while x < self.width + 1 {
+                let role = if focused && x == 1 + input_width(input, start, form.cursor) {
+                    Role::EditorCursor
+                } else {
+                    Role::EditorText
+                };
+                mgr.set_glyph(self.g_id, glyph(role, ' '), x, first);
+                x += 1;
+            }
+        } else if !form.options.is_empty() {
+            let visible = last + 1 - first;
+            if form.option < form.option_scroll {
+                form.option_scroll = form.option;
+            } else if form.option >= form.option_scroll + visible {
+                form.option_scroll = form.option + 1 - visible;
+            }
+            for (row, y) in (first..last + 1).enumerate() {
+                let idx = form.option_scroll + row;
+                let text = form.options.get(idx).map(|o| o.as_str()).unwrap_or("");
+                let mut line = cells(text, self.width).into_iter();
+                for x in 1..self.width + 1 {
+                    let c = line.next().unwrap_or(' ');
+                    let g = if idx == form.option && form.field_focused() {
+                        glyph(Role::MenuSelected, c)
+                    } else if idx == form.option {
+                        glyph(Role::OptionSelected, c)
+                    } else {
+                        Glyph::char(c)
+                    };
+                    mgr.set_glyph(self.g_id, g, x, y);
+                }
+            }
+        }
+    }
+
+    fn draw_buttons(&self, form: &Form, mgr: &mut Manager) {
+        let labels: Vec<Vec<char>> = form
+            .buttons
+            .iter()
+            .map(|b| cells(&format!("[ {} ]", b), self.width))
+            .collect();
+        let total: usize = labels.iter().map(|l| l.len() + 1).sum();
+        let mut x = 1 + self.width.saturating_sub(total) / 2;
+        let y = self.height;
+        for x in 1..self.width + 1 {
+            mgr.set_glyph(self.g_id, Glyph::char(' '), x, y);
+        }
+        let focused = form.focused_button();
+        for (i, label) in labels.into_iter().enumerate() {
+            let role = if focused == Some(i) {
+                Role::ButtonSelected
+            } else {
+                Role::Button
+            };
+            for c in label {
+                if x > self.width {
+                    return;
+                }
+                mgr.set_glyph(self.g_id, glyph(role, c), x, y);
+                x += 1;
+            }
+            x += 1;
+        }
+    }
+}
+
+fn input_width(input: &[char], start: usize, end: usize) -> usize {
+    input[start..end].iter().map(|c| char_width(*c)).sum()
+}
+
+// Button whose label starts with given letter
+fn shortcut(buttons: &[String], ch: char) -> Option<usize> {
+    let ch = ch.to_lowercase().next()?;
+    buttons.iter().position(|b| {
+        b.chars()
+            .next()
+            .and_then(|f| f.to_lowercase().next())
+            .map_or(false, |f| f == ch)
+    })
+}
+
+// Text split into lines of at most width cells, breaking between words
+fn wrap(text: &str, width: usize) -> Vec<Vec<char>> {
+    let mut lines = vec![];
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && str_width(&line) + 1 + str_width(word) > width {
+                lines.push(cells(&line, width));
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(cells(&line, width));
+    }
+    lines
+}
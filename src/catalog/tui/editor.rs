@@ -1,7 +1,19 @@
+use crate::catalog::tui::help::Help;
 use crate::catalog::tui::Direction;
+use crate::common::external_editor;
+use crate::common::i18n::{tr, trf};
 use animaterm::utilities::message_box;
 use animaterm::{glyph, prelude::*};
 
+use crate::common::text_width::{cells, char_width, WIDE_FILLER};
+use crate::common::theme::{self, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::{log_debug, log_warn};
+use std::collections::VecDeque;
+
+const UNDO_DEPTH: usize = 100;
+const KILL_RING_SIZE: usize = 16;
+
 #[derive(Clone, Debug)]
 pub enum EditorResult {
     Text(String),
@@ -11,45 +23,100 @@ pub enum EditorResult {
     NextPage,
     LastPage,
 }
+
+// State of text before an edit, used for undo & redo
+#[derive(Clone)]
+struct Snapshot {
+    text: Vec<char>,
+    cursor: usize,
+}
+
+// What was done by previous key press, so that consecutive
+// kills are joined and typing a word is undone at once
+#[derive(Clone, Copy, PartialEq)]
+enum LastEdit {
+    Other,
+    Typing,
+    Kill,
+    Yank(usize),
+}
+
+// Text is held as a single buffer of chars, with '\n' separating lines.
+// For display it is split into rows no wider than the box (soft wrap),
+// only rows between scroll and scroll + height are visible.
+// Bottom frame line shows cursor position and how many bytes are used.
 pub struct Editor {
     pub g_id: usize,
     display_id: usize,
-    lines: Vec<String>,
-    cursor_position: (usize, usize),
+    text: Vec<char>,
+    // Index into text, cursor is placed before char at that index
+    cursor: usize,
+    // First visible row
+    scroll: usize,
     max_position: (usize, usize),
+    // What is currently drawn in text area, so that only changes are sent to Manager
+    painted: Vec<(char, Role)>,
+    status: String,
+    title: String,
+    // Shown in status line until next key press
+    notice: Option<String>,
     allow_newlines: bool,
     read_only: bool,
     can_edit: bool,
     byte_limit: Option<u16>,
+    keymap: Keymap,
+    help: Help,
+    // Selected text lies between mark and cursor
+    mark: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: LastEdit,
+    // Killed and copied texts, most recent first.
+    // Editor is passed between apps, so it is shared by Catalog and Forum.
+    kill_ring: VecDeque<String>,
 }
 impl Editor {
     pub fn new(mgr: &mut Manager) -> Self {
         let display_id = mgr.new_display(true);
+        let help = Help::new(mgr);
         let (cols, rows) = mgr.screen_size();
         let m_box = message_box(
-            Some("Text input".to_string()),
+            Some(tr("editor.text_input")),
             String::new(),
             Glyph::plain(),
             cols,
             rows,
         );
         let g_id = mgr.add_graphic(m_box, 0, (0, 0)).unwrap();
+        let max_position = (cols - 2, rows - 2);
         let mut editor = Editor {
             g_id,
             display_id,
-            lines: vec![String::new(); rows],
-            cursor_position: (2, 1),
-            max_position: (cols - 2, rows - 2),
+            text: vec![],
+            cursor: 0,
+            scroll: 0,
+            max_position,
+            painted: vec![('\0', Role::EditorText); (cols - 3) * (rows - 2)],
+            status: String::new(),
+            title: String::new(),
+            notice: None,
             allow_newlines: true,
             read_only: false,
             can_edit: true,
             byte_limit: None,
+            keymap: Keymap::new(),
+            help,
+            mark: None,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            last_edit: LastEdit::Other,
+            kill_ring: VecDeque::with_capacity(KILL_RING_SIZE),
         };
         editor.show(mgr);
         editor
     }
     pub fn cleanup(&self, main_display: usize, mgr: &mut Manager) {
-        eprintln!("Editor cleanup");
+        log_debug!("Editor cleanup");
         mgr.restore_display(self.display_id, true);
         mgr.restore_display(main_display, false);
     }
@@ -63,33 +130,35 @@ impl Editor {
     pub fn show(&mut self, mgr: &mut Manager) {
         mgr.restore_display(self.display_id, true);
         mgr.set_graphic(self.g_id, 0, true);
+        // Graphic was reset, so everything needs to be painted again
+        for cell in self.painted.iter_mut() {
+            cell.0 = '\0';
+        }
+        self.status.clear();
+    }
+
+    // Number of columns available for text
+    fn width(&self) -> usize {
+        self.max_position.0 - 1
+    }
+
+    // Number of rows available for text
+    fn height(&self) -> usize {
+        self.max_position.1
+    }
+
+    fn byte_len(&self) -> usize {
+        self.text.iter().map(|c| c.len_utf8()).sum()
     }
+
     pub fn take_text(&mut self, mgr: &mut Manager) -> String {
-        let mut result = String::new();
-        // for i in 0..self.text.len() {
-        //     let line = std::mem::replace(&mut self.text[i], String::new());
-        //     result.push_str(line.trim_end_matches(' '));
-        //     result.push(' ');
-        // }
-        // self.clear(mgr);
-        // self.move_to_line_start(mgr);
-        let mut glyph = Glyph::plain();
-        for row in 1..=self.max_position.1 {
-            self.cursor_position = (2, row);
-            let taken = self.remove_chars_from_cursor_to_end(mgr);
-            mgr.set_glyph(self.g_id, glyph, 2, row);
-            // eprintln!("Pushing: '{}'", taken);
-            result.push_str(&taken);
-        }
-        self.cursor_position = (2, 1);
-        glyph.set_blink(true);
-        glyph.set_reverse(true);
-        mgr.set_glyph(
-            self.g_id,
-            glyph,
-            self.cursor_position.0,
-            self.cursor_position.1,
-        );
+        let mut result: String = std::mem::take(&mut self.text).into_iter().collect();
+        self.cursor = 0;
+        self.scroll = 0;
+        self.mark = None;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.redraw(mgr);
         //A primitive way to enforce max String size
         // TODO: find a better solution
         if let Some(byte_limit) = self.byte_limit {
@@ -99,396 +168,354 @@ impl Editor {
         }
         result
     }
-    pub fn insert(&mut self, mgr: &mut Manager, ch: char) {
-        //TODO: we need to take into consideration self.byte_limit
-        //
-        if let Some(byte_limit) = self.byte_limit {
-            let mut cur_size = 0;
-            for line in &self.lines {
-                cur_size = cur_size + line.len();
-            }
-            if cur_size >= byte_limit as usize {
-                return;
-            }
-        }
-        let line_chars_count = self.lines[self.cursor_position.1].chars().count();
-        if line_chars_count >= self.max_position.0 - 2 {
-            return;
-        }
-        let glyph = if ch == '\n' {
-            Glyph::plain()
-        } else {
-            Glyph::char(ch)
-        };
-        if self.cursor_position.0 - 2 == line_chars_count {
-            self.lines[self.cursor_position.1].push(ch);
-        } else {
-            let mut chars = self.lines[self.cursor_position.1].chars();
-            let mut new_string = String::with_capacity(line_chars_count);
-            for _i in 0..self.cursor_position.0 - 2 {
-                if let Some(char) = chars.next() {
-                    new_string.push(char);
-                } else {
-                    new_string.push(' ');
+
+    // Terminal columns taken by text between start and end
+    fn columns(&self, start: usize, end: usize) -> usize {
+        self.text[start..end].iter().map(|c| char_width(*c)).sum()
+    }
+
+    // Rows of text as (start, end) indices, end is exclusive and
+    // never includes '\n'. Long lines are wrapped at last space
+    // that fits, or at box width if there is none.
+    // Width is counted in terminal columns, not chars.
+    fn layout(&self) -> Vec<(usize, usize)> {
+        let width = self.width();
+        let mut rows = vec![];
+        let mut line_start = 0;
+        loop {
+            let line_end = self.text[line_start..]
+                .iter()
+                .position(|c| *c == '\n')
+                .map(|p| line_start + p)
+                .unwrap_or(self.text.len());
+            let mut start = line_start;
+            loop {
+                let mut used = 0;
+                let mut end = start;
+                let mut last_space = None;
+                while end < line_end {
+                    let w = char_width(self.text[end]);
+                    if used + w > width {
+                        break;
+                    }
+                    if self.text[end] == ' ' && end > start {
+                        last_space = Some(end);
+                    }
+                    used += w;
+                    end += 1;
+                }
+                if end == line_end {
+                    rows.push((start, line_end));
+                    // Room for cursor placed after a full row
+                    if used >= width {
+                        rows.push((line_end, line_end));
+                    }
+                    break;
                 }
+                if end == start {
+                    // Single char wider than the box
+                    end = start + 1;
+                } else if let Some(space) = last_space {
+                    end = space + 1;
+                }
+                rows.push((start, end));
+                start = end;
             }
-            new_string.push(ch);
-            let mut i = 1;
-            while let Some(char) = chars.next() {
-                new_string.push(char);
-                mgr.set_glyph(
-                    self.g_id,
-                    Glyph::char(char),
-                    i + self.cursor_position.0,
-                    self.cursor_position.1,
-                );
-                i += 1;
+            if line_end == self.text.len() {
+                break;
             }
-            self.lines[self.cursor_position.1] = new_string;
+            line_start = line_end + 1;
         }
-        mgr.set_glyph(
-            self.g_id,
-            glyph,
-            self.cursor_position.0,
-            self.cursor_position.1,
-        );
-        if ch == '\n' {
-            if self.cursor_position.1 < self.max_position.1 {
-                self.cursor_position.0 = 2;
-                self.cursor_position.1 += 1;
-            }
-        } else {
-            self.cursor_position.0 += 1;
-            if self.cursor_position.0 > self.max_position.0 {
-                self.cursor_position.0 = 2;
-                self.cursor_position.1 += 1;
-                if self.cursor_position.1 > self.max_position.1 {
-                    self.cursor_position.1 = self.max_position.1;
-                }
+        rows
+    }
+
+    // Row and column of cursor within layout, column is counted
+    // in terminal columns
+    fn cursor_row_col(&self, rows: &[(usize, usize)]) -> (usize, usize) {
+        let width = self.width();
+        for (r, (start, end)) in rows.iter().enumerate() {
+            let line_end = *end == self.text.len() || self.text[*end] == '\n';
+            if self.cursor >= *start
+                && (self.cursor < *end
+                    || (self.cursor == *end && line_end && self.columns(*start, *end) < width))
+            {
+                return (r, self.columns(*start, self.cursor));
             }
         }
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
+        (rows.len().saturating_sub(1), 0)
+    }
+
+    fn scroll_to_cursor(&mut self, rows: &[(usize, usize)]) {
+        let (row, _col) = self.cursor_row_col(rows);
+        if row < self.scroll {
+            self.scroll = row;
+        } else if row >= self.scroll + self.height() {
+            self.scroll = row + 1 - self.height();
         }
     }
 
-    pub fn backspace(&mut self, mgr: &mut Manager) {
-        let glyph = Glyph::plain();
-        let mut last_char = false;
-        let len = self.lines[self.cursor_position.1].chars().count();
-        // eprintln!("cursor position: {}", self.cursor_position.0);
-        if len < self.cursor_position.0 - 2 {
-            //Do nothing
-            // eprintln!("1");
-        } else if len == self.cursor_position.0 - 2 {
-            // eprintln!("2");
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-            last_char = true;
-            self.lines[self.cursor_position.1].pop();
-            mgr.set_glyph(
-                self.g_id,
-                Glyph::plain(),
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        } else {
-            if self.cursor_position.0 == 2 {
-                return;
-            }
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-            // eprintln!(
-            //     "3, len: {}, curr position -2 : {}",
-            //     len,
-            //     self.cursor_position.0 - 2
-            // );
-            let mut chars = self.lines[self.cursor_position.1].chars();
-            let mut new_string = String::with_capacity(len);
-            for _i in 0..self.cursor_position.0 - 3 {
-                if let Some(char) = chars.next() {
-                    new_string.push(char);
+    // Paints visible rows and status line, only glyphs that have changed
+    // since last time are sent to Manager.
+    fn redraw(&mut self, mgr: &mut Manager) {
+        let rows = self.layout();
+        self.scroll_to_cursor(&rows);
+        let (cursor_row, cursor_col) = self.cursor_row_col(&rows);
+        let region = self.region();
+        let width = self.width();
+        for y in 0..self.height() {
+            let row = self.scroll + y;
+            let (start, end) = rows.get(row).copied().unwrap_or((0, 0));
+            // Char shown in each column with index of text it comes from,
+            // wide chars are followed by a filler
+            let mut cells = Vec::with_capacity(width);
+            for idx in start..end {
+                let ch = self.text[idx];
+                let w = char_width(ch);
+                if w == 0 {
+                    continue;
+                }
+                if cells.len() + w > width {
+                    break;
+                }
+                cells.push((ch, idx));
+                if w == 2 {
+                    cells.push((WIDE_FILLER, idx));
                 }
             }
-            let _skip = chars.next();
-            // eprintln!("Skiping: {:?}", skip);
-            let mut i = 0;
-            while let Some(char) = chars.next() {
-                // eprintln!("pushing: {:?} at {}", char, self.cursor_position.0 + i - 1);
-                new_string.push(char);
-                mgr.set_glyph(
-                    self.g_id,
-                    Glyph::char(char),
-                    self.cursor_position.0 + i - 1,
-                    self.cursor_position.1,
-                );
-                i += 1;
+            for x in 0..width {
+                let (ch, idx) = cells.get(x).copied().unwrap_or((' ', usize::MAX));
+                let selected = idx != usize::MAX
+                    && region.is_some_and(|(r_start, r_end)| idx >= r_start && idx < r_end);
+                let role = if row == cursor_row && x == cursor_col {
+                    Role::EditorCursor
+                } else if selected {
+                    Role::EditorSelection
+                } else {
+                    Role::EditorText
+                };
+                let p_idx = y * width + x;
+                if self.painted[p_idx] != (ch, role) {
+                    self.painted[p_idx] = (ch, role);
+                    mgr.set_glyph(self.g_id, theme::glyph(role, ch), x + 2, y + 1);
+                }
             }
-            self.lines[self.cursor_position.1] = new_string;
-            mgr.set_glyph(
-                self.g_id,
-                Glyph::plain(),
-                self.cursor_position.0 + i - 1,
-                self.cursor_position.1,
-            );
-        }
-        self.cursor_position.0 -= 1;
-        if self.cursor_position.0 < 2 {
-            // eprintln!("p.0 <2");
-            self.cursor_position.1 -= 1;
-            if self.cursor_position.1 == 0 {
-                // eprintln!("p.1 <2, start");
-                self.cursor_position = (2, 1);
+        }
+        self.set_status(mgr);
+    }
+
+    fn set_status(&mut self, mgr: &mut Manager) {
+        let line = self.text[..self.cursor]
+            .iter()
+            .filter(|c| **c == '\n')
+            .count();
+        let col = self.columns(self.line_start(), self.cursor);
+        let bytes = if let Some(limit) = self.byte_limit {
+            trf("editor.bytes_limit", &[&self.byte_len(), &limit])
+        } else {
+            trf("editor.bytes", &[&self.byte_len()])
+        };
+        let mut status = format!(
+            "─{}─{}",
+            trf("editor.position", &[&(line + 1), &(col + 1)]),
+            bytes
+        );
+        if self.read_only {
+            status.push('─');
+            status.push_str(&tr("editor.read_only"));
+        }
+        if let Some(notice) = &self.notice {
+            status.push('─');
+            status.push_str(notice);
+        }
+        if status == self.status {
+            return;
+        }
+        let mut chars = cells(&status, self.max_position.0 - 1).into_iter();
+        let mut g = theme::glyph(Role::EditorTitle, '─');
+        for i in 1..self.max_position.0 {
+            if let Some(char) = chars.next() {
+                g.set_char(char);
             } else {
-                // eprintln!("end of prev line: {}", self.text[self.cursor_position.1]);
-                self.cursor_position.0 = self.lines[self.cursor_position.1].chars().count() + 2;
+                g.set_char('─');
             }
+            mgr.set_glyph(self.g_id, g, i, self.max_position.1 + 1);
         }
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            // eprintln!(
-            //     "Got glyph: {} from pos: {}",
-            //     glyph.character, self.cursor_position.0
-            // );
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            if last_char {
-                glyph.set_char(' ');
+        self.status = status;
+    }
+
+    pub fn insert(&mut self, mgr: &mut Manager, ch: char) {
+        if let Some(byte_limit) = self.byte_limit {
+            if self.byte_len() + ch.len_utf8() > byte_limit as usize {
+                return;
             }
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
         }
+        self.text.insert(self.cursor, ch);
+        self.cursor += 1;
+        self.redraw(mgr);
+    }
+
+    pub fn backspace(&mut self, mgr: &mut Manager) {
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor -= 1;
+        self.text.remove(self.cursor);
+        self.redraw(mgr);
     }
 
     pub fn delete(&mut self, mgr: &mut Manager) {
-        let next_position = (self.cursor_position.0 + 1, self.cursor_position.1);
-        // eprintln!("cursor position: {}", self.cursor_position.0);
-        if next_position.0 > self.max_position.0 {
+        if self.cursor >= self.text.len() {
             return;
         }
-        // eprintln!("next position: {}", next_position.0);
-        let len = self.lines[self.cursor_position.1].chars().count();
-        if len < next_position.0 - 2 {
-            //do nothing
-            // eprintln!("del 1");
-        } else if len == next_position.0 - 2 {
-            // eprintln!("del 2");
-            self.lines[self.cursor_position.1].pop();
-            mgr.set_glyph(
-                self.g_id,
-                Glyph::plain(),
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        } else {
-            // eprintln!("del 3");
-            let mut chars = self.lines[next_position.1].chars();
-            let mut new_string = String::with_capacity(len);
-            for _i in 0..self.cursor_position.0 - 2 {
-                if let Some(char) = chars.next() {
-                    new_string.push(char);
-                }
-            }
-            let _skip = chars.next();
-            // eprintln!("Skip: {:?}", _skip);
-            let mut i = next_position.0 - 1;
-            while let Some(char) = chars.next() {
-                // eprintln!("push: '{}'", char);
-                new_string.push(char);
-                mgr.set_glyph(self.g_id, Glyph::char(char), i, next_position.1);
-                i += 1;
-            }
-            mgr.set_glyph(self.g_id, Glyph::plain(), i, next_position.1);
-            self.lines[self.cursor_position.1] = new_string;
-        }
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            // eprintln!("Retrieved: '{}'", glyph.character);
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
+        self.text.remove(self.cursor);
+        // Marks without their base char would combine with previous one
+        while self.cursor < self.text.len() && self.is_mark(self.cursor) {
+            self.text.remove(self.cursor);
         }
+        self.redraw(mgr);
+    }
+
+    // Combining mark that is drawn together with previous char
+    fn is_mark(&self, idx: usize) -> bool {
+        self.text[idx] != '\n' && char_width(self.text[idx]) == 0
     }
+
+    fn line_start(&self) -> usize {
+        self.text[..self.cursor]
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map(|p| p + 1)
+            .unwrap_or(0)
+    }
+
+    fn line_end(&self) -> usize {
+        self.text[self.cursor..]
+            .iter()
+            .position(|c| *c == '\n')
+            .map(|p| self.cursor + p)
+            .unwrap_or(self.text.len())
+    }
+
+    // Removes text up to the end of line, or the newline itself
+    // when cursor is already at the end of line
     pub fn remove_chars_from_cursor_to_end(&mut self, mgr: &mut Manager) -> String {
-        let mut chars = self.lines[self.cursor_position.1].chars();
-        let mut new_string = String::with_capacity(self.max_position.0);
-        let mut old_string = String::with_capacity(self.max_position.0);
-        for _i in 0..self.cursor_position.0 - 2 {
-            if let Some(char) = chars.next() {
-                new_string.push(char);
-            } else {
-                break;
-            }
+        let mut end = self.line_end();
+        if end == self.cursor && end < self.text.len() {
+            end += 1;
         }
-        let mut glyph = Glyph::plain();
-        glyph.set_blink(true);
-        glyph.set_reverse(true);
-        mgr.set_glyph(
-            self.g_id,
-            glyph,
-            self.cursor_position.0,
-            self.cursor_position.1,
-        );
-        let mut i = 1;
-        let plain = Glyph::plain();
-        while let Some(char) = chars.next() {
-            if i > self.max_position.0 - 2 {
-                self.cursor_position.1 = self.cursor_position.1 + 1;
-                self.cursor_position.0 = 1;
-                i = 1
-                // break;
-            }
-            // eprintln!(
-            //     "Clearing {} {}",
-            //     self.cursor_position.0 + i,
-            //     self.cursor_position.1
-            // );
-            mgr.set_glyph(
-                self.g_id,
-                plain,
-                self.cursor_position.0 + i,
-                self.cursor_position.1,
-            );
-            old_string.push(char);
-            i += 1;
-        }
-        // eprint!("NS: {} ;", new_string);
-        self.lines[self.cursor_position.1] = new_string;
-        if self.allow_newlines && !old_string.is_empty() && !old_string.ends_with('\n') {
-            old_string.push('\n');
-        }
-        old_string
+        let removed: String = self.text.drain(self.cursor..end).collect();
+        self.redraw(mgr);
+        removed
     }
     pub fn move_to_line_start(&mut self, mgr: &mut Manager) {
-        // eprintln!("Move to start");
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(false);
-            glyph.set_reverse(false);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        }
-        self.cursor_position.0 = 2;
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        }
+        self.cursor = self.line_start();
+        self.redraw(mgr);
     }
     pub fn move_to_line_end(&mut self, mgr: &mut Manager) {
-        // eprintln!("Move to end");
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(false);
-            glyph.set_reverse(false);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        }
-        self.cursor_position.0 = self.lines[self.cursor_position.1].chars().count() + 2;
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        }
+        self.cursor = self.line_end();
+        self.redraw(mgr);
     }
     pub fn move_cursor(&mut self, direction: Direction, mgr: &mut Manager) {
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(false);
-            glyph.set_reverse(false);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
-        }
         match direction {
-            Direction::Up => {
-                self.cursor_position.1 -= 1;
-                if self.cursor_position.1 < 1 {
-                    self.cursor_position.1 = self.max_position.1;
-                }
-            }
-            Direction::Down => {
-                self.cursor_position.1 += 1;
-                if self.cursor_position.1 > self.max_position.1 {
-                    self.cursor_position.1 = 1;
+            Direction::Up | Direction::Down => {
+                let rows = self.layout();
+                let (row, col) = self.cursor_row_col(&rows);
+                let target = if matches!(direction, Direction::Up) {
+                    row.checked_sub(1)
+                } else if row + 1 < rows.len() {
+                    Some(row + 1)
+                } else {
+                    None
+                };
+                if let Some(target) = target {
+                    self.cursor = self.index_at(&rows, target, col);
                 }
             }
             Direction::Left => {
-                self.cursor_position.0 -= 1;
-                if self.cursor_position.0 < 2 {
-                    self.cursor_position.0 = self.max_position.0;
+                self.cursor = self.cursor.saturating_sub(1);
+                while self.cursor > 0 && self.is_mark(self.cursor) {
+                    self.cursor -= 1;
                 }
             }
             Direction::Right => {
-                self.cursor_position.0 += 1;
-                if self.cursor_position.0 > self.max_position.0 {
-                    self.cursor_position.0 = 2;
+                if self.cursor < self.text.len() {
+                    self.cursor += 1;
+                }
+                while self.cursor < self.text.len() && self.is_mark(self.cursor) {
+                    self.cursor += 1;
                 }
             }
         }
-        mgr.get_glyph(self.g_id, self.cursor_position.0, self.cursor_position.1);
-        if let Ok(AnimOk::GlyphRetrieved(_u, mut glyph)) = mgr.read_result() {
-            glyph.set_blink(true);
-            glyph.set_reverse(true);
-            mgr.set_glyph(
-                self.g_id,
-                glyph,
-                self.cursor_position.0,
-                self.cursor_position.1,
-            );
+        self.redraw(mgr);
+    }
+
+    // Index of text at given column of a row, or at last position
+    // of that row if it is shorter
+    fn index_at(&self, rows: &[(usize, usize)], row: usize, col: usize) -> usize {
+        let (start, end) = rows[row];
+        let line_end = end == self.text.len() || self.text[end] == '\n';
+        let last = if (line_end && self.columns(start, end) < self.width()) || end == start {
+            end
+        } else {
+            end - 1
+        };
+        // Walk to the char covering given column, skipping marks
+        // that combine with a previous char
+        let mut idx = start;
+        let mut x = 0;
+        while idx < last {
+            let w = char_width(self.text[idx]);
+            if w > 0 && x + w > col {
+                break;
+            }
+            x += w;
+            idx += 1;
+        }
+        idx
+    }
+
+    pub fn move_word(&mut self, forward: bool, mgr: &mut Manager) {
+        if forward {
+            while self.cursor < self.text.len() && !self.text[self.cursor].is_alphanumeric() {
+                self.cursor += 1;
+            }
+            while self.cursor < self.text.len() && self.text[self.cursor].is_alphanumeric() {
+                self.cursor += 1;
+            }
+        } else {
+            while self.cursor > 0 && !self.text[self.cursor - 1].is_alphanumeric() {
+                self.cursor -= 1;
+            }
+            while self.cursor > 0 && self.text[self.cursor - 1].is_alphanumeric() {
+                self.cursor -= 1;
+            }
         }
+        self.redraw(mgr);
+    }
+
+    // Moves cursor by a screen of rows
+    pub fn scroll_page(&mut self, down: bool, mgr: &mut Manager) {
+        let rows = self.layout();
+        let (row, col) = self.cursor_row_col(&rows);
+        let step = self.height().saturating_sub(1).max(1);
+        let target = if down {
+            (row + step).min(rows.len() - 1)
+        } else {
+            row.saturating_sub(step)
+        };
+        self.scroll = if down {
+            self.scroll + step
+        } else {
+            self.scroll.saturating_sub(step)
+        };
+        self.cursor = self.index_at(&rows, target, col);
+        self.redraw(mgr);
     }
+
     pub fn set_title(&mut self, mgr: &mut Manager, title: &str) {
-        let mut chars = title.chars();
-        let mut g = glyph::Glyph::char('*');
+        self.title = title.to_string();
+        let mut chars = cells(title, self.max_position.0 - 1).into_iter();
+        let mut g = theme::glyph(Role::EditorTitle, '*');
         for i in 1..self.max_position.0 {
             if let Some(char) = chars.next() {
                 g.set_char(char);
@@ -498,62 +525,177 @@ impl Editor {
             mgr.set_glyph(self.g_id, g, i, 0);
         }
     }
-    // TODO: also put lines into self.lines
     pub fn set_text(&mut self, mgr: &mut Manager, text: &str) {
-        let mut lines = text.lines();
-        let gp = Glyph::plain();
-        let mut g = Glyph::plain();
-        let mut curr_x_position = 2;
-        let mut curr_y_position = 1;
-        while let Some(line) = lines.next() {
-            let mut chars = line.chars();
-            let ll = chars.clone().count();
-            if ll < self.max_position.0 - 2 {
-                while let Some(c) = chars.next() {
-                    if c == '\n' {
-                        continue;
-                    }
-                    g.set_char(c);
-                    self.lines[curr_y_position].push(c);
-                    mgr.set_glyph(self.g_id, g, curr_x_position, curr_y_position);
-                    curr_x_position += 1;
-                }
-                for i in curr_x_position..=self.max_position.0 {
-                    mgr.set_glyph(self.g_id, gp, i, curr_y_position);
+        self.text = text.chars().filter(|c| *c != '\r').collect();
+        self.cursor = 0;
+        self.scroll = 0;
+        self.mark = None;
+        self.redraw(mgr);
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            text: self.text.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    // Should be called right before text gets modified
+    fn remember(&mut self) {
+        if self.undo_stack.len() >= UNDO_DEPTH {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(self.snapshot());
+        self.redo_stack.clear();
+    }
+
+    fn restore(&mut self, snapshot: Snapshot, mgr: &mut Manager) {
+        self.text = snapshot.text;
+        self.cursor = snapshot.cursor;
+        self.mark = None;
+        self.redraw(mgr);
+    }
+
+    pub fn undo(&mut self, mgr: &mut Manager) {
+        if let Some(snapshot) = self.undo_stack.pop() {
+            self.redo_stack.push(self.snapshot());
+            self.restore(snapshot, mgr);
+        }
+    }
+
+    pub fn redo(&mut self, mgr: &mut Manager) {
+        if let Some(snapshot) = self.redo_stack.pop() {
+            self.undo_stack.push(self.snapshot());
+            self.restore(snapshot, mgr);
+        }
+    }
+
+    // Start and end (exclusive) of selected text
+    fn region(&self) -> Option<(usize, usize)> {
+        let mark = self.mark?;
+        Some((mark.min(self.cursor), mark.max(self.cursor)))
+    }
+
+    pub fn toggle_mark(&mut self, mgr: &mut Manager) {
+        if self.mark.take().is_none() {
+            self.mark = Some(self.cursor);
+        }
+        self.redraw(mgr);
+    }
+
+    fn drop_mark(&mut self, mgr: &mut Manager) {
+        if self.mark.take().is_some() {
+            self.redraw(mgr);
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.region()?;
+        if start == end {
+            None
+        } else {
+            Some(self.text[start..end].iter().collect())
+        }
+    }
+
+    fn delete_region(&mut self, mgr: &mut Manager) {
+        let Some((start, end)) = self.region() else {
+            return;
+        };
+        self.text.drain(start..end);
+        self.cursor = start;
+        self.mark = None;
+        self.redraw(mgr);
+    }
+
+    // Consecutive kills are joined into a single entry
+    fn push_kill(&mut self, text: String, append: bool) {
+        if text.is_empty() {
+            return;
+        }
+        if append {
+            if let Some(last) = self.kill_ring.front_mut() {
+                last.push_str(&text);
+                return;
+            }
+        }
+        if self.kill_ring.len() >= KILL_RING_SIZE {
+            self.kill_ring.pop_back();
+        }
+        self.kill_ring.push_front(text);
+    }
+
+    fn insert_text(&mut self, mgr: &mut Manager, text: &str) {
+        for ch in text.chars() {
+            if ch == '\n' && !self.allow_newlines {
+                continue;
+            }
+            if ch.is_control() && ch != '\n' {
+                continue;
+            }
+            if let Some(byte_limit) = self.byte_limit {
+                if self.byte_len() + ch.len_utf8() > byte_limit as usize {
+                    break;
                 }
-                curr_y_position += 1;
-                curr_x_position = 2;
-            } else {
-                while let Some(c) = chars.next() {
-                    eprint!("C:{} ", c);
-                    g.set_char(c);
-                    mgr.set_glyph(self.g_id, g, curr_x_position, curr_y_position);
-                    curr_x_position += 1;
-                    if curr_x_position > self.max_position.0 {
-                        curr_x_position = 2;
-                        curr_y_position += 1;
+            }
+            self.text.insert(self.cursor, ch);
+            self.cursor += 1;
+        }
+        self.redraw(mgr);
+    }
+
+    // Replaces text with what was written in $VISUAL/$EDITOR,
+    // applying the same rules as when typing it in
+    pub fn edit_externally(&mut self, mgr: &mut Manager) {
+        let text: String = self.text.iter().collect();
+        // Manager's input and render loops have to stop for the time
+        // external program owns the terminal, otherwise they would steal
+        // it's keys and paint over it. external_editor switches terminal
+        // out of TUI mode and back, afterwards whole display is redrawn.
+        mgr.suspend();
+        let result = external_editor::edit(&text);
+        mgr.resume();
+        for cell in self.painted.iter_mut() {
+            cell.0 = '\0';
+        }
+        self.status.clear();
+        mgr.restore_display(self.display_id, true);
+        let title = self.title.clone();
+        self.set_title(mgr, &title);
+        match result {
+            Ok(new_text) => {
+                let mut chars = Vec::with_capacity(new_text.len());
+                let mut bytes = 0;
+                for ch in new_text.chars() {
+                    let ch = match ch {
+                        '\n' if !self.allow_newlines => ' ',
+                        '\t' => ' ',
+                        '\r' => continue,
+                        c if c.is_control() && c != '\n' => continue,
+                        c => c,
+                    };
+                    if let Some(limit) = self.byte_limit {
+                        if bytes + ch.len_utf8() > limit as usize {
+                            self.notice = Some(trf("editor.cut_to_limit", &[&limit]));
+                            break;
+                        }
                     }
+                    bytes += ch.len_utf8();
+                    chars.push(ch);
                 }
-                for i in curr_x_position..=self.max_position.0 {
-                    mgr.set_glyph(self.g_id, gp, i, curr_y_position);
+                if chars != self.text {
+                    self.remember();
+                    self.text = chars;
+                    self.cursor = self.cursor.min(self.text.len());
+                    self.mark = None;
                 }
-                // TODO: how do we split those longer lines?
-                eprintln!(
-                    "dupa {}> {} (line:{})",
-                    ll,
-                    self.max_position.0 - 2,
-                    curr_y_position
-                );
-                curr_y_position += 1;
-                curr_x_position = 2;
+            }
+            Err(e) => {
+                log_warn!("External editor failed: {}", e);
+                self.notice = Some(trf("editor.external_failed", &[&e]));
             }
         }
-
-        // for y in curr_y_position..=self.max_position.1 {
-        //     for x in 2..=self.max_position.0 {
-        //         mgr.set_glyph(self.g_id, gp, x, y);
-        //     }
-        // }
+        self.redraw(mgr);
     }
 
     pub fn serve(
@@ -571,6 +713,8 @@ impl Editor {
         self.set_limit(byte_limit);
         if let Some(text) = initial_text {
             self.set_text(mgr, &text);
+        } else {
+            self.redraw(mgr);
         }
         // print!("Type text in (press TAB to finish): ");
         let result = self.run(mgr);
@@ -582,118 +726,169 @@ impl Editor {
         self.read_only = read_only;
         self.can_edit = can_edit;
     }
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
     pub fn run(&mut self, mgr: &mut Manager) -> EditorResult {
+        self.redraw(mgr);
         loop {
             if let Some(ch) = mgr.read_char() {
                 // eprintln!("Some ch: {}", ch);
-                if let Some(key) = map_private_char_to_key(ch) {
-                    // eprintln!("Some key: {:?}", key);
-                    match key {
-                        Key::Up => {
-                            if self.allow_newlines {
-                                self.move_cursor(Direction::Up, mgr)
-                            }
+                let previous = std::mem::replace(&mut self.last_edit, LastEdit::Other);
+                self.notice = None;
+                let Some(action) = self.keymap.action_for_char(KeyContext::Editor, ch) else {
+                    if ch == '\u{7f}' {
+                        if !self.read_only {
+                            self.drop_mark(mgr);
+                            self.remember();
+                            self.backspace(mgr);
                         }
-                        Key::Down => {
-                            if self.allow_newlines {
-                                self.move_cursor(Direction::Down, mgr)
+                    } else if let Some(key) = map_private_char_to_key(ch) {
+                        log_debug!("Other: {}", key);
+                    } else if ch == '\n' && !self.allow_newlines {
+                        // Do nothing
+                    } else if !ch.is_control() || ch == '\n' {
+                        if !self.read_only {
+                            self.drop_mark(mgr);
+                            // A word typed in is undone at once
+                            if previous != LastEdit::Typing || ch.is_whitespace() {
+                                self.remember();
                             }
+                            self.insert(mgr, ch);
+                            self.last_edit = LastEdit::Typing;
                         }
-                        Key::Left => self.move_cursor(Direction::Left, mgr),
-                        Key::Right => self.move_cursor(Direction::Right, mgr),
-                        // Key::Home => self.move_to_line_start(mgr),
-                        // Key::End => self.move_to_line_end(mgr),
-                        Key::Delete => {
-                            if self.read_only {
-                                continue;
-                            }
-                            self.delete(mgr)
+                    } else {
+                        // eprint!("code: {:?}", ch);
+                    }
+                    continue;
+                };
+                match action {
+                    KeyAction::Finish => {
+                        let taken = self.take_text(mgr);
+                        // mgr.restore_display(main_display, true);
+                        if self.read_only {
+                            return EditorResult::Close;
+                        } else {
+                            return EditorResult::Text(taken);
                         }
-                        Key::F8 => {
-                            eprintln!("Read-only:{}, can_edit: {}", self.read_only, self.can_edit);
-                            if self.read_only && self.can_edit {
-                                self.read_only = false;
-                                eprintln!("Read-write enabled");
-                            }
+                    }
+                    KeyAction::MoveUp => self.move_cursor(Direction::Up, mgr),
+                    KeyAction::MoveDown => self.move_cursor(Direction::Down, mgr),
+                    KeyAction::MoveLeft => self.move_cursor(Direction::Left, mgr),
+                    KeyAction::MoveRight => self.move_cursor(Direction::Right, mgr),
+                    KeyAction::WordLeft => self.move_word(false, mgr),
+                    KeyAction::WordRight => self.move_word(true, mgr),
+                    KeyAction::LineStart => self.move_to_line_start(mgr),
+                    KeyAction::LineEnd => self.move_to_line_end(mgr),
+                    KeyAction::ScrollUp => self.scroll_page(false, mgr),
+                    KeyAction::ScrollDown => self.scroll_page(true, mgr),
+                    KeyAction::Delete => {
+                        if self.read_only {
+                            continue;
                         }
-                        Key::AltB => {
-                            self.move_cursor(Direction::Left, mgr);
-                            self.move_cursor(Direction::Left, mgr);
-                            self.move_cursor(Direction::Left, mgr);
-                            self.move_cursor(Direction::Left, mgr);
+                        self.drop_mark(mgr);
+                        self.remember();
+                        self.delete(mgr)
+                    }
+                    KeyAction::KillToEnd => {
+                        if self.read_only {
+                            continue;
                         }
-                        Key::AltF => {
-                            self.move_cursor(Direction::Right, mgr);
-                            self.move_cursor(Direction::Right, mgr);
-                            self.move_cursor(Direction::Right, mgr);
-                            self.move_cursor(Direction::Right, mgr);
+                        self.drop_mark(mgr);
+                        self.remember();
+                        let killed = self.remove_chars_from_cursor_to_end(mgr);
+                        self.push_kill(killed, previous == LastEdit::Kill);
+                        self.last_edit = LastEdit::Kill;
+                    }
+                    KeyAction::SetMark => self.toggle_mark(mgr),
+                    KeyAction::Copy => {
+                        if let Some(text) = self.selected_text() {
+                            self.push_kill(text, false);
+                            self.drop_mark(mgr);
                         }
-                        Key::PgUp => {
-                            let _ = self.take_text(mgr);
-                            return EditorResult::PrevPage;
+                    }
+                    KeyAction::Cut => {
+                        if self.read_only {
+                            continue;
                         }
-                        Key::PgDn => {
-                            let _ = self.take_text(mgr);
-                            return EditorResult::NextPage;
+                        if let Some(text) = self.selected_text() {
+                            self.remember();
+                            self.push_kill(text, false);
+                            self.delete_region(mgr);
                         }
-                        Key::Home => {
-                            let _ = self.take_text(mgr);
-                            return EditorResult::FirstPage;
+                    }
+                    KeyAction::Paste => {
+                        if self.read_only {
+                            continue;
                         }
-                        Key::End => {
-                            let _ = self.take_text(mgr);
-                            return EditorResult::LastPage;
+                        if let Some(text) = self.kill_ring.front().cloned() {
+                            self.drop_mark(mgr);
+                            self.remember();
+                            self.insert_text(mgr, &text);
+                            self.last_edit = LastEdit::Yank(0);
                         }
-                        other => eprint!("Other: {}", other),
                     }
-                } else if ch == '\t' {
-                    let taken = self.take_text(mgr);
-                    // mgr.restore_display(main_display, true);
-                    if self.read_only {
-                        return EditorResult::Close;
-                    } else {
-                        return EditorResult::Text(taken);
+                    KeyAction::YankPop => {
+                        // Replaces just pasted text with an older entry from kill ring
+                        let LastEdit::Yank(idx) = previous else {
+                            continue;
+                        };
+                        if self.read_only || self.kill_ring.len() < 2 {
+                            self.last_edit = previous;
+                            continue;
+                        }
+                        if let Some(snapshot) = self.undo_stack.pop() {
+                            self.restore(snapshot, mgr);
+                        }
+                        let idx = (idx + 1) % self.kill_ring.len();
+                        let text = self.kill_ring[idx].clone();
+                        self.remember();
+                        self.insert_text(mgr, &text);
+                        self.last_edit = LastEdit::Yank(idx);
+                    }
+                    KeyAction::ExternalEditor => {
+                        if !self.read_only {
+                            self.edit_externally(mgr);
+                        }
+                    }
+                    KeyAction::Undo => {
+                        if !self.read_only {
+                            self.undo(mgr);
+                        }
+                    }
+                    KeyAction::Redo => {
+                        if !self.read_only {
+                            self.redo(mgr);
+                        }
                     }
-                } else if ch == '\u{7f}' {
-                    if self.read_only {
-                        continue;
+                    KeyAction::EnableEditing => {
+                        log_debug!("Read-only:{}, can_edit: {}", self.read_only, self.can_edit);
+                        if self.read_only && self.can_edit {
+                            self.read_only = false;
+                            self.redraw(mgr);
+                            log_debug!("Read-write enabled");
+                        }
                     }
-                    self.backspace(mgr);
-                } else if ch == '\u{1}' {
-                    self.move_to_line_start(mgr);
-                } else if ch == '\u{5}' {
-                    self.move_to_line_end(mgr);
-                // } else if ch == '\u{a}' {
-                //     // Enter or newline
-                //     eprintln!("Enter!");
-                //     if self.allow_newlines {
-                //         self.move_cursor(Direction::Down, mgr);
-                //         self.move_to_line_start(mgr);
-                //     }
-                } else if ch == '\u{b}' {
-                    if self.read_only {
-                        continue;
+                    KeyAction::PrevPage => {
+                        let _ = self.take_text(mgr);
+                        return EditorResult::PrevPage;
                     }
-                    self.remove_chars_from_cursor_to_end(mgr);
-                } else if ch == '\u{e}' {
-                    if self.allow_newlines {
-                        self.move_cursor(Direction::Down, mgr);
+                    KeyAction::NextPage => {
+                        let _ = self.take_text(mgr);
+                        return EditorResult::NextPage;
                     }
-                } else if ch == '\u{10}' {
-                    if self.allow_newlines {
-                        self.move_cursor(Direction::Up, mgr);
+                    KeyAction::FirstPage => {
+                        let _ = self.take_text(mgr);
+                        return EditorResult::FirstPage;
                     }
-                } else if ch == '\u{2}' {
-                    self.move_cursor(Direction::Left, mgr);
-                } else if ch == '\u{6}' {
-                    self.move_cursor(Direction::Right, mgr);
-                } else {
-                    // eprint!("code: {:?}", ch);
-                    if ch == '\n' && !self.allow_newlines {
-                        // Do nothing
-                    } else if !self.read_only {
-                        self.insert(mgr, ch);
+                    KeyAction::LastPage => {
+                        let _ = self.take_text(mgr);
+                        return EditorResult::LastPage;
                     }
+                    KeyAction::Help => self.help.show(KeyContext::Editor, mgr),
+                    other => log_debug!("{} has no meaning in Editor", other.name()),
                 }
             }
         }
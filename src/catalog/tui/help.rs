@@ -0,0 +1,161 @@
+use crate::commands::commands_for;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::{cells, str_width};
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use animaterm::utilities::message_box;
+use animaterm::{Glyph, Manager};
+
+// Overlay listing key bindings of given context together with
+// what they do. It is generated from the keymap used by input loops,
+// in Catalog and Forum it also lists palette commands without a key.
+pub struct Help {
+    g_id: usize,
+    // Cells available inside the frame
+    width: usize,
+    height: usize,
+    keymap: Keymap,
+}
+
+// Keys (or command) and what it does, None for a section header
+type HelpLine = (Option<String>, String);
+
+impl Help {
+    pub fn new(mgr: &mut Manager) -> Self {
+        let (cols, rows) = mgr.screen_size();
+        let width = cols.min(72);
+        let height = rows.min(24);
+        let m_box = message_box(
+            Some(String::new()),
+            String::new(),
+            Glyph::plain(),
+            width,
+            height,
+        );
+        let offset = (
+            ((cols - width) >> 1) as isize,
+            ((rows - height) >> 1) as isize,
+        );
+        let g_id = mgr.add_graphic(m_box, 0, offset).unwrap();
+        Help {
+            g_id,
+            width: width - 2,
+            height: height - 2,
+            keymap: Keymap::new(),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
+    }
+
+    pub fn show(&self, context: KeyContext, mgr: &mut Manager) {
+        let lines = self.lines(context);
+        let keys_width = lines
+            .iter()
+            .filter_map(|(keys, _d)| keys.as_ref().map(|k| str_width(k)))
+            .max()
+            .unwrap_or(0)
+            .min(self.width / 2);
+        // Last row is for the footer
+        let visible = self.height - 1;
+        let max_scroll = lines.len().saturating_sub(visible);
+        let mut scroll = 0;
+        mgr.move_graphic(self.g_id, 4, (0, 0));
+        self.set_title(
+            &trf("help.title", &[&tr(&format!("help.{}", context.name()))]),
+            mgr,
+        );
+        loop {
+            for row in 0..visible {
+                self.draw_line(row + 1, lines.get(scroll + row), keys_width, mgr);
+            }
+            let footer = if max_scroll > 0 {
+                trf("help.footer_scroll", &[&(scroll + 1), &lines.len()])
+            } else {
+                tr("help.footer")
+            };
+            self.draw_footer(&footer, mgr);
+            let ch = loop {
+                if let Some(ch) = mgr.read_char() {
+                    break ch;
+                }
+            };
+            match self.keymap.action_for_char(KeyContext::Help, ch) {
+                Some(KeyAction::MoveUp) => scroll = scroll.saturating_sub(1),
+                Some(KeyAction::MoveDown) => scroll = (scroll + 1).min(max_scroll),
+                Some(KeyAction::PrevPage) => scroll = scroll.saturating_sub(visible),
+                Some(KeyAction::NextPage) => scroll = (scroll + visible).min(max_scroll),
+                // Any other key closes help
+                _other => break,
+            }
+        }
+        mgr.move_graphic(self.g_id, 0, (0, 0));
+    }
+
+    fn lines(&self, context: KeyContext) -> Vec<HelpLine> {
+        let mut lines: Vec<HelpLine> = self
+            .keymap
+            .bindings_for(context)
+            .into_iter()
+            .map(|(action, keys)| (Some(keys.join(" ")), action.description()))
+            .collect();
+        let commands: Vec<HelpLine> = commands_for(context)
+            .into_iter()
+            .filter(|c| c.key_action().is_none())
+            .map(|c| (Some(format!(":{}", c.usage())), c.description()))
+            .collect();
+        if !commands.is_empty() {
+            lines.push((None, String::new()));
+            lines.push((None, tr("help.commands")));
+            lines.extend(commands);
+        }
+        lines
+    }
+
+    fn set_title(&self, title: &str, mgr: &mut Manager) {
+        let mut chars = cells(title, self.width - 1).into_iter();
+        let mut g = Glyph::plain();
+        for x in 1..self.width + 1 {
+            g.set_char(chars.next().unwrap_or('─'));
+            mgr.set_glyph(self.g_id, g, x, 0);
+        }
+    }
+
+    fn draw_line(&self, y: usize, line: Option<&HelpLine>, keys_width: usize, mgr: &mut Manager) {
+        let mut row: Vec<(char, Role)> = Vec::with_capacity(self.width);
+        match line {
+            Some((Some(keys), description)) => {
+                row.push((' ', Role::Option));
+                for c in cells(keys, keys_width) {
+                    row.push((c, Role::OptionMatch));
+                }
+                while row.len() < keys_width + 3 {
+                    row.push((' ', Role::Option));
+                }
+                let room = self.width.saturating_sub(row.len());
+                for c in cells(description, room) {
+                    row.push((c, Role::Option));
+                }
+            }
+            Some((None, header)) => {
+                for c in cells(header, self.width) {
+                    row.push((c, Role::ListHeader));
+                }
+            }
+            None => {}
+        }
+        for x in 0..self.width {
+            let (c, role) = row.get(x).copied().unwrap_or((' ', Role::Option));
+            mgr.set_glyph(self.g_id, glyph(role, c), x + 1, y);
+        }
+    }
+
+    fn draw_footer(&self, text: &str, mgr: &mut Manager) {
+        let mut chars = cells(text, self.width).into_iter();
+        for x in 1..self.width + 1 {
+            let c = chars.next().unwrap_or(' ');
+            mgr.set_glyph(self.g_id, glyph(Role::MenuDisabled, c), x, self.height);
+        }
+    }
+}
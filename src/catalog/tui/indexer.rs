@@ -1,8 +1,13 @@
 use crate::catalog::tui::Direction;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::cells;
 use animaterm::utilities::message_box;
 use animaterm::{glyph, prelude::*};
 
 use super::button::Button;
+use super::help::Help;
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
 
 pub struct Indexer {
     pub g_id: usize,
@@ -16,19 +21,22 @@ pub struct Indexer {
     _allow_newlines: bool,
     _read_only: bool,
     _byte_limit: Option<u16>,
+    keymap: Keymap,
+    help: Help,
 }
 impl Indexer {
     pub fn new(mgr: &mut Manager) -> Self {
         let display_id = mgr.new_display(true);
+        let help = Help::new(mgr);
         let (cols, rows) = mgr.screen_size();
         let visible_buttons = rows / 3 - 1;
         let mut buttons = Vec::with_capacity(visible_buttons);
         for i in 0..visible_buttons {
             let button = Button::new(
-                (cols-2, 3),
+                (cols - 2, 3),
                 0,
                 (1, (i as isize + 1) * 3),
-                &format!("Nagłówek {} z {}zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz", i, visible_buttons - 1),
+                &trf("indexer.placeholder", &[&i, &(visible_buttons - 1)]),
                 None,
                 mgr,
             );
@@ -39,7 +47,7 @@ impl Indexer {
             buttons.push(button);
         }
         let m_box = message_box(
-            Some("Text input".to_string()),
+            Some(tr("editor.text_input")),
             String::new(),
             Glyph::plain(),
             cols,
@@ -58,6 +66,8 @@ impl Indexer {
             _allow_newlines: true,
             _read_only: false,
             _byte_limit: None,
+            keymap: Keymap::new(),
+            help,
         };
         indexer.show(mgr);
         indexer
@@ -73,6 +83,9 @@ impl Indexer {
     }
 
     pub fn move_selection(&mut self, direction: Direction, mgr: &mut Manager) {
+        if self.visible_buttons == 0 {
+            return;
+        }
         self.buttons[self.cursor_position].deselect(mgr, false);
         if matches!(direction, Direction::Up) {
             if self.cursor_position == 0 {
@@ -115,7 +128,7 @@ impl Indexer {
         }
     }
     pub fn set_title(&mut self, mgr: &mut Manager, title: &str) {
-        let mut chars = title.chars();
+        let mut chars = cells(title, self.max_position.0 - 1).into_iter();
         let mut g = glyph::Glyph::char('*');
         for i in 1..self.max_position.0 {
             if let Some(char) = chars.next() {
@@ -164,38 +177,47 @@ impl Indexer {
 
     fn draw_buttons(&mut self, mgr: &mut Manager) {
         self.cursor_position = 0;
-        self.visible_buttons = self.header_chunks[self.chunk_idx].len();
-        // eprintln!("Indexer visible buttons: {}", self.visible_buttons);
+        let empty = vec![];
+        let chunk = self.header_chunks.get(self.chunk_idx).unwrap_or(&empty);
+        self.visible_buttons = chunk.len();
+        // Buttons past the last header are hidden and can not be selected
         for i in 0..self.buttons.len() {
-            if let Some(name) = self.header_chunks[self.chunk_idx].get(i) {
+            if let Some(name) = chunk.get(i) {
                 self.buttons[i].rename(mgr, name);
                 self.buttons[i].show(mgr);
             } else {
-                //TODO: disable following buttons
-                // break;
                 self.buttons[i].hide(mgr);
             }
         }
     }
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
     pub fn run(&mut self, mgr: &mut Manager) -> Option<usize> {
         self.buttons[self.cursor_position].select(mgr, false);
         loop {
             if let Some(key) = mgr.read_key() {
-                match key {
-                    Key::Up | Key::CtrlP => self.move_selection(Direction::Up, mgr),
-                    Key::Down | Key::CtrlN => self.move_selection(Direction::Down, mgr),
-                    Key::CtrlA => {
+                let Some(action) = self.keymap.action(KeyContext::Indexer, &key) else {
+                    log_debug!("Unsupperted key pressed: {:?}", key);
+                    continue;
+                };
+                match action {
+                    KeyAction::MoveUp => self.move_selection(Direction::Up, mgr),
+                    KeyAction::MoveDown => self.move_selection(Direction::Down, mgr),
+                    KeyAction::FirstItem => {
                         self.buttons[self.cursor_position].deselect(mgr, false);
                         self.cursor_position = 0;
                         self.buttons[0].select(mgr, false);
                     }
-                    Key::CtrlE => {
+                    KeyAction::LastItem if self.visible_buttons > 0 => {
                         self.buttons[self.cursor_position].deselect(mgr, false);
                         self.cursor_position = self.visible_buttons - 1;
                         self.buttons[self.cursor_position].select(mgr, false);
                     }
-                    Key::Tab => break,
-                    Key::Enter => {
+                    KeyAction::Finish => break,
+                    KeyAction::Select if self.visible_buttons > 0 => {
                         // eprintln!(
                         //     "chunk_idx: {}, but len: {}, cursor pos: {}",
                         //     self.chunk_idx,
@@ -204,8 +226,9 @@ impl Indexer {
                         // );
                         return Some((self.chunk_idx * self.buttons.len()) + self.cursor_position);
                     }
+                    KeyAction::Help => self.help.show(KeyContext::Indexer, mgr),
                     other => {
-                        eprintln!("Unsupperted key pressed: {:?}", other);
+                        log_debug!("{} has no meaning in Indexer", other.name());
                     }
                 }
             }
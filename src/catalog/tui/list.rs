@@ -0,0 +1,493 @@
+use crate::catalog::tui::help::Help;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::{cells, WIDE_FILLER};
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
+use animaterm::prelude::map_private_char_to_key;
+use animaterm::utilities::message_box;
+use animaterm::{Glyph, Manager};
+use std::cmp::Ordering;
+use std::iter::repeat;
+use std::time::{Duration, Instant};
+
+// Chars typed within this time from each other make a single
+// phrase to jump to, otherwise a new phrase is started
+const JUMP_TIMEOUT: Duration = Duration::from_millis(1200);
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub title: String,
+    // Width in terminal cells, 0 means column takes whatever is left
+    pub width: usize,
+    // Numeric columns are aligned to the right and sorted by value
+    pub numeric: bool,
+}
+
+impl Column {
+    pub fn text(title: String, width: usize) -> Self {
+        Column {
+            title,
+            width,
+            numeric: false,
+        }
+    }
+    pub fn number(title: String, width: usize) -> Self {
+        Column {
+            title,
+            width,
+            numeric: true,
+        }
+    }
+}
+
+// Table shown by ListView, one String for every column in a row.
+// Pinned rows (like "Add new rule") are kept on top and never sorted.
+// Row index returned by ListView is an index into rows,
+// no matter how those were sorted.
+#[derive(Clone, Debug)]
+pub struct ListData {
+    pub columns: Vec<Column>,
+    pub rows: Vec<Vec<String>>,
+    pub pinned: usize,
+}
+
+impl ListData {
+    pub fn new(columns: Vec<Column>) -> Self {
+        ListData {
+            columns,
+            rows: vec![],
+            pinned: 0,
+        }
+    }
+
+    pub fn pin(&mut self, row: Vec<String>) {
+        self.rows.insert(self.pinned, row);
+        self.pinned += 1;
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    // Single row as a line of given width,
+    // for showing it outside of a ListView
+    pub fn line(&self, row: usize, width: usize) -> String {
+        let Some(row) = self.rows.get(row) else {
+            return String::new();
+        };
+        self.row_cells(row, &self.widths(width))
+            .into_iter()
+            .filter(|c| *c != WIDE_FILLER)
+            .collect()
+    }
+
+    // Width of every column when a row takes width cells,
+    // columns are separated with a single space
+    fn widths(&self, width: usize) -> Vec<usize> {
+        let separators = self.columns.len().saturating_sub(1);
+        let fixed: usize = self.columns.iter().map(|c| c.width).sum();
+        let flexible = self.columns.iter().filter(|c| c.width == 0).count();
+        let left = width.saturating_sub(fixed + separators);
+        let mut given = 0;
+        let mut flex_seen = 0;
+        self.columns
+            .iter()
+            .map(|c| {
+                if c.width > 0 {
+                    return c.width;
+                }
+                flex_seen += 1;
+                // Last flexible column also takes remainder of division
+                let w = if flex_seen == flexible {
+                    left - given
+                } else {
+                    left / flexible
+                };
+                given += w;
+                w
+            })
+            .collect()
+    }
+
+    fn row_cells(&self, row: &[String], widths: &[usize]) -> Vec<char> {
+        let mut line = vec![];
+        for (i, (column, width)) in self.columns.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line.push(' ');
+            }
+            let text = row.get(i).map(|t| t.as_str()).unwrap_or("");
+            let text_cells = cells(text, *width);
+            let padding = repeat(' ').take(width - text_cells.len());
+            if column.numeric {
+                line.extend(padding);
+                line.extend(text_cells);
+            } else {
+                line.extend(text_cells);
+                line.extend(padding);
+            }
+        }
+        line
+    }
+
+    fn compare(&self, a: usize, b: usize, column: usize) -> Ordering {
+        let empty = String::new();
+        let x = self.rows[a].get(column).unwrap_or(&empty);
+        let y = self.rows[b].get(column).unwrap_or(&empty);
+        if self.columns[column].numeric {
+            // Values that are not numbers go after those that are
+            match (x.trim().parse::<f64>(), y.trim().parse::<f64>()) {
+                (Ok(x), Ok(y)) => return x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+                (Ok(_x), Err(_e)) => return Ordering::Less,
+                (Err(_e), Ok(_y)) => return Ordering::Greater,
+                _both_err => {}
+            }
+        }
+        x.to_lowercase().cmp(&y.to_lowercase())
+    }
+}
+
+// Full screen table with a header line, one row per line
+// and a status line at the bottom.
+// Rows can be sorted by any column, typing jumps to a row
+// starting with (or containing) typed text in sorted column.
+pub struct ListView {
+    g_id: usize,
+    display_id: usize,
+    // Cells available for a row
+    width: usize,
+    // Number of visible rows
+    height: usize,
+    keymap: Keymap,
+    help: Help,
+    data: ListData,
+    widths: Vec<usize>,
+    // Indices into data.rows in order they are shown
+    order: Vec<usize>,
+    // Position within order
+    cursor: usize,
+    // First visible position
+    scroll: usize,
+    // Column and whether it is sorted descending
+    sort: Option<(usize, bool)>,
+    jump: String,
+    jump_time: Instant,
+}
+
+impl ListView {
+    pub fn new(mgr: &mut Manager) -> Self {
+        let display_id = mgr.new_display(true);
+        let help = Help::new(mgr);
+        let (cols, rows) = mgr.screen_size();
+        let m_box = message_box(
+            Some(tr("indexer.title")),
+            String::new(),
+            Glyph::plain(),
+            cols,
+            rows,
+        );
+        let g_id = mgr.add_graphic(m_box, 1, (0, 0)).unwrap();
+        ListView {
+            g_id,
+            display_id,
+            width: cols - 2,
+            // Header takes first line inside the frame
+            height: rows - 3,
+            keymap: Keymap::new(),
+            help,
+            data: ListData::new(vec![]),
+            widths: vec![],
+            order: vec![],
+            cursor: 0,
+            scroll: 0,
+            sort: None,
+            jump: String::new(),
+            jump_time: Instant::now(),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
+    pub fn cleanup(&self, main_display: usize, mgr: &mut Manager) {
+        mgr.restore_display(self.display_id, true);
+        mgr.restore_display(main_display, false);
+    }
+
+    // Returns index of selected row, or None when user backed out
+    pub fn serve(
+        &mut self,
+        main_display: usize,
+        title: &str,
+        data: ListData,
+        mgr: &mut Manager,
+    ) -> Option<usize> {
+        mgr.restore_display(self.display_id, true);
+        mgr.set_graphic(self.g_id, 0, true);
+        self.widths = data.widths(self.width);
+        self.order = (0..data.rows.len()).collect();
+        self.data = data;
+        self.cursor = 0;
+        self.scroll = 0;
+        self.sort = None;
+        self.jump.clear();
+        self.set_title(mgr, title);
+        self.draw_header(mgr);
+        self.draw_rows(mgr);
+        self.draw_status(mgr);
+        let result = self.run(mgr);
+        mgr.restore_display(main_display, true);
+        result
+    }
+
+    fn run(&mut self, mgr: &mut Manager) -> Option<usize> {
+        loop {
+            let Some(ch) = mgr.read_char() else {
+                continue;
+            };
+            if let Some(action) = self.keymap.action_for_char(KeyContext::List, ch) {
+                let last = self.order.len().saturating_sub(1);
+                match action {
+                    KeyAction::MoveUp => {
+                        let target = if self.cursor == 0 {
+                            last
+                        } else {
+                            self.cursor - 1
+                        };
+                        self.move_cursor(target, mgr);
+                    }
+                    KeyAction::MoveDown => {
+                        let target = if self.cursor >= last {
+                            0
+                        } else {
+                            self.cursor + 1
+                        };
+                        self.move_cursor(target, mgr);
+                    }
+                    KeyAction::PrevPage => {
+                        self.move_cursor(self.cursor.saturating_sub(self.height), mgr)
+                    }
+                    KeyAction::NextPage => {
+                        self.move_cursor((self.cursor + self.height).min(last), mgr)
+                    }
+                    KeyAction::FirstItem => self.move_cursor(0, mgr),
+                    KeyAction::LastItem => self.move_cursor(last, mgr),
+                    KeyAction::SortColumn => {
+                        self.sort = match self.sort {
+                            None if !self.data.columns.is_empty() => Some((0, false)),
+                            Some((column, _desc)) if column + 1 < self.data.columns.len() => {
+                                Some((column + 1, false))
+                            }
+                            _other => None,
+                        };
+                        self.sort_rows(mgr);
+                    }
+                    KeyAction::ReverseSort => {
+                        self.sort = match self.sort {
+                            Some((column, descending)) => Some((column, !descending)),
+                            None if !self.data.columns.is_empty() => Some((0, true)),
+                            None => None,
+                        };
+                        self.sort_rows(mgr);
+                    }
+                    KeyAction::ClearFilter => {
+                        self.jump.clear();
+                        self.draw_status(mgr);
+                    }
+                    KeyAction::Finish => return None,
+                    KeyAction::Select => {
+                        if let Some(idx) = self.order.get(self.cursor) {
+                            return Some(*idx);
+                        }
+                    }
+                    KeyAction::Help => self.help.show(KeyContext::List, mgr),
+                    other => {
+                        log_debug!("{} has no meaning in List", other.name());
+                    }
+                }
+            } else if ch == '\u{7f}' {
+                //Backspace
+                self.jump.pop();
+                self.jump_to(mgr);
+            } else if map_private_char_to_key(ch).is_some() || ch.is_control() {
+                log_debug!("Undefined control char: {:?}", ch);
+            } else {
+                if self.jump_time.elapsed() > JUMP_TIMEOUT {
+                    self.jump.clear();
+                }
+                self.jump.push(ch);
+                self.jump_time = Instant::now();
+                self.jump_to(mgr);
+            }
+        }
+    }
+
+    fn move_cursor(&mut self, position: usize, mgr: &mut Manager) {
+        if self.order.is_empty() {
+            return;
+        }
+        let previous = self.cursor;
+        self.cursor = position;
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+            self.draw_rows(mgr);
+        } else if self.cursor >= self.scroll + self.height {
+            self.scroll = self.cursor + 1 - self.height;
+            self.draw_rows(mgr);
+        } else {
+            self.draw_row(previous, mgr);
+            self.draw_row(self.cursor, mgr);
+        }
+        self.draw_status(mgr);
+    }
+
+    // Sorts rows following pinned ones, cursor stays on the same row
+    fn sort_rows(&mut self, mgr: &mut Manager) {
+        let current = self.order.get(self.cursor).copied();
+        let pinned = self.data.pinned.min(self.data.rows.len());
+        let mut sorted: Vec<usize> = (pinned..self.data.rows.len()).collect();
+        if let Some((column, descending)) = self.sort {
+            sorted.sort_by(|a, b| {
+                let ordering = self.data.compare(*a, *b, column);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        self.order = (0..pinned).chain(sorted).collect();
+        self.cursor = current
+            .and_then(|c| self.order.iter().position(|i| *i == c))
+            .unwrap_or(0);
+        if self.cursor < self.scroll || self.cursor >= self.scroll + self.height {
+            self.scroll = self.cursor.saturating_sub(self.height / 2);
+        }
+        self.draw_header(mgr);
+        self.draw_rows(mgr);
+        self.draw_status(mgr);
+    }
+
+    // Moves cursor to first row that starts with typed phrase in sorted
+    // column (or first column when unsorted), or contains it
+    fn jump_to(&mut self, mgr: &mut Manager) {
+        if !self.jump.is_empty() {
+            let column = self.sort.map(|(c, _d)| c).unwrap_or(0);
+            let phrase = self.jump.to_lowercase();
+            let text = |idx: &usize| {
+                self.data.rows[*idx]
+                    .get(column)
+                    .map(|t| t.to_lowercase())
+                    .unwrap_or_default()
+            };
+            let found = self
+                .order
+                .iter()
+                .position(|i| text(i).starts_with(&phrase))
+                .or_else(|| self.order.iter().position(|i| text(i).contains(&phrase)));
+            if let Some(position) = found {
+                self.move_cursor(position, mgr);
+                return;
+            }
+        }
+        self.draw_status(mgr);
+    }
+
+    fn set_title(&mut self, mgr: &mut Manager, title: &str) {
+        let mut chars = cells(title, self.width - 1).into_iter();
+        let mut g = Glyph::char('*');
+        for i in 1..self.width + 1 {
+            if let Some(char) = chars.next() {
+                g.set_char(char);
+            } else {
+                g.set_char('─');
+            }
+            mgr.set_glyph(self.g_id, g, i, 0);
+        }
+    }
+
+    fn draw_header(&self, mgr: &mut Manager) {
+        let titles: Vec<String> = self
+            .data
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match self.sort {
+                Some((c, false)) if c == i => format!("{} ▲", column.title),
+                Some((c, true)) if c == i => format!("{} ▼", column.title),
+                _other => column.title.clone(),
+            })
+            .collect();
+        let mut line = self.data.row_cells(&titles, &self.widths).into_iter();
+        for x in 1..self.width + 1 {
+            let c = line.next().unwrap_or(' ');
+            mgr.set_glyph(self.g_id, glyph(Role::ListHeader, c), x, 1);
+        }
+    }
+
+    fn draw_rows(&self, mgr: &mut Manager) {
+        for position in self.scroll..self.scroll + self.height {
+            self.draw_row(position, mgr);
+        }
+    }
+
+    fn draw_row(&self, position: usize, mgr: &mut Manager) {
+        if position < self.scroll || position >= self.scroll + self.height {
+            return;
+        }
+        let y = 2 + position - self.scroll;
+        let line = if let Some(idx) = self.order.get(position) {
+            self.data.row_cells(&self.data.rows[*idx], &self.widths)
+        } else {
+            vec![]
+        };
+        let mut line = line.into_iter();
+        for x in 1..self.width + 1 {
+            let c = line.next().unwrap_or(' ');
+            let g = if position == self.cursor && position < self.order.len() {
+                glyph(Role::MenuSelected, c)
+            } else {
+                Glyph::char(c)
+            };
+            mgr.set_glyph(self.g_id, g, x, y);
+        }
+    }
+
+    // Bottom frame line shows which rows are visible,
+    // how rows are sorted and what is being jumped to
+    fn draw_status(&self, mgr: &mut Manager) {
+        let count = self.order.len();
+        let mut status = if count == 0 {
+            format!("─{}", tr("list.empty"))
+        } else {
+            let last = (self.scroll + self.height).min(count);
+            let pages = count.div_ceil(self.height).max(1);
+            format!(
+                "─{}─{}",
+                trf("list.rows", &[&(self.scroll + 1), &last, &count]),
+                trf("list.page", &[&(self.cursor / self.height + 1), &pages])
+            )
+        };
+        if let Some((column, descending)) = self.sort {
+            let direction = if descending { '▼' } else { '▲' };
+            status.push('─');
+            status.push_str(&trf(
+                "list.sorted",
+                &[&self.data.columns[column].title, &direction],
+            ));
+        }
+        if !self.jump.is_empty() {
+            status.push('─');
+            status.push_str(&trf("list.jump", &[&self.jump]));
+        }
+        let mut chars = cells(&status, self.width).into_iter();
+        let mut g = Glyph::char('─');
+        let y = self.height + 2;
+        for x in 1..self.width + 1 {
+            g.set_char(chars.next().unwrap_or('─'));
+            mgr.set_glyph(self.g_id, g, x, y);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{log_debug, log_info, log_warn};
 use animaterm::prelude::*;
 use async_std::channel::Sender as ASender;
 use async_std::task::sleep;
@@ -13,34 +14,47 @@ use dapp_lib::prelude::GnomeId;
 use dapp_lib::prelude::SwarmID;
 use dapp_lib::prelude::SwarmName;
 use dapp_lib::Data;
-pub use notifier::Notifier;
+pub use notifier::{NoteAction, NoteCmd, Notification, Notifier, Severity};
 use std::collections::HashMap;
 use std::collections::HashSet;
 // use std::fmt::format;
 use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 use std::time::Duration;
-mod ask;
 pub mod button;
 mod content_creator;
 mod context_menu;
+pub mod dialog;
 mod editor;
+mod help;
 mod indexer;
+mod list;
 mod notifier;
 mod option;
+pub mod palette;
 mod selector;
+mod status;
 mod tile;
 mod viewer;
 use crate::catalog::logic::Tag;
 pub use crate::catalog::tui::editor::EditorResult;
+use crate::commands::Command;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::cells;
 use crate::config::Configuration;
+use crate::keymap::{KeyAction, KeyContext};
 use crate::InternalMsg;
 use crate::Toolset;
-use ask::Question;
 use context_menu::CMenu;
+pub use context_menu::MenuItem;
+pub use dialog::{Answer, Dialog};
 pub use editor::Editor;
+pub use help::Help;
 pub use indexer::Indexer;
+pub use list::{Column, ListData, ListView};
+pub use palette::Palette;
 pub use selector::Selector;
+use status::StatusLine;
 use tile::Tile;
 pub use tile::TileType;
 // use viewer::Viewer;
@@ -114,7 +128,7 @@ impl VillageLayout {
         self.visible_rows >> 1
     }
     pub fn reset_tiles(&mut self, owner_id: GnomeId, keep_neighbors: bool, mgr: &mut Manager) {
-        eprintln!("reset_tiles, keep_neighbors: {keep_neighbors}");
+        log_debug!("reset_tiles, keep_neighbors: {keep_neighbors}");
         let visible_streets = self.visible_rows >> 1;
         let mut str_names = Vec::with_capacity(visible_streets as usize);
         for _i in 0..visible_streets {
@@ -167,7 +181,7 @@ impl VillageLayout {
         fill_screen: bool,
         mgr: &mut Manager,
     ) {
-        eprintln!("set_street_names, resetting street_to_rows");
+        log_debug!("set_street_names, resetting street_to_rows");
         // let visible_streets = self.visible_rows >> 1;
         let mut g = Glyph::plain();
         self.street_to_rows = HashMap::new();
@@ -177,7 +191,7 @@ impl VillageLayout {
             // if let Some(str_name) = str_names.get(i as usize) {
             //TODO: print this name on screen
             let mut ci = 0;
-            for c in str_name.0.chars() {
+            for c in cells(&str_name.0, 32) {
                 g.set_char(c);
                 mgr.set_glyph(self.g_id, g, 32 + ci, (14 * i as usize) + 7);
                 ci += 1;
@@ -251,7 +265,7 @@ impl VillageLayout {
             // }
             // }
         } else {
-            eprintln!("Unable to find home tile in order to set owner");
+            log_warn!("Unable to find home tile in order to set owner");
         }
     }
 
@@ -278,7 +292,7 @@ impl VillageLayout {
         mgr: &mut Manager,
     ) -> (u8, u8) {
         if let Some(tile_location) = self.neighbors.get(&n_id) {
-            eprintln!("Have this neighbor already under {:?}", tile_location);
+            log_debug!("Have this neighbor already under {:?}", tile_location);
             *tile_location
         } else {
             let tile_id = self.next_neighbor_field_tile(any_tile);
@@ -311,21 +325,21 @@ impl VillageLayout {
         mgr: &mut Manager,
         // ) -> (u8, u8) {
     ) {
-        eprintln!("add_new_content CID-{} tags: {:?}", c_id, tags);
-        eprintln!("street to rows: {:?}", self.street_to_rows);
+        log_debug!("add_new_content CID-{} tags: {:?}", c_id, tags);
+        log_debug!("street to rows: {:?}", self.street_to_rows);
         for tag in tags {
-            eprintln!("Tag: {:?}", tag);
+            log_debug!("Tag: {:?}", tag);
             if let Some(restricted_rows) = self.street_to_rows.get(&tag) {
-                eprintln!("rest rows: {:?}", restricted_rows);
+                log_debug!("rest rows: {:?}", restricted_rows);
                 let tile_id = self.next_field_tile(restricted_rows, false);
-                eprintln!("Next id: {:?}", tile_id);
+                log_debug!("Next id: {:?}", tile_id);
                 if let Some(tile) = self.tiles.get_mut(&tile_id) {
                     tile.set_to_content(Some(description.clone()), d_type, c_id, false, mgr);
                 } else {
-                    eprintln!("No tile");
+                    log_debug!("No tile");
                 }
             } else {
-                eprintln!("No restricted rows");
+                log_debug!("No restricted rows");
             }
         }
         // tile_id
@@ -346,14 +360,14 @@ impl VillageLayout {
         // for tag in tags {
         // eprintln!("Tag: {:?}", tag);
         // if let Some(restricted_rows) = self.street_to_rows.get(&tag) {
-        eprintln!("rest rows: {:?}", restricted_rows);
+        log_debug!("rest rows: {:?}", restricted_rows);
         for (d_type, c_id, header) in contents {
             let tile_id = self.next_field_tile(&restricted_rows, fill_screen);
-            eprintln!("Next id: {:?}", tile_id);
+            log_debug!("Next id: {:?}", tile_id);
             if let Some(tile) = self.tiles.get_mut(&tile_id) {
                 tile.set_to_content(Some(header), d_type, c_id, false, mgr);
             } else {
-                eprintln!("No tile");
+                log_debug!("No tile");
             }
         }
         // } else {
@@ -365,17 +379,17 @@ impl VillageLayout {
 
     fn hide_content(&mut self, c_id: ContentID, tags: Vec<Tag>, mgr: &mut Manager) {
         //TODO: find tiles and change them to field
-        eprintln!("We should hide CID-{} from: {:?}", c_id, tags);
+        log_debug!("We should hide CID-{} from: {:?}", c_id, tags);
         for tag in tags {
-            eprintln!("hiding tag {:?}", tag);
+            log_debug!("hiding tag {:?}", tag);
             if let Some(rows) = self.street_to_rows.get(&tag) {
-                eprintln!("searching among rows: {:?}", rows);
+                log_debug!("searching among rows: {:?}", rows);
                 'outer: for row in rows {
                     for column in 2..self.tiles_in_row {
                         if let Some(tile) = self.tiles.get_mut(&(*row, column)) {
-                            eprintln!("Tile: {:?}", tile.tile_type);
+                            log_debug!("Tile: {:?}", tile.tile_type);
                             if tile.tile_type.is_content(c_id) {
-                                eprintln!("found it");
+                                log_debug!("found it");
                                 tile.set_to_field(mgr);
                                 break 'outer;
                             }
@@ -383,7 +397,7 @@ impl VillageLayout {
                     }
                 }
             } else {
-                eprintln!("no rows to search in");
+                log_debug!("no rows to search in");
             }
         }
     }
@@ -397,9 +411,9 @@ impl VillageLayout {
     fn next_neighbor_field_tile(&self, any_tile: bool) -> (u8, u8) {
         // let x_end = if any_tile { self.tiles_in_row } else { 2 };
         let col_end = if any_tile { self.tiles_in_row } else { 2 };
-        eprintln!("any: {any_tile}");
-        eprintln!("Row range: 0 - {}", self.visible_rows);
-        eprintln!("Columns range: 0 - {}", col_end);
+        log_debug!("any: {any_tile}");
+        log_debug!("Row range: 0 - {}", self.visible_rows);
+        log_debug!("Columns range: 0 - {}", col_end);
         for col in 0..col_end {
             // for y in 0..self.visible_rows {
             for row in 0..self.visible_rows {
@@ -514,7 +528,7 @@ pub enum ToCatalogView {
     ContentHeader(ContentID, Data),
     ReadError(ContentID, AppError),
     DisplaySelector(bool, String, Vec<String>, Vec<usize>), //bool indicates if we are founder of active swarm
-    DisplayCMenu(usize),
+    DisplayCMenu(Vec<MenuItem>),
     DisplayEditor(
         (bool, bool), // (read_only, can_edit)
         String,
@@ -529,10 +543,16 @@ pub enum ToCatalogView {
         String, //Tags
     ),
     DisplayIndexer(Vec<String>),
+    DisplayList(String, ListData),                // title, table
+    DisplayConfirm(String, String),               // title, question
+    DisplayPrompt(String, String, String, usize), // title, text, initial, byte_limit
+    DisplayChoice(String, String, Vec<String>),   // title, text, options
     SwapTiles(GnomeId),
+    SelectTile((u8, u8)),
     StreetNames(Vec<(Tag, Vec<(DataType, ContentID, String)>)>, bool), // bool if true fill entire screen
     SetNotification(usize, Vec<Glyph>),
     MoveNotification(usize, (isize, isize)),
+    Offline(bool),
     Quit,
 }
 
@@ -548,18 +568,46 @@ pub enum FromCatalogView {
     ContentInquiry(ContentID),
     NeighborSelected(SwarmName),
     KeyPress(Key),
+    DebugPanel,
+    LogViewer,
+    NotificationHistory,
+    ActivityFeed,
+    DismissNotification,
+    Quit((u8, u8)), // selected tile
     SwitchToApp(AppType, SwarmID, SwarmName),
     ShowContextMenu(TileType),
     TileSelected(TileType),
-    CMenuAction(usize),
+    CMenuAction(Option<usize>),
+    RunCommand(Command, String),
     SelectedIndices(Vec<usize>),
     EditResult(EditorResult),
     IndexResult(Option<usize>),
+    ConfirmResult(Answer),
+    ChoiceResult(Option<usize>),
+    PromptResult(Option<String>),
     CreatorResult(CreatorResult),
     VisibleStreetsCountAndMaxTiles(u8, usize),
     CursorOutOfScreen(Direction, (u8, u8)),
 }
 
+impl FromCatalogView {
+    // Messages sent only because user moved somewhere else in the village,
+    // overlays and menus bring user back to where they were
+    pub fn is_navigation(&self) -> bool {
+        match self {
+            FromCatalogView::TileSelected(_)
+            | FromCatalogView::CursorOutOfScreen(_, _)
+            | FromCatalogView::NeighborSelected(_)
+            | FromCatalogView::SwitchToApp(..) => true,
+            FromCatalogView::RunCommand(command, _arg) => matches!(
+                command,
+                Command::Street | Command::SwitchSwarm | Command::OpenContent | Command::Reconnect
+            ),
+            _other => false,
+        }
+    }
+}
+
 pub fn instantiate_tui_mgr() -> Manager {
     let capture_keyboard = true;
     let cols = None;
@@ -607,6 +655,11 @@ pub fn serve_catalog_tui(
     let mut village = VillageLayout::new(g_id, s_size);
     let mut neighboring_villages = HashMap::new();
     let visible_streets = village.initialize(my_id, &mut mgr, config.clone());
+    let keymap = config.keymap.clone();
+    editor.set_keymap(keymap.clone());
+    creator.set_keymap(keymap.clone());
+    selector.set_keymap(keymap.clone());
+    indexer.set_keymap(keymap.clone());
     let tiles_max = (village.tiles_in_row as usize) * (village.visible_rows as usize);
     // let mut street_to_rows = HashMap::new();
     let _ = to_app.send(FromCatalogView::VisibleStreetsCountAndMaxTiles(
@@ -623,79 +676,81 @@ pub fn serve_catalog_tui(
     // let mut editor = Editor::new(&mut mgr);
     // mgr.restore_display(main_display, true);
     let mut c_menu = CMenu::new(&mut mgr);
+    c_menu.set_keymap(keymap.clone());
+    let mut list_view = ListView::new(&mut mgr);
+    list_view.set_keymap(keymap.clone());
     let mut d_type_map = HashMap::new();
-    d_type_map.insert(DataType::Data(0), "Text".to_string());
-    d_type_map.insert(DataType::Data(1), "Text file".to_string());
-    d_type_map.insert(DataType::Data(2), "Binary file".to_string());
-    let question = Question::new(&mut mgr);
+    d_type_map.insert(DataType::Data(0), tr("dtype.text"));
+    d_type_map.insert(DataType::Data(1), tr("dtype.text_file"));
+    d_type_map.insert(DataType::Data(2), tr("dtype.binary_file"));
+    let mut dialog = Dialog::new(&mut mgr);
+    dialog.set_keymap(keymap.clone());
     // let mut am_i_founder = false;
     // let mut manifest = Manifest::new(AppType::Catalog, HashMap::new());
-    let _set_id = c_menu.add_set(
-        &mut mgr,
-        vec![
-            " TAGs".to_string(),
-            " Data types".to_string(),
-            " Add TAG".to_string(),
-            " Add data type".to_string(),
-            " Create new…".to_string(),
-            " Public IPs".to_string(),
-            " Active Swarms".to_string(),
-            " Add Search".to_string(),
-        ],
-    );
-    let _set_id = c_menu.add_set(
-        &mut mgr,
-        vec![
-            " Nowa Notatka".to_string(),
-            " Usuń Notatkę".to_string(),
-            " Kopiuj Odnośnik".to_string(),
-            " Edytuj Link".to_string(),
-            " Było".to_string(),
-            " Będzie".to_string(),
-            " Bardzo".to_string(),
-            " Miło".to_string(),
-        ],
-    );
-    eprintln!("Added CMenu set: {}", _set_id);
-    let _set_id = c_menu.add_set(
-        &mut mgr,
-        vec![
-            " Wklej Odnośnik".to_string(),
-            " Na".to_string(),
-            " Razie".to_string(),
-            " Nic".to_string(),
-            " Ciekawego".to_string(),
-            " Tu".to_string(),
-            " Nie".to_string(),
-            " List Searches".to_string(),
-        ],
-    );
-    eprintln!("Added CMenu set: {}", _set_id);
-    let _set_id = c_menu.add_set(
-        &mut mgr,
-        vec![
-            " Żądanie".to_string(),
-            " Odpowiedź".to_string(),
-            " Żądanie2".to_string(),
-            " Odpowiedź2".to_string(),
-            " Żądanie3".to_string(),
-            " Odpowiedź3".to_string(),
-            " Żądanie4".to_string(),
-            " Odpowiedź4".to_string(),
-        ],
-    );
-    eprintln!("Added CMenu set: {}", _set_id);
     // let mut manifest_req: u8 = 0;
+    let mut palette = Palette::new(&mut mgr);
+    palette.set_keymap(keymap.clone());
+    let mut help = Help::new(&mut mgr);
+    help.set_keymap(keymap.clone());
+    let status_line = StatusLine::new(&mut mgr);
+    // Action of a command chosen from palette, run as if it's key was pressed
+    let mut pending: Option<KeyAction> = None;
     loop {
-        if let Some(key) = mgr.read_key() {
-            let terminate = key == Key::Q || key == Key::ShiftQ;
-            match key {
-                Key::AltEnter | Key::Space => {
+        let mut next_action = pending.take();
+        if next_action.is_none() {
+            if let Some(key) = mgr.read_key() {
+                let Some(action) = keymap.action(KeyContext::Catalog, &key) else {
+                    // eprintln!("Send to app: {}", key);
+                    if to_app.send(FromCatalogView::KeyPress(key)).is_err() {
+                        break;
+                    }
+                    continue;
+                };
+                next_action = Some(action);
+            }
+        }
+        if let Some(action) = next_action {
+            match action {
+                KeyAction::Quit => {
+                    let answer =
+                        dialog.confirm(&tr("dialog.quit_title"), &tr("dialog.quit"), &mut mgr);
+                    if answer == Answer::Yes {
+                        let _ = to_app.send(FromCatalogView::Quit(village.selected_tile));
+                        break;
+                    }
+                }
+                KeyAction::DebugPanel => {
+                    let _ = to_app.send(FromCatalogView::DebugPanel);
+                }
+                KeyAction::LogViewer => {
+                    let _ = to_app.send(FromCatalogView::LogViewer);
+                }
+                KeyAction::Notifications => {
+                    let _ = to_app.send(FromCatalogView::NotificationHistory);
+                }
+                KeyAction::ActivityFeed => {
+                    let _ = to_app.send(FromCatalogView::ActivityFeed);
+                }
+                KeyAction::DismissNotification => {
+                    let _ = to_app.send(FromCatalogView::DismissNotification);
+                }
+                KeyAction::Help => help.show(KeyContext::Catalog, &mut mgr),
+                KeyAction::CommandPalette => {
+                    if let Some((command, argument)) = palette.run(KeyContext::Catalog, &mut mgr) {
+                        match command.key_action() {
+                            Some(action) => pending = Some(action),
+                            None => {
+                                let _ = to_app.send(FromCatalogView::RunCommand(command, argument));
+                            }
+                        }
+                    }
+                }
+                KeyAction::ContextMenu => {
                     // TODO: minimize logic in tui - simply send a Selected message to logic
                     //       and wait for instructions
                     let _ = to_app.send(FromCatalogView::ShowContextMenu(village.get_selection()));
                 }
-                Key::Left | Key::H | Key::CtrlB => {
+                KeyAction::MoveLeft => {
                     if village.select_next(Direction::Left, &mut mgr) {
                         let _ = to_app.send(FromCatalogView::CursorOutOfScreen(
                             Direction::Left,
@@ -703,7 +758,7 @@ pub fn serve_catalog_tui(
                         ));
                     }
                 }
-                Key::Right | Key::L | Key::CtrlF => {
+                KeyAction::MoveRight => {
                     if village.select_next(Direction::Right, &mut mgr) {
                         let _ = to_app.send(FromCatalogView::CursorOutOfScreen(
                             Direction::Right,
@@ -711,7 +766,7 @@ pub fn serve_catalog_tui(
                         ));
                     }
                 }
-                Key::Up | Key::K | Key::CtrlP => {
+                KeyAction::MoveUp => {
                     if village.select_next(Direction::Up, &mut mgr) {
                         let _ = to_app.send(FromCatalogView::CursorOutOfScreen(
                             Direction::Up,
@@ -719,7 +774,7 @@ pub fn serve_catalog_tui(
                         ));
                     }
                 }
-                Key::Down | Key::J | Key::CtrlN => {
+                KeyAction::MoveDown => {
                     if village.select_next(Direction::Down, &mut mgr) {
                         let _ = to_app.send(FromCatalogView::CursorOutOfScreen(
                             Direction::Down,
@@ -727,10 +782,10 @@ pub fn serve_catalog_tui(
                         ));
                     }
                 }
-                Key::AltCtrlH => {
+                KeyAction::HomeVillage => {
                     let _ = to_app.send(FromCatalogView::NeighborSelected(village.my_name.clone()));
                     // TODO: remove swap_tiles logic from presentation, it should not be here
-                    eprintln!("AltCtrlH");
+                    log_debug!("HomeVillage");
                     swap_tiles(
                         village.my_name.founder,
                         &mut village,
@@ -738,15 +793,15 @@ pub fn serve_catalog_tui(
                         &mut mgr,
                     );
                 }
-                Key::Home => {
+                KeyAction::FirstItem => {
                     let sel = village.home_tile;
                     village.select(&sel, &mut mgr);
                 }
-                Key::End => {
+                KeyAction::LastItem => {
                     let sel = (village.selected_tile.0, village.tiles_in_row - 1);
                     village.select(&sel, &mut mgr);
                 }
-                Key::Enter => {
+                KeyAction::Select => {
                     let tile = village.get_selection();
                     // if let TileType::Neighbor(n_id) = &tile {
                     //     // eprintln!("Enter");
@@ -754,9 +809,9 @@ pub fn serve_catalog_tui(
                     // }
                     let _ = to_app.send(FromCatalogView::TileSelected(tile));
                 }
-                Key::F => {
+                KeyAction::OpenForum => {
                     // TODO: make sure we are attached to target swarm
-                    eprintln!("F: SwitchToApp");
+                    log_debug!("OpenForum: SwitchToApp");
                     let _ = to_app.send(FromCatalogView::SwitchToApp(
                         AppType::Forum,
                         SwarmID(2),
@@ -768,11 +823,7 @@ pub fn serve_catalog_tui(
                     break;
                 }
                 other => {
-                    // eprintln!("Send to app: {} terminate: {}", other, terminate);
-                    let res = to_app.send(FromCatalogView::KeyPress(other));
-                    if res.is_err() || terminate {
-                        break;
-                    }
+                    log_debug!("{} has no meaning in Catalog", other.name());
                 }
             }
         }
@@ -782,28 +833,30 @@ pub fn serve_catalog_tui(
                     village.reset_tiles(village.my_name.founder, !fill_screen, &mut mgr);
                     village.set_street_names(str_names, fill_screen, &mut mgr);
                 }
-                ToCatalogView::DisplayCMenu(set_id) => {
-                    let action = c_menu.show(&mut mgr, set_id, village.cm_position());
+                ToCatalogView::DisplayCMenu(items) => {
+                    let action = c_menu.show(&mut mgr, &items, village.cm_position());
                     let _ = to_app.send(FromCatalogView::CMenuAction(action));
                 }
                 ToCatalogView::Neighbors(neighbors, use_any_tile) => {
                     //TODO: first make sure neighbor is not placed on screen
-                    eprintln!(
+                    log_debug!(
                         "curr villge ns: {:?}, use any: {use_any_tile}",
                         village.neighbors.keys()
                     );
                     for neighbor in neighbors.into_iter() {
                         let _tile_id = village.add_new_neighbor(neighbor, use_any_tile, &mut mgr);
-                        eprintln!("Showing Neighbor {}: {:?}", neighbor, _tile_id);
+                        log_debug!("Showing Neighbor {}: {:?}", neighbor, _tile_id);
                     }
                 }
                 ToCatalogView::NeighborLeft(n_id) => {
                     let _tile_id = village.remove_neighbor(n_id, &mut mgr);
                 }
                 ToCatalogView::AppendContent(c_id, d_type, tags, description) => {
-                    eprintln!(
+                    log_debug!(
                         "ToPresentation::AppendContent({:?}, {:?})\nTags: {:?}",
-                        c_id, d_type, tags
+                        c_id,
+                        d_type,
+                        tags
                     );
                     //TODO: First we check if this content should be displayed on screen
                     let mut filtered_tags = village.filter_visible_tags(tags);
@@ -817,11 +870,11 @@ pub fn serve_catalog_tui(
                     for (loc, _t) in tile_locations {
                         // eprintln!("tloc: {:?}", _t);
                         for (tag, rows) in &village.street_to_rows {
-                            eprintln!("tag: {:?}, rows: {:?}", tag, rows);
+                            log_debug!("tag: {:?}, rows: {:?}", tag, rows);
                             if rows.contains(&loc.0) {
                                 // and subscract Tags that correspond to them
                                 filtered_tags.remove(&tag);
-                                eprintln!("Rem: {:?}", tag);
+                                log_debug!("Rem: {:?}", tag);
                             }
                         }
                     }
@@ -833,7 +886,7 @@ pub fn serve_catalog_tui(
                     village.hide_content(c_id, tags, &mut mgr);
                 }
                 ToCatalogView::ContentHeader(c_id, _data) => {
-                    eprintln!("Showing Contents of {}", c_id,);
+                    log_debug!("Showing Contents of {}", c_id,);
                     // let read_only = !am_i_founder;
 
                     // if let Some((d_type, data)) = creator.show(
@@ -869,7 +922,7 @@ pub fn serve_catalog_tui(
                     // manifest = mani.clone();
                     // if manifest_req == 1 {
                     // let tag_names = mani.tag_names();
-                    eprintln!("All tag names: {:?}", tag_names);
+                    log_debug!("All tag names: {:?}", tag_names);
                     let _selected = selector.select(
                         &header,
                         &tag_names,
@@ -924,9 +977,25 @@ pub fn serve_catalog_tui(
                 }
                 ToCatalogView::DisplayIndexer(headers) => {
                     let index_result =
-                        indexer.serve(main_display, "This is an Indexer", headers, &mut mgr);
+                        indexer.serve(main_display, &tr("indexer.title"), headers, &mut mgr);
                     let _ = to_app.send(FromCatalogView::IndexResult(index_result));
                 }
+                ToCatalogView::DisplayList(title, data) => {
+                    let index_result = list_view.serve(main_display, &title, data, &mut mgr);
+                    let _ = to_app.send(FromCatalogView::IndexResult(index_result));
+                }
+                ToCatalogView::DisplayConfirm(title, text) => {
+                    let answer = dialog.confirm(&title, &text, &mut mgr);
+                    let _ = to_app.send(FromCatalogView::ConfirmResult(answer));
+                }
+                ToCatalogView::DisplayChoice(title, text, options) => {
+                    let choice = dialog.choose(&title, &text, &options, &mut mgr);
+                    let _ = to_app.send(FromCatalogView::ChoiceResult(choice));
+                }
+                ToCatalogView::DisplayPrompt(title, text, initial, byte_limit) => {
+                    let text = dialog.prompt(&title, &text, &initial, byte_limit, &mut mgr);
+                    let _ = to_app.send(FromCatalogView::PromptResult(text));
+                }
                 ToCatalogView::DisplayCreator(read_only, d_type, description, tags) => {
                     //TODO
                     let c_result =
@@ -934,14 +1003,19 @@ pub fn serve_catalog_tui(
                     let _ = to_app.send(FromCatalogView::CreatorResult(c_result));
                 }
                 ToCatalogView::SwapTiles(g_id) => {
-                    eprintln!("ToPresentation::SwapTiles");
+                    log_debug!("ToPresentation::SwapTiles");
                     swap_tiles(g_id, &mut village, &mut neighboring_villages, &mut mgr);
                 }
+                ToCatalogView::SelectTile(tile) => {
+                    village.select(&tile, &mut mgr);
+                }
                 ToCatalogView::ReadError(c_id, error) => {
-                    if question.ask(
-                        &format!("Error reading CID {}:\n {}", c_id, error),
+                    let answer = dialog.confirm(
+                        &tr("dialog.error_title"),
+                        &trf("content.read_error", &[&c_id, &error]),
                         &mut mgr,
-                    ) {
+                    );
+                    if answer == Answer::Yes {
                         // let manifest = create_manifest(&mut mgr);
                         // TODO: send Data to Swarm
                     }
@@ -954,6 +1028,10 @@ pub fn serve_catalog_tui(
                 ToCatalogView::MoveNotification(g_id, offset) => {
                     mgr.move_graphic(g_id, 4, offset);
                 }
+                ToCatalogView::Offline(true) => {
+                    status_line.show(&tr("status.offline"), &mut mgr);
+                }
+                ToCatalogView::Offline(false) => status_line.hide(&mut mgr),
                 ToCatalogView::Quit => {
                     break;
                 }
@@ -964,7 +1042,7 @@ pub fn serve_catalog_tui(
     // creator.cleanup(main_display, &mut mgr);
     // selector.cleanup(main_display, &mut mgr);
     // indexer.cleanup(main_display, &mut mgr);
-    eprintln!("Done serving TUI");
+    log_debug!("Done serving TUI");
     // mgr.terminate();
     // (mgr, config)
     Toolset::fold(
@@ -985,7 +1063,7 @@ fn swap_tiles(
     mgr: &mut Manager,
 ) {
     if n_id.is_any() {
-        eprintln!("We should clear all tiles");
+        log_debug!("We should clear all tiles");
         village.neighbors = HashMap::new();
         village.clear_street_names(2, mgr);
         for tile in village.tiles.values_mut() {
@@ -1001,7 +1079,7 @@ fn swap_tiles(
         let existing_tile_types = village.get_tile_headers();
         neighboring_villages.insert(owner, existing_tile_types);
         village.set_owner(n_id, mgr);
-        eprintln!(
+        log_debug!(
             "NV keys: {:?}, searching for: {:?}",
             neighboring_villages.keys(),
             n_id
@@ -1021,13 +1099,13 @@ fn swap_tiles(
                         }
                     }
                     TileType::Neighbor(n_id) => {
-                        eprintln!("TileNeigh: {n_id}");
+                        log_debug!("TileNeigh: {n_id}");
                         if let Some(tile) = village.tiles.get_mut(&slot) {
                             // We should get updated Neighbor list from internal mechanism
                             // tile.set_to_field(mgr);
                             village.neighbors.insert(n_id, slot);
                             tile.set_to_neighbor(n_id, false, mgr);
-                            eprintln!("Set to neigh{n_id}");
+                            log_debug!("Set to neigh{n_id}");
                         }
                     }
                     TileType::Field => {
@@ -1049,14 +1127,14 @@ fn swap_tiles(
                 }
             }
         } else {
-            eprintln!("Reseting tiles");
+            log_debug!("Reseting tiles");
             village.reset_tiles(n_id, false, mgr);
         };
         let sel = village.selected_tile;
         village.select(&sel, mgr);
     } else {
         //TODO
-        eprintln!("No owner defined for village");
+        log_debug!("No owner defined for village");
     }
 }
 
@@ -1077,5 +1155,5 @@ pub async fn from_catalog_tui_adapter(
             }
         }
     }
-    eprintln!("from_tui_adapter is done");
+    log_info!("from_tui_adapter is done");
 }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::time::Duration;
 
 use animaterm::Glyph;
@@ -8,9 +9,100 @@ use async_std::channel::Receiver;
 use async_std::channel::Sender;
 use async_std::task::sleep;
 use async_std::task::spawn;
+use dapp_lib::prelude::{ContentID, GnomeId, SwarmName};
 use std::sync::mpsc::Sender as SyncSender;
 
 use super::ToCatalogView;
+use crate::common::i18n::{tr, trf};
+use crate::common::logging::timestamp;
+use crate::common::text_width::cells;
+use crate::common::theme::{glyph, Role};
+use crate::{log_debug, log_info, log_warn};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn text(&self) -> String {
+        match self {
+            Severity::Info => tr("notify.info"),
+            Severity::Warning => tr("notify.warning"),
+            Severity::Error => tr("notify.error"),
+        }
+    }
+
+    fn role(&self) -> Role {
+        match self {
+            Severity::Info => Role::Notification,
+            Severity::Warning => Role::NotificationWarning,
+            Severity::Error => Role::NotificationError,
+        }
+    }
+}
+
+// Something user can do about a notification from notification history
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoteAction {
+    OpenContent(SwarmName, ContentID),
+    SwitchToSwarm(SwarmName),
+    AcceptFriend(GnomeId),
+    ShowLog,
+}
+
+impl NoteAction {
+    pub fn text(&self) -> String {
+        match self {
+            NoteAction::OpenContent(s_name, c_id) => trf("notify.open_content", &[&c_id, &s_name]),
+            NoteAction::SwitchToSwarm(s_name) => trf("notify.switch_to", &[&s_name]),
+            NoteAction::AcceptFriend(g_id) => trf("notify.accept_friend", &[&g_id]),
+            NoteAction::ShowLog => tr("notify.show_log"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub severity: Severity,
+    pub text: String,
+    pub action: Option<NoteAction>,
+    pub time: String,
+}
+
+impl Notification {
+    pub fn new(severity: Severity, text: String, action: Option<NoteAction>) -> Self {
+        Notification {
+            severity,
+            text,
+            action,
+            time: timestamp(),
+        }
+    }
+
+    // Single line for notification history
+    pub fn line(&self) -> String {
+        // Only hours, minutes and seconds out of full timestamp
+        let time = self.time.get(11..19).unwrap_or(&self.time);
+        if self.action.is_some() {
+            format!("{} {} {} »", time, self.severity.text(), self.text)
+        } else {
+            format!("{} {} {}", time, self.severity.text(), self.text)
+        }
+    }
+}
+
+pub enum NoteCmd {
+    Show(Notification),
+    // Animation step of given generation, steps of older generations
+    // belong to dismissed notes and are ignored
+    Tick(u32),
+    Dismiss,
+    Pause(bool),
+    Stop,
+}
 
 enum NotifierState {
     SlidingIn(u8),
@@ -24,9 +116,13 @@ pub struct Notifier {
     cols: usize,
     rows: usize,
     state: NotifierState,
-    buffer: Vec<String>,
-    sender: Sender<Option<String>>,
-    receiver: Receiver<Option<String>>,
+    buffer: VecDeque<Notification>,
+    paused: bool,
+    generation: u32,
+    // How many columns the note has moved left from it's initial position
+    shift: isize,
+    sender: Sender<NoteCmd>,
+    receiver: Receiver<NoteCmd>,
     tui_sender: SyncSender<ToCatalogView>,
 }
 //TODO: Create an async channel for Notifier to receive notifications.
@@ -37,12 +133,12 @@ impl Notifier {
     pub fn new(
         offset: (isize, isize),
         mgr: &mut Manager,
-        (sender, receiver): (Sender<Option<String>>, Receiver<Option<String>>),
+        (sender, receiver): (Sender<NoteCmd>, Receiver<NoteCmd>),
         tui_sender: SyncSender<ToCatalogView>,
     ) -> Self {
         let cols = 30;
         let rows = 3;
-        let g = Glyph::red();
+        let g = glyph(Role::Notification, ' ');
         let frame = vec![g; cols * rows];
         let mut library = HashMap::new();
         library.insert(0, frame);
@@ -54,7 +150,10 @@ impl Notifier {
             cols,
             rows,
             state: NotifierState::OffScreen,
-            buffer: Vec::with_capacity(16),
+            buffer: VecDeque::with_capacity(16),
+            paused: false,
+            generation: 0,
+            shift: 0,
             sender,
             receiver,
             tui_sender,
@@ -73,105 +172,141 @@ impl Notifier {
         loop {
             // eprintln!("Notifier waiting…");
             let recv_result = self.receiver.recv().await;
-            // eprintln!("Notifier ggot: {:?}", recv_result);
             match recv_result {
-                Ok(Some(new_note)) => {
-                    if new_note.is_empty() {
-                        eprintln!("Notifier received empty note, terminating");
-                        break;
-                    }
-                    //TODO: display a new_note or add it to buffer
-                    match self.state {
-                        NotifierState::OffScreen => {
-                            self.state = NotifierState::SlidingIn(self.cols as u8);
-                            let note_frame = self.prepare_note(new_note);
-                            spawn(timer(self.sender.clone(), self.cols));
-                            // eprintln!("Timer spawned");
-                            let _res = self
-                                .tui_sender
-                                .send(ToCatalogView::SetNotification(self.id, note_frame));
-                            eprintln!("SetNote send result: {_res:?}");
-                        }
-                        _ => {
-                            self.buffer.push(new_note);
-                        }
+                Ok(NoteCmd::Show(note)) => {
+                    if self.paused || !matches!(self.state, NotifierState::OffScreen) {
+                        self.buffer.push_back(note);
+                    } else {
+                        self.present(note);
                     }
                 }
-                Ok(None) => match self.state {
-                    NotifierState::SlidingIn(step) => {
-                        let next_step = step.wrapping_sub(1);
-                        self.state = if next_step == 0 {
-                            NotifierState::Presenting
-                        } else {
-                            NotifierState::SlidingIn(next_step)
-                        };
-                        let _ = self
-                            .tui_sender
-                            .send(ToCatalogView::MoveNotification(self.id, (-1, 0)));
+                Ok(NoteCmd::Tick(generation)) => {
+                    if generation != self.generation {
+                        continue;
                     }
-                    NotifierState::Presenting => {
-                        self.state = NotifierState::SlidingOut(self.cols as u8);
-                    }
-                    NotifierState::SlidingOut(step) => {
-                        let next_step = step.wrapping_sub(1);
-                        self.state = if next_step == 0 {
-                            NotifierState::OffScreen
-                        } else {
-                            NotifierState::SlidingOut(next_step)
-                        };
-                        let _ = self
-                            .tui_sender
-                            .send(ToCatalogView::MoveNotification(self.id, (1, 0)));
-                        if !self.buffer.is_empty() {
-                            let next_note = self.buffer.remove(0);
-                            let _ = self.sender.send(Some(next_note)).await;
-                        }
+                    self.step();
+                }
+                Ok(NoteCmd::Dismiss) => {
+                    if matches!(self.state, NotifierState::OffScreen) {
+                        continue;
                     }
-                    NotifierState::OffScreen => {
-                        eprintln!("Did not expect a timeout");
+                    self.generation = self.generation.wrapping_add(1);
+                    let _ = self
+                        .tui_sender
+                        .send(ToCatalogView::MoveNotification(self.id, (self.shift, 0)));
+                    self.shift = 0;
+                    self.state = NotifierState::OffScreen;
+                    self.present_next();
+                }
+                Ok(NoteCmd::Pause(paused)) => {
+                    self.paused = paused;
+                    if matches!(self.state, NotifierState::OffScreen) {
+                        self.present_next();
                     }
-                },
+                }
+                Ok(NoteCmd::Stop) => {
+                    log_info!("Notifier received Stop, terminating");
+                    break;
+                }
                 Err(err) => {
-                    eprintln!("Notifier terminating ({:?})", err);
+                    log_info!("Notifier terminating ({:?})", err);
                     break;
                 }
             }
         }
-        eprintln!("Notifier is done.");
+        log_info!("Notifier is done.");
+    }
+
+    fn present(&mut self, note: Notification) {
+        self.state = NotifierState::SlidingIn(self.cols as u8);
+        let note_frame = self.prepare_note(note);
+        spawn(timer(self.sender.clone(), self.cols, self.generation));
+        let _res = self
+            .tui_sender
+            .send(ToCatalogView::SetNotification(self.id, note_frame));
+        log_debug!("SetNote send result: {_res:?}");
+    }
+
+    fn present_next(&mut self) {
+        if self.paused {
+            return;
+        }
+        if let Some(next_note) = self.buffer.pop_front() {
+            self.present(next_note);
+        }
+    }
+
+    fn step(&mut self) {
+        match self.state {
+            NotifierState::SlidingIn(step) => {
+                let next_step = step.wrapping_sub(1);
+                self.state = if next_step == 0 {
+                    NotifierState::Presenting
+                } else {
+                    NotifierState::SlidingIn(next_step)
+                };
+                self.shift += 1;
+                let _ = self
+                    .tui_sender
+                    .send(ToCatalogView::MoveNotification(self.id, (-1, 0)));
+            }
+            NotifierState::Presenting => {
+                self.state = NotifierState::SlidingOut(self.cols as u8);
+            }
+            NotifierState::SlidingOut(step) => {
+                let next_step = step.wrapping_sub(1);
+                self.state = if next_step == 0 {
+                    NotifierState::OffScreen
+                } else {
+                    NotifierState::SlidingOut(next_step)
+                };
+                self.shift -= 1;
+                let _ = self
+                    .tui_sender
+                    .send(ToCatalogView::MoveNotification(self.id, (1, 0)));
+                if matches!(self.state, NotifierState::OffScreen) {
+                    self.present_next();
+                }
+            }
+            NotifierState::OffScreen => {
+                log_warn!("Did not expect a timeout");
+            }
+        }
     }
-    fn prepare_note(&self, text: String) -> Vec<Glyph> {
+
+    fn prepare_note(&self, note: Notification) -> Vec<Glyph> {
         let mut frame = Vec::with_capacity(self.cols * self.rows);
-        let mut red = Glyph::red();
+        let mut g = glyph(note.severity.role(), ' ');
         for _i in 0..self.cols {
-            frame.push(red);
+            frame.push(g);
         }
         let mut remaining_glyphs = self.cols;
-        for (i, char) in text.chars().enumerate() {
+        for (i, char) in cells(&note.text, self.cols).into_iter().enumerate() {
             if i >= self.cols {
                 break;
             }
             remaining_glyphs -= 1;
-            red.set_char(char);
-            frame.push(red);
+            g.set_char(char);
+            frame.push(g);
         }
-        red.set_char(' ');
+        g.set_char(' ');
         for _i in 0..self.cols + remaining_glyphs {
-            frame.push(red);
+            frame.push(g);
         }
         frame
     }
 }
 
-async fn timer(sender: Sender<Option<String>>, counter: usize) {
+async fn timer(sender: Sender<NoteCmd>, counter: usize, generation: u32) {
     let step = Duration::from_millis(500 / counter as u64);
     for _i in 0..counter {
         sleep(step).await;
-        let _ = sender.send(None).await;
+        let _ = sender.send(NoteCmd::Tick(generation)).await;
     }
     sleep(Duration::from_secs(3)).await;
-    let _ = sender.send(None).await;
+    let _ = sender.send(NoteCmd::Tick(generation)).await;
     for _i in 0..counter {
         sleep(step).await;
-        let _ = sender.send(None).await;
+        let _ = sender.send(NoteCmd::Tick(generation)).await;
     }
 }
@@ -1,5 +1,6 @@
 // use crate::logic::Tag;
-use animaterm::prelude::Glyph;
+use crate::common::text_width::indexed_cells;
+use crate::common::theme::{glyph, Role};
 use animaterm::prelude::Graphic;
 use animaterm::prelude::Manager;
 use std::collections::HashMap;
@@ -8,26 +9,24 @@ pub struct Option {
     pub index: usize,
     g_id: usize,
     active: bool,
+    matched: Vec<usize>,
+    painted: bool,
 }
 
 impl Option {
     pub fn new(offset: (isize, isize), mgr: &mut Manager) -> Self {
         let mut library = HashMap::new();
-        let mut g = Glyph::char('-');
         // 0 - frame not selected, not activated
         // 1 - frame selected, not activated
         // 2 - frame not selected, activated
         // 3 - frame selected, activated
-        let frame = vec![g; 32];
+        let frame = vec![glyph(Role::Option, '-'); 32];
         library.insert(0, frame);
-        g.set_reverse(true);
-        let frame = vec![g; 32];
+        let frame = vec![glyph(Role::OptionSelected, '-'); 32];
         library.insert(1, frame);
-        g.set_blink(true);
-        let frame = vec![g; 32];
+        let frame = vec![glyph(Role::OptionSelectedMarked, '-'); 32];
         library.insert(3, frame);
-        g.set_reverse(false);
-        let frame = vec![g; 32];
+        let frame = vec![glyph(Role::OptionMarked, '-'); 32];
         library.insert(2, frame);
         let g_id = mgr
             .add_graphic(Graphic::new(32, 1, 0, library, None), 4, offset)
@@ -36,6 +35,8 @@ impl Option {
             index: 0,
             g_id,
             active: false,
+            matched: vec![],
+            painted: false,
         }
     }
     pub fn present(&mut self, selected: bool, activated: bool, mgr: &mut Manager) {
@@ -55,61 +56,54 @@ impl Option {
         mgr.move_graphic(self.g_id, 0, (0, 0));
         // mgr.set_graphic(self.g_id, 0, false);
     }
-    pub fn update(&mut self, id: usize, text: &str, mgr: &mut Manager) {
-        self.index = id;
-        mgr.set_graphic(self.g_id, 0, false);
-        let mut c_iter = text.chars();
-        let mut g = Glyph::plain();
-        let gp = Glyph::plain();
-        for i in 0..32 {
-            if let Some(c) = c_iter.next() {
-                g.set_char(c);
-                mgr.set_glyph(self.g_id, g, i, 0);
-            } else {
-                mgr.set_glyph(self.g_id, gp, i, 0);
-            }
-        }
-        mgr.set_graphic(self.g_id, 1, false);
-        let mut c_iter = text.chars();
-        let mut g = Glyph::plain();
-        g.set_reverse(true);
-        let mut gp = Glyph::plain();
-        gp.set_reverse(true);
-        for i in 0..32 {
-            if let Some(c) = c_iter.next() {
-                g.set_char(c);
-                mgr.set_glyph(self.g_id, g, i, 0);
-            } else {
-                mgr.set_glyph(self.g_id, gp, i, 0);
-            }
+    // Paints option text into all four frames, characters at matched
+    // positions are highlighted. Nothing is painted when given option
+    // is already shown, returns whether painting took place.
+    pub fn update(&mut self, id: usize, text: &str, matched: &[usize], mgr: &mut Manager) -> bool {
+        if self.painted && self.index == id && self.matched == matched {
+            return false;
         }
-        mgr.set_graphic(self.g_id, 2, false);
-        let mut c_iter = text.chars();
-        let mut g = Glyph::plain();
-        g.set_blink(true);
-        let gp = Glyph::plain();
-        for i in 0..32 {
-            if let Some(c) = c_iter.next() {
-                g.set_char(c);
-                mgr.set_glyph(self.g_id, g, i, 0);
-            } else {
-                mgr.set_glyph(self.g_id, gp, i, 0);
-            }
-        }
-        mgr.set_graphic(self.g_id, 3, false);
-        let mut c_iter = text.chars();
-        let mut g = Glyph::plain();
-        g.set_reverse(true);
-        g.set_blink(true);
-        let mut gp = Glyph::plain();
-        gp.set_reverse(true);
-        for i in 0..32 {
-            if let Some(c) = c_iter.next() {
-                g.set_char(c);
+        self.index = id;
+        self.matched = matched.to_vec();
+        self.painted = true;
+        let cells = indexed_cells(text, 32);
+        // frame, text, matched text, padding
+        let frames = [
+            (0, Role::Option, Role::OptionMatch, Role::Option),
+            (
+                1,
+                Role::OptionSelected,
+                Role::OptionSelectedMatch,
+                Role::OptionSelected,
+            ),
+            (2, Role::OptionMarked, Role::OptionMatch, Role::Option),
+            (
+                3,
+                Role::OptionSelectedMarked,
+                Role::OptionSelectedMatch,
+                Role::OptionSelected,
+            ),
+        ];
+        for (f_id, role, m_role, p_role) in frames {
+            mgr.set_graphic(self.g_id, f_id, false);
+            for i in 0..32 {
+                let g = if let Some((c, c_idx)) = cells.get(i) {
+                    if matched.contains(c_idx) {
+                        glyph(m_role, *c)
+                    } else {
+                        glyph(role, *c)
+                    }
+                } else {
+                    glyph(p_role, ' ')
+                };
                 mgr.set_glyph(self.g_id, g, i, 0);
-            } else {
-                mgr.set_glyph(self.g_id, gp, i, 0);
             }
         }
+        true
+    }
+
+    // Next update will paint even if contents did not change
+    pub fn invalidate(&mut self) {
+        self.painted = false;
     }
 }
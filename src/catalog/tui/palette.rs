@@ -0,0 +1,350 @@
+use crate::catalog::tui::help::Help;
+use crate::commands::{commands_for, Argument, Command};
+use crate::common::fuzzy::rank;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::{cells, char_width, str_width, WIDE_FILLER};
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
+use animaterm::prelude::map_private_char_to_key;
+use animaterm::utilities::message_box;
+use animaterm::{Glyph, Manager};
+
+// Window at the top of the screen listing every command
+// available in given context. First word typed is matched
+// fuzzily against command names, the rest is command's argument.
+// Tab completes highlighted name, Enter runs it, Escape closes.
+pub struct Palette {
+    g_id: usize,
+    // Cells available inside the frame
+    width: usize,
+    height: usize,
+    keymap: Keymap,
+    help: Help,
+}
+
+// Single row of the list
+struct Entry {
+    usage: String,
+    description: String,
+    keys: String,
+}
+
+struct Line {
+    input: Vec<char>,
+    cursor: usize,
+}
+
+impl Line {
+    fn filter(&self) -> String {
+        self.input
+            .iter()
+            .take_while(|c| !c.is_whitespace())
+            .collect()
+    }
+
+    fn argument(&self) -> String {
+        let text: String = self.input.iter().collect();
+        match text.trim_start().split_once(char::is_whitespace) {
+            Some((_name, arg)) => arg.trim().to_string(),
+            None => String::new(),
+        }
+    }
+
+    fn set(&mut self, text: String) {
+        self.input = text.chars().collect();
+        self.cursor = self.input.len();
+    }
+}
+
+impl Palette {
+    pub fn new(mgr: &mut Manager) -> Self {
+        let (cols, rows) = mgr.screen_size();
+        let width = cols.min(80);
+        let height = rows.min(16);
+        let m_box = message_box(
+            Some(String::new()),
+            String::new(),
+            Glyph::plain(),
+            width,
+            height,
+        );
+        let offset = (((cols - width) >> 1) as isize, 1);
+        let g_id = mgr.add_graphic(m_box, 0, offset).unwrap();
+        Palette {
+            g_id,
+            width: width - 2,
+            height: height - 2,
+            keymap: Keymap::new(),
+            help: Help::new(mgr),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
+
+    // Chosen command with it's argument (possibly empty),
+    // None when palette was closed
+    pub fn run(&self, context: KeyContext, mgr: &mut Manager) -> Option<(Command, String)> {
+        let commands = commands_for(context);
+        let names: Vec<String> = commands.iter().map(|c| c.name().to_string()).collect();
+        let entries: Vec<Entry> = commands
+            .iter()
+            .map(|c| Entry {
+                usage: c.usage(),
+                description: c.description(),
+                keys: c
+                    .key_action()
+                    .map(|a| self.keymap.keys_for(context, a).join(" "))
+                    .unwrap_or_default(),
+            })
+            .collect();
+        let usage_width = entries
+            .iter()
+            .map(|e| str_width(&e.usage))
+            .max()
+            .unwrap_or(0)
+            .min(self.width / 2);
+        let mut line = Line {
+            input: vec![],
+            cursor: 0,
+        };
+        let mut filter = line.filter();
+        let mut ranked = rank(&filter, &names);
+        let mut selected = 0;
+        let mut scroll = 0;
+        let mut hint: Option<String> = None;
+
+        mgr.move_graphic(self.g_id, 3, (0, 0));
+        self.set_title(&tr("palette.title"), mgr);
+        let chosen = loop {
+            // Keep selected command visible
+            let visible = self.height - 2;
+            if selected < scroll {
+                scroll = selected;
+            } else if selected >= scroll + visible {
+                scroll = selected + 1 - visible;
+            }
+            self.draw_input(&line, mgr);
+            for row in 0..visible {
+                let y = row + 2;
+                match ranked.get(scroll + row) {
+                    Some((idx, matched)) => self.draw_row(
+                        y,
+                        &entries[*idx],
+                        matched,
+                        usage_width,
+                        scroll + row == selected,
+                        mgr,
+                    ),
+                    None => self.set_row(y, "", mgr),
+                }
+            }
+            let status = if let Some(hint) = hint.take() {
+                hint
+            } else if ranked.is_empty() {
+                tr("palette.no_match")
+            } else {
+                String::new()
+            };
+            self.set_row(self.height, &status, mgr);
+
+            let ch = loop {
+                if let Some(ch) = mgr.read_char() {
+                    break ch;
+                }
+            };
+            if let Some(action) = self.keymap.action_for_char(KeyContext::Palette, ch) {
+                match action {
+                    KeyAction::MoveUp => selected = selected.saturating_sub(1),
+                    KeyAction::MoveDown => {
+                        if selected + 1 < ranked.len() {
+                            selected += 1;
+                        }
+                    }
+                    KeyAction::MoveLeft => line.cursor = line.cursor.saturating_sub(1),
+                    KeyAction::MoveRight => {
+                        line.cursor = (line.cursor + 1).min(line.input.len());
+                    }
+                    KeyAction::LineStart => line.cursor = 0,
+                    KeyAction::LineEnd => line.cursor = line.input.len(),
+                    KeyAction::Delete => {
+                        if line.cursor < line.input.len() {
+                            line.input.remove(line.cursor);
+                        }
+                    }
+                    KeyAction::KillToEnd => line.input.truncate(line.cursor),
+                    KeyAction::NextField => {
+                        if let Some((idx, _m)) = ranked.get(selected) {
+                            let argument = line.argument();
+                            line.set(complete(commands[*idx], &argument));
+                        }
+                    }
+                    KeyAction::Select => {
+                        let Some((idx, _m)) = ranked.get(selected) else {
+                            continue;
+                        };
+                        let cmd = commands[*idx];
+                        let argument = line.argument();
+                        if argument.is_empty()
+                            && cmd.argument() != Argument::None
+                            && !cmd.argument_optional()
+                        {
+                            line.set(complete(cmd, &argument));
+                            hint = Some(trf("palette.missing", &[&cmd.argument().hint()]));
+                        } else {
+                            break Some((cmd, argument));
+                        }
+                    }
+                    KeyAction::Finish => break None,
+                    KeyAction::Help => self.help.show(KeyContext::Palette, mgr),
+                    other => {
+                        log_debug!("{} has no meaning in Palette", other.name());
+                        continue;
+                    }
+                }
+            } else if ch == '\u{7f}' {
+                //Backspace
+                if line.cursor > 0 {
+                    line.cursor -= 1;
+                    line.input.remove(line.cursor);
+                }
+            } else if map_private_char_to_key(ch).is_some() || ch.is_control() {
+                log_debug!("Undefined control char: {:?}", ch);
+                continue;
+            } else {
+                line.input.insert(line.cursor, ch);
+                line.cursor += 1;
+            }
+            if line.filter() != filter {
+                filter = line.filter();
+                ranked = rank(&filter, &names);
+                selected = 0;
+            }
+        };
+        mgr.move_graphic(self.g_id, 0, (0, 0));
+        chosen
+    }
+
+    fn set_title(&self, title: &str, mgr: &mut Manager) {
+        let mut chars = cells(title, self.width - 1).into_iter();
+        let mut g = Glyph::plain();
+        for x in 1..self.width + 1 {
+            g.set_char(chars.next().unwrap_or('─'));
+            mgr.set_glyph(self.g_id, g, x, 0);
+        }
+    }
+
+    fn draw_input(&self, line: &Line, mgr: &mut Manager) {
+        let prompt = [':', ' '];
+        for (x, c) in prompt.iter().enumerate() {
+            mgr.set_glyph(self.g_id, Glyph::char(*c), x + 1, 1);
+        }
+        let left = prompt.len() + 1;
+        let room = self.width - prompt.len();
+        // Scroll input so that cursor is always visible
+        let mut start = 0;
+        while line.input[start..line.cursor]
+            .iter()
+            .map(|c| char_width(*c))
+            .sum::<usize>()
+            >= room
+        {
+            start += 1;
+        }
+        let mut x = left;
+        for (i, c) in line.input.iter().enumerate().skip(start) {
+            let w = char_width(*c);
+            if w == 0 {
+                continue;
+            }
+            if x + w > self.width + 1 {
+                break;
+            }
+            let role = if i == line.cursor {
+                Role::EditorCursor
+            } else {
+                Role::EditorText
+            };
+            mgr.set_glyph(self.g_id, glyph(role, *c), x, 1);
+            if w == 2 {
+                mgr.set_glyph(self.g_id, glyph(role, WIDE_FILLER), x + 1, 1);
+            }
+            x += w;
+        }
+        let cursor_x = if line.cursor == line.input.len() {
+            x
+        } else {
+            0
+        };
+        while x < self.width + 1 {
+            let role = if x == cursor_x {
+                Role::EditorCursor
+            } else {
+                Role::EditorText
+            };
+            mgr.set_glyph(self.g_id, glyph(role, ' '), x, 1);
+            x += 1;
+        }
+    }
+
+    // Usage with matched chars highlighted, description
+    // and key bindings aligned to the right
+    fn draw_row(
+        &self,
+        y: usize,
+        entry: &Entry,
+        matched: &[usize],
+        usage_width: usize,
+        selected: bool,
+        mgr: &mut Manager,
+    ) {
+        let (base, hit) = if selected {
+            (Role::MenuSelected, Role::OptionSelectedMatch)
+        } else {
+            (Role::Option, Role::OptionMatch)
+        };
+        let mut row: Vec<(char, Role)> = Vec::with_capacity(self.width);
+        row.push((' ', base));
+        for (i, c) in cells(&entry.usage, usage_width).into_iter().enumerate() {
+            let role = if matched.contains(&i) { hit } else { base };
+            row.push((c, role));
+        }
+        while row.len() < usage_width + 3 {
+            row.push((' ', base));
+        }
+        let keys_width = str_width(&entry.keys) + 1;
+        let description_width = self.width.saturating_sub(row.len() + keys_width + 1);
+        for c in cells(&entry.description, description_width) {
+            row.push((c, base));
+        }
+        while row.len() + keys_width < self.width {
+            row.push((' ', base));
+        }
+        for c in cells(&entry.keys, self.width.saturating_sub(row.len())) {
+            row.push((c, base));
+        }
+        for x in 0..self.width {
+            let (c, role) = row.get(x).copied().unwrap_or((' ', base));
+            mgr.set_glyph(self.g_id, glyph(role, c), x + 1, y);
+        }
+    }
+
+    fn set_row(&self, y: usize, text: &str, mgr: &mut Manager) {
+        let mut chars = cells(text, self.width).into_iter();
+        for x in 1..self.width + 1 {
+            mgr.set_glyph(self.g_id, Glyph::char(chars.next().unwrap_or(' ')), x, y);
+        }
+    }
+}
+
+// Input line with command's full name, ready for an argument
+fn complete(cmd: Command, argument: &str) -> String {
+    if cmd.argument() == Argument::None {
+        cmd.name().to_string()
+    } else {
+        format!("{} {}", cmd.name(), argument)
+    }
+}
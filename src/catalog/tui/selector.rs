@@ -1,11 +1,17 @@
+use crate::catalog::tui::help::Help;
 use crate::catalog::tui::option;
+use crate::common::fuzzy::rank;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::cells;
 use animaterm::prelude::map_private_char_to_key;
 use animaterm::Glyph;
 use animaterm::Graphic;
-use animaterm::Key;
 use animaterm::Manager;
 use dapp_lib::prelude::AppType;
 use option::Option;
+
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::log_debug;
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -32,11 +38,14 @@ pub struct Selector {
     options: Vec<Option>,
     selected_option: usize,
     _last_updated_row: usize,
+    keymap: Keymap,
+    help: Help,
 }
 
 impl Selector {
     pub fn new(_app_type: AppType, mgr: &mut Manager) -> Self {
         let display_id = mgr.new_display(true);
+        let help = Help::new(mgr);
         let (cols, rows) = mgr.screen_size();
         let width = cols;
         let height = rows;
@@ -62,8 +71,15 @@ impl Selector {
             options: tags,
             selected_option: 0,
             _last_updated_row: 1,
+            keymap: Keymap::new(),
+            help,
         }
     }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
     pub fn cleanup(&self, main_display: usize, mgr: &mut Manager) {
         mgr.restore_display(self.display_id, true);
         mgr.restore_display(main_display, false);
@@ -77,144 +93,124 @@ impl Selector {
         mgr: &mut Manager,
         quit_on_first_select: bool,
     ) -> Vec<usize> {
-        // TODO: allow for swaping pages of options
-        // if we can not fit all of them on one screen
         mgr.restore_display(self.display_id, true);
-        // eprintln!("Options to present: {:?}", options);
         mgr.move_graphic(self.g_id, 3, (0, 0));
         let gp = Glyph::plain();
         let mut g = Glyph::plain();
-        let filter_header = format!("Filter: ",);
+        let filter_header = tr("selector.filter");
         let mut filter = String::with_capacity(64);
-        let mut filter_len = filter_header.len();
-        let mut iter = header.chars();
+        let first_line = header.lines().next().unwrap_or("");
+        let mut iter = cells(first_line, self.width - 1).into_iter();
         for x in 1..self.width {
             if let Some(c) = iter.next() {
-                if c == '\n' {
-                    break;
-                }
                 g.set_char(c);
                 mgr.set_glyph(self.g_id, g, x, 0);
             } else {
                 mgr.set_glyph(self.g_id, gp, x, 0);
             }
         }
-        let mut iter = filter_header.chars();
-        for x in 1..self.width {
-            if let Some(c) = iter.next() {
-                if c == '\n' {
-                    break;
-                }
-                g.set_char(c);
-                mgr.set_glyph(self.g_id, g, x, 1);
-            } else {
-                mgr.set_glyph(self.g_id, gp, x, 1);
-            }
+        // Buttons may still show options from previous call
+        for option in &mut self.options {
+            option.invalidate();
         }
-        let mut options_len = options.len();
         let buttons_len = self.options.len();
         let options_in_row = self.width >> 5;
-        let opt_rows_count = (self.height >> 1) - 1;
-        let opts_per_page = options_in_row * opt_rows_count;
-        let mut option_pages = vec![];
-        let mut page = Vec::with_capacity(opts_per_page);
-        let mut total_added = 0;
-        for i in 0..options_len {
-            page.push(i);
-            total_added += 1;
-            if total_added % opts_per_page == 0 {
-                option_pages.push(page);
-                page = Vec::with_capacity(opts_per_page);
-            }
-            if total_added >= options_len {
-                if !page.is_empty() {
-                    option_pages.push(page);
-                }
-                break;
-            }
-        }
-        if option_pages.is_empty() {
-            option_pages.push(vec![]);
-        }
+        // Options matching filter, best first, with positions of matched chars
+        let mut ranked = rank(&filter, options);
         let mut curr_page = 0;
-        let options_count = total_added;
+        self.selected_option = 0;
         let mut filter_updated = false;
-        options_len = option_pages[curr_page].len();
-        let mut opt_page = &option_pages[curr_page];
+        let mut page_changed = true;
+        // Buttons below this one may not show current page yet
         let mut last_updated = 0;
-        if let Some(opt_id) = opt_page.get(last_updated) {
-            let sel = self.selected_option == last_updated;
-            self.options[last_updated].update(*opt_id, options.get(*opt_id).unwrap(), mgr);
-            self.options[last_updated].present(sel, selected.contains(opt_id), mgr);
-        } else {
-            self.options[last_updated].hide(mgr);
-        }
 
         loop {
+            let pages_count = ranked.len().div_ceil(buttons_len).max(1);
+            let page_start = curr_page * buttons_len;
+            let page = &ranked[page_start..(page_start + buttons_len).min(ranked.len())];
+            let options_len = page.len();
+            if page_changed {
+                let page_info = trf(
+                    "selector.page",
+                    &[
+                        &(curr_page + 1),
+                        &pages_count,
+                        &ranked.len(),
+                        &options.len(),
+                    ],
+                );
+                self.show_filter(&filter_header, &filter, &page_info, mgr);
+                last_updated = 0;
+                page_changed = false;
+            }
+            // Buttons are brought up to date one at a time between reading
+            // keys, those already showing right option are not repainted,
+            // so typing into filter stays responsive
+            while last_updated < buttons_len {
+                let i = last_updated;
+                last_updated += 1;
+                if let Some((opt_id, matched)) = page.get(i) {
+                    let painted = self.options[i].update(*opt_id, &options[*opt_id], matched, mgr);
+                    self.options[i].present(
+                        self.selected_option == i,
+                        selected.contains(opt_id),
+                        mgr,
+                    );
+                    if painted {
+                        break;
+                    }
+                } else {
+                    self.options[i].hide(mgr);
+                }
+            }
             if let Some(char) = mgr.read_char() {
-                let action;
-                // eprintln!("A char read {:?}", char);
-                if let Some(key) = map_private_char_to_key(char) {
-                    // Now that we added filter and read char instead of Key
-                    // there will be some control characters that we would like to use
-                    // for navigation/selection instead of pushing them into filter string
-                    // So we might define an Action enum and map a char or key into that
-                    action = match key {
-                        Key::AltTab => Action::Select,
-                        Key::PgUp => Action::PrevPage,
-                        Key::PgDn => Action::NextPage,
-                        Key::Up => Action::MoveUp,
-                        Key::Down => Action::MoveDown,
-                        Key::Left => Action::MoveLeft,
-                        Key::Right => Action::MoveRight,
-                        _other => {
-                            // eprintln!("Key {} not defined", key);
+                // Control characters and special keys are used
+                // for navigation/selection instead of pushing them into filter string
+                let action = if let Some(k_action) =
+                    self.keymap.action_for_char(KeyContext::Selector, char)
+                {
+                    match k_action {
+                        KeyAction::Select => Action::Select,
+                        KeyAction::Finish => Action::Finish,
+                        KeyAction::PrevPage => Action::PrevPage,
+                        KeyAction::NextPage => Action::NextPage,
+                        KeyAction::MoveUp => Action::MoveUp,
+                        KeyAction::MoveDown => Action::MoveDown,
+                        KeyAction::MoveLeft => Action::MoveLeft,
+                        KeyAction::MoveRight => Action::MoveRight,
+                        KeyAction::ClearFilter => Action::ClearFilter,
+                        KeyAction::Help => {
+                            self.help.show(KeyContext::Selector, mgr);
                             Action::None
                         }
-                    };
-                } else if char.is_control() {
-                    action = match char {
-                        //CtrlB
-                        '\u{2}' => Action::MoveLeft,
-                        //CtrlD
-                        '\u{4}' => Action::NextPage,
-                        //CtrlF
-                        '\u{6}' => Action::MoveRight,
-                        //CtrlJ or Enter
-                        '\u{a}' => Action::Select,
-                        //CtrlK
-                        '\u{b}' => Action::ClearFilter,
-                        //CtrlN
-                        '\u{e}' => Action::MoveDown,
-                        //CtrlP
-                        '\u{10}' => Action::MoveUp,
-                        //CtrlU
-                        '\u{15}' => Action::PrevPage,
-                        //Esc
-                        '\u{1b}' => Action::Finish,
-                        //Backspace
-                        '\u{7f}' => Action::DelFromFilter,
                         other => {
-                            eprintln!("Undefined control char: {:?}", other);
+                            log_debug!("{} has no meaning in Selector", other.name());
                             Action::None
                         }
                     }
+                } else if char == '\u{7f}' {
+                    //Backspace
+                    Action::DelFromFilter
+                } else if map_private_char_to_key(char).is_some() || char.is_control() {
+                    log_debug!("Undefined control char: {:?}", char);
+                    Action::None
                 } else {
-                    action = Action::AddToFilter(char);
-                }
+                    Action::AddToFilter(char)
+                };
 
-                // eprintln!("Selected action: {:?}", action);
                 match action {
                     Action::Finish => {
                         self.selected_option = 0;
                         break;
                     }
                     Action::Select => {
-                        let idx = self.options[self.selected_option].index;
+                        let Some((idx, _matched)) = page.get(self.selected_option) else {
+                            continue;
+                        };
+                        let idx = *idx;
                         if let Some(position) = selected.iter().position(|&x| x == idx) {
                             selected.remove(position);
                             self.options[self.selected_option].present(true, false, mgr);
-                            // let index = option_pages[curr_page][self.selected_option];
                         } else if quit_on_first_select {
                             selected = vec![idx];
                             self.selected_option = 0;
@@ -225,141 +221,45 @@ impl Selector {
                         }
                     }
                     Action::NextPage => {
-                        if option_pages.len() <= 1 {
+                        if pages_count <= 1 {
                             continue;
                         }
                         self.selected_option = 0;
                         curr_page += 1;
-                        if curr_page >= option_pages.len() {
+                        if curr_page >= pages_count {
                             curr_page = 0;
                         }
-                        options_len = option_pages[curr_page].len();
-                        opt_page = &option_pages[curr_page];
-                        last_updated = 0;
-                        if let Some(opt_id) = opt_page.get(last_updated) {
-                            let sel = self.selected_option == last_updated;
-                            self.options[last_updated].update(
-                                *opt_id,
-                                options.get(*opt_id).unwrap(),
-                                mgr,
-                            );
-                            self.options[last_updated].present(sel, selected.contains(opt_id), mgr);
-                        } else {
-                            self.options[last_updated].hide(mgr);
-                        }
+                        page_changed = true;
                     }
                     Action::PrevPage => {
-                        if option_pages.len() <= 1 {
+                        if pages_count <= 1 {
                             continue;
                         }
+                        self.selected_option = 0;
                         if curr_page == 0 {
-                            curr_page = option_pages.len() - 1;
+                            curr_page = pages_count - 1;
                         } else {
                             curr_page -= 1;
                         }
-                        self.selected_option = 0;
-                        options_len = option_pages[curr_page].len();
-                        opt_page = &option_pages[curr_page];
-                        last_updated = 0;
-                        if let Some(opt_id) = opt_page.get(last_updated) {
-                            let sel = self.selected_option == last_updated;
-                            self.options[last_updated].update(
-                                *opt_id,
-                                options.get(*opt_id).unwrap(),
-                                mgr,
-                            );
-                            self.options[last_updated].present(sel, selected.contains(opt_id), mgr);
-                        } else {
-                            self.options[last_updated].hide(mgr);
-                        }
+                        page_changed = true;
                     }
-                    Action::MoveUp => {
+                    Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight => {
                         if options_len == 0 {
                             continue;
                         }
-                        let index = option_pages[curr_page][self.selected_option];
+                        let index = page[self.selected_option].0;
                         self.options[self.selected_option].present(
                             false,
                             selected.contains(&index),
                             mgr,
                         );
-                        if options_len >= options_in_row {
-                            if self.selected_option >= options_in_row {
-                                self.selected_option -= options_in_row;
-                            } else {
-                                let mut new_positions = Vec::with_capacity(options_in_row);
-                                for i in 0..options_in_row {
-                                    new_positions.push(options_len - options_in_row + i);
-                                }
-                                new_positions.rotate_right(options_len % options_in_row);
-                                self.selected_option = new_positions[self.selected_option];
-                            }
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            true,
-                            selected.contains(&index),
-                            mgr,
-                        );
-                    }
-                    Action::MoveDown => {
-                        if options_len == 0 {
-                            continue;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            false,
-                            selected.contains(&index),
-                            mgr,
+                        self.selected_option = Selector::moved(
+                            &action,
+                            self.selected_option,
+                            options_len,
+                            options_in_row,
                         );
-                        self.selected_option += options_in_row;
-                        if self.selected_option >= options_len {
-                            self.selected_option = self.selected_option % options_in_row;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            true,
-                            selected.contains(&index),
-                            mgr,
-                        );
-                    }
-                    Action::MoveRight => {
-                        if options_len == 0 {
-                            continue;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            false,
-                            selected.contains(&index),
-                            mgr,
-                        );
-                        self.selected_option += 1;
-                        if self.selected_option >= options_len {
-                            self.selected_option = 0;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            true,
-                            selected.contains(&index),
-                            mgr,
-                        );
-                    }
-                    Action::MoveLeft => {
-                        if options_len == 0 {
-                            continue;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
-                        self.options[self.selected_option].present(
-                            false,
-                            selected.contains(&index),
-                            mgr,
-                        );
-                        if self.selected_option == 0 {
-                            self.selected_option = options_len - 1;
-                        } else {
-                            self.selected_option -= 1;
-                        }
-                        let index = option_pages[curr_page][self.selected_option];
+                        let index = page[self.selected_option].0;
                         self.options[self.selected_option].present(
                             true,
                             selected.contains(&index),
@@ -367,140 +267,104 @@ impl Selector {
                         );
                     }
                     Action::AddToFilter(char) => {
-                        eprintln!(
-                            "updating filter with: '{:?}',alfa_num:{}, ctrl:{}",
-                            char,
-                            char.is_alphanumeric(),
-                            char.is_control()
-                        );
-                        filter_updated = true;
-                        // TODO: every time we modify filter's contents we need to recalculate
-                        //       what options are going to be displayed on screen
-                        //       if option count has changed we need to update displayed options
-                        //       when necessary
-                        //       Sometimes the number of options does change, but all the options
-                        //       that are currently displayed should stay unchanged.
-                        //       Sometimes first n options does not change, so we only need to
-                        //       update following options
-                        // TODO: in order to implement above we need to compare every Option's
-                        //       current id with updated id, and act only if those are different.
+                        log_debug!("updating filter with: '{:?}'", char);
                         filter.push(char);
-                        filter_len += 1;
-
-                        // TODO: and then compare currently displayed page with new corresponding
-                        //       page and update options starting from first difference onwards
-                        //       if ids match we skip given option and move forward
-                        //       if we run out of new options, we hide remaining option buttons
-
-                        g.set_char(char);
-                        mgr.set_glyph(self.g_id, g, filter_len, 1);
+                        filter_updated = true;
                     }
                     Action::DelFromFilter => {
-                        if filter_len > filter_header.len() {
+                        if filter.pop().is_some() {
                             filter_updated = true;
-                            filter.pop();
-                            g.set_char(' ');
-                            mgr.set_glyph(self.g_id, g, filter_len, 1);
-                            filter_len = filter_len - 1;
                         }
                     }
                     Action::ClearFilter => {
-                        while filter_len > filter_header.len() {
+                        if !filter.is_empty() {
+                            filter.clear();
                             filter_updated = true;
-                            filter.pop();
-                            g.set_char(' ');
-                            mgr.set_glyph(self.g_id, g, filter_len, 1);
-                            filter_len = filter_len - 1;
                         }
                     }
-
-                    Action::None => {
-                        // eprintln!("Other key pressed: {:?}", other);
-                    }
+                    Action::None => {}
                 }
             }
             if filter_updated {
-                let filter_words: Vec<_> = filter.split_whitespace().collect();
-                // eprintln!("Filter words:");
-                // for word in filter_words.iter() {
-                //     eprintln!("W: {}", word);
-                // }
-                let filtered_options = if filter_words.is_empty() {
-                    let mut f_o = Vec::with_capacity(options_count);
-                    for i in 0..options_count {
-                        f_o.push(i);
-                    }
-                    f_o
+                // Buttons are compared with new ranking as they are updated,
+                // only those that differ get repainted
+                ranked = rank(&filter, options);
+                log_debug!("{} options match filter", ranked.len());
+                curr_page = 0;
+                self.selected_option = 0;
+                page_changed = true;
+                filter_updated = false;
+            }
+        }
+        for tag in &mut self.options {
+            tag.hide(mgr);
+        }
+        mgr.move_graphic(self.g_id, 0, (0, 0));
+        selected
+    }
+
+    // Position of selection after moving it in given direction
+    // within a grid of options_len options
+    fn moved(action: &Action, current: usize, options_len: usize, options_in_row: usize) -> usize {
+        match action {
+            Action::MoveUp => {
+                if options_len < options_in_row {
+                    current
+                } else if current >= options_in_row {
+                    current - options_in_row
                 } else {
-                    options
-                        .iter()
-                        .enumerate()
-                        .filter(|(_i, x)| {
-                            let mut contains = false;
-                            for word in &filter_words {
-                                if x.contains(word) {
-                                    contains = true;
-                                    break;
-                                }
-                            }
-                            contains
-                        })
-                        .map(|(i, _x)| i)
-                        .collect::<Vec<_>>()
-                };
-                eprintln!("options count has changed: {}", filtered_options.len());
-                option_pages = vec![];
-                let pages_iter = filtered_options.chunks_exact(buttons_len);
-                let leftover = pages_iter.remainder();
-                for option_page in pages_iter {
-                    option_pages.push(Vec::from(option_page));
-                }
-                if !leftover.is_empty() {
-                    let mut more = Vec::with_capacity(leftover.len());
-                    for id in leftover {
-                        more.push(*id);
+                    let mut new_positions = Vec::with_capacity(options_in_row);
+                    for i in 0..options_in_row {
+                        new_positions.push(options_len - options_in_row + i);
                     }
-                    option_pages.push(more);
+                    new_positions.rotate_right(options_len % options_in_row);
+                    new_positions[current]
                 }
-                if option_pages.is_empty() {
-                    eprintln!("no opts!");
-                    option_pages.push(vec![]);
+            }
+            Action::MoveDown => {
+                let next = current + options_in_row;
+                if next >= options_len {
+                    next % options_in_row
+                } else {
+                    next
                 }
-                curr_page = 0;
-                self.selected_option = 0;
-                opt_page = &option_pages[curr_page];
-                options_len = opt_page.len();
-                last_updated = 0;
-                if let Some(opt_id) = opt_page.get(last_updated) {
-                    let sel = self.selected_option == last_updated;
-                    self.options[last_updated].update(*opt_id, options.get(*opt_id).unwrap(), mgr);
-                    self.options[last_updated].present(sel, selected.contains(opt_id), mgr);
+            }
+            Action::MoveRight => {
+                if current + 1 >= options_len {
+                    0
                 } else {
-                    self.options[last_updated].hide(mgr);
+                    current + 1
                 }
-                filter_updated = false;
             }
-            if last_updated + 1 < buttons_len {
-                last_updated = last_updated + 1;
-                if let Some(opt_idx) = opt_page.get(last_updated) {
-                    let sel = self.selected_option == last_updated;
-                    // if self.options[last_updated].index != *opt_idx {
-                    self.options[last_updated].update(
-                        *opt_idx,
-                        options.get(*opt_idx).unwrap(),
-                        mgr,
-                    );
-                    // }
-                    self.options[last_updated].present(sel, selected.contains(opt_idx), mgr);
+            Action::MoveLeft => {
+                if current == 0 {
+                    options_len - 1
                 } else {
-                    self.options[last_updated].hide(mgr);
+                    current - 1
                 }
             }
+            _other => current,
         }
-        for tag in &mut self.options {
-            tag.hide(mgr);
+    }
+
+    // Filter on the left, page indicator on the right of second row
+    fn show_filter(&self, filter_header: &str, filter: &str, page_info: &str, mgr: &mut Manager) {
+        let gp = Glyph::plain();
+        let mut g = Glyph::plain();
+        let info = cells(page_info, (self.width - 1) / 2);
+        let info_start = self.width - info.len();
+        let text = format!("{}{}", filter_header, filter);
+        let mut iter = cells(&text, info_start - 1).into_iter();
+        for x in 1..self.width {
+            if x >= info_start {
+                g.set_char(info[x - info_start]);
+                mgr.set_glyph(self.g_id, g, x, 1);
+            } else if let Some(c) = iter.next() {
+                g.set_char(c);
+                mgr.set_glyph(self.g_id, g, x, 1);
+            } else {
+                mgr.set_glyph(self.g_id, gp, x, 1);
+            }
         }
-        mgr.move_graphic(self.g_id, 0, (0, 0));
-        selected
     }
 }
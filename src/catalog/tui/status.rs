@@ -0,0 +1,45 @@
+use crate::common::text_width::cells;
+use crate::common::theme::{glyph, Role};
+use animaterm::prelude::Graphic;
+use animaterm::prelude::Manager;
+use std::collections::HashMap;
+
+// Single line in bottom right corner telling about a state
+// that lasts, unlike notifications that slide away
+pub struct StatusLine {
+    g_id: usize,
+    width: usize,
+}
+
+impl StatusLine {
+    pub fn new(mgr: &mut Manager) -> Self {
+        let (cols, rows) = mgr.screen_size();
+        let width = cols.min(32);
+        let mut library = HashMap::new();
+        library.insert(0, vec![glyph(Role::NotificationError, ' '); width]);
+        let offset = ((cols - width) as isize, rows as isize - 1);
+        let g_id = mgr
+            .add_graphic(Graphic::new(width, 1, 0, library, None), 0, offset)
+            .unwrap();
+        StatusLine { g_id, width }
+    }
+
+    pub fn show(&self, text: &str, mgr: &mut Manager) {
+        let text_cells = cells(text, self.width);
+        let padding = self.width - text_cells.len();
+        mgr.set_graphic(self.g_id, 0, false);
+        for i in 0..self.width {
+            let c = if i < padding {
+                ' '
+            } else {
+                text_cells[i - padding]
+            };
+            mgr.set_glyph(self.g_id, glyph(Role::NotificationError, c), i, 0);
+        }
+        mgr.move_graphic(self.g_id, 4, (0, 0));
+    }
+
+    pub fn hide(&self, mgr: &mut Manager) {
+        mgr.move_graphic(self.g_id, 0, (0, 0));
+    }
+}
@@ -1,5 +1,8 @@
 use std::path::PathBuf;
 
+use crate::common::i18n::trf;
+use crate::common::text_width::cells;
+use crate::common::theme::{glyph, theme_name, Role};
 use animaterm::Glyph;
 use animaterm::Graphic;
 use animaterm::Manager;
@@ -52,6 +55,13 @@ pub struct Tile {
 }
 impl Tile {
     pub fn new(offset: (isize, isize), mgr: &mut Manager, asset_dir: &PathBuf) -> Self {
+        // Themes may come with their own tile graphics
+        let theme_dir = asset_dir.join(theme_name());
+        let asset_dir = if theme_dir.is_dir() {
+            &theme_dir
+        } else {
+            asset_dir
+        };
         let c_path = asset_dir.join("content.g");
         let c_graphic = Graphic::from_file(c_path).unwrap();
         let id = mgr.add_graphic(c_graphic, 2, offset).unwrap();
@@ -74,11 +84,11 @@ impl Tile {
         if let Some(text) = optional_text {
             let words = text.split_whitespace();
             let g = Glyph::transparent();
-            let mut gt = Glyph::char(' ');
+            let mut gt = glyph(Role::TileText, ' ');
             let mut test_frame = vec![g; 84];
             let mut curr_line = 1;
             for word in words {
-                for (i, char) in word.chars().enumerate() {
+                for (i, char) in cells(word, 10).into_iter().enumerate() {
                     if i >= 10 {
                         continue;
                     }
@@ -129,7 +139,8 @@ impl Tile {
     }
     pub fn set_to_neighbor(&mut self, n_id: GnomeId, selected: bool, mgr: &mut Manager) {
         // TODO: make use of n_id
-        self.update_tile_text(Some(format!("Nejbor {:x}", n_id.0)), mgr);
+        let n_text = trf("tile.neighbor", &[&format!("{:x}", n_id.0)]);
+        self.update_tile_text(Some(n_text), mgr);
         self.select_frame = 7;
         self.deselect_frame = 6;
         self.tile_type = TileType::Neighbor(n_id);
@@ -1,4 +1,6 @@
 use crate::catalog::logic::Manifest;
+use crate::common::i18n::{tr, trf};
+use crate::common::text_width::cells;
 use animaterm::prelude::Glyph;
 use animaterm::prelude::Graphic;
 use animaterm::prelude::Manager;
@@ -56,16 +58,13 @@ impl Viewer {
         d_type_map: &HashMap<DataType, String>,
     ) -> Option<(DataType, Data)> {
         let d_text = if let Some(_text) = d_type_map.get(&d_type) {
-            &format!(
-                "Define new DataType                       bytes left: {}",
-                30
-            )
+            trf("viewer.define_dtype", &[&30])
         } else {
-            "EDIT    DataType: Unknown"
+            tr("viewer.unknown_dtype")
         };
         let p = Glyph::plain();
         let mut g = Glyph::plain();
-        let mut char_iter = d_text.chars();
+        let mut char_iter = cells(&d_text, self.width - 2).into_iter();
         for x in 2..self.width {
             if let Some(c) = char_iter.next() {
                 g.set_char(c);
@@ -74,8 +73,8 @@ impl Viewer {
                 mgr.set_glyph(self.g_id, p, x, 1);
             }
         }
-        let t_text = "Descpription: Plaintext file";
-        let mut char_iter = t_text.chars();
+        let t_text = tr("viewer.plaintext");
+        let mut char_iter = cells(&t_text, self.width - 2).into_iter();
         for x in 2..self.width {
             if let Some(c) = char_iter.next() {
                 g.set_char(c);
@@ -84,8 +83,8 @@ impl Viewer {
                 mgr.set_glyph(self.g_id, p, x, 3);
             }
         }
-        let b_text = "                    APPLY    CANCEL";
-        let mut char_iter = b_text.chars();
+        let b_text = tr("viewer.buttons");
+        let mut char_iter = cells(&b_text, self.width - 2).into_iter();
         let y = self.height - 2;
         for x in 2..self.width {
             if let Some(c) = char_iter.next() {
@@ -0,0 +1,303 @@
+use crate::common::i18n::tr;
+use crate::keymap::{KeyAction, KeyContext};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Commands are typed into command palette, opened with ':' (or F2)
+// in Catalog and in Forum. A command is it's name, optionally
+// followed by an argument, for example:
+// street Music
+// append 42
+// swarm Friends
+// Commands that have a key binding are run exactly as if
+// that key was pressed, others are sent to application logic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Argument {
+    None,
+    Tag,
+    Swarm,
+    Neighbor,
+    Content,
+    Text,
+}
+
+impl Argument {
+    pub fn hint(&self) -> String {
+        match self {
+            Argument::None => String::new(),
+            Argument::Tag => tr("argument.tag"),
+            Argument::Swarm => tr("argument.swarm"),
+            Argument::Neighbor => tr("argument.neighbor"),
+            Argument::Content => tr("argument.content"),
+            Argument::Text => tr("argument.text"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Quit,
+    Help,
+    OpenForum,
+    OpenCatalog,
+    HomeVillage,
+    ContextMenu,
+    Notifications,
+    ActivityFeed,
+    LogViewer,
+    DebugPanel,
+    DismissNotification,
+    ShowTags,
+    ShowDataTypes,
+    AddTag,
+    AddDataType,
+    CreateContent,
+    Invitations,
+    ActiveSwarms,
+    Search,
+    ListSearches,
+    Chat,
+    PasteLink,
+    OpenContent,
+    CopyLink,
+    AppendNote,
+    RemovePage,
+    Street,
+    SwitchSwarm,
+    LeaveSwarm,
+    Reconnect,
+    Pin,
+    Unpin,
+    Watch,
+    Unwatch,
+    Ping,
+    VillageSummary,
+    FriendRequest,
+    AskForContent,
+    BootstrapNeighbors,
+    VillageSettings,
+    Topics,
+    NewEntry,
+    Filter,
+    Categories,
+    Settings,
+    RunningPolicies,
+    StoredPolicies,
+    RunningCapabilities,
+    StoredCapabilities,
+    RunningByteSets,
+    StoredByteSets,
+    FirstPage,
+    PrevPage,
+    NextPage,
+    LastPage,
+    CopyEntry,
+}
+
+const COMMANDS: [(KeyContext, Command); 58] = [
+    (KeyContext::Catalog, Command::Quit),
+    (KeyContext::Catalog, Command::OpenForum),
+    (KeyContext::Catalog, Command::HomeVillage),
+    (KeyContext::Catalog, Command::ContextMenu),
+    (KeyContext::Catalog, Command::ShowTags),
+    (KeyContext::Catalog, Command::ShowDataTypes),
+    (KeyContext::Catalog, Command::Street),
+    (KeyContext::Catalog, Command::AddTag),
+    (KeyContext::Catalog, Command::AddDataType),
+    (KeyContext::Catalog, Command::CreateContent),
+    (KeyContext::Catalog, Command::OpenContent),
+    (KeyContext::Catalog, Command::AppendNote),
+    (KeyContext::Catalog, Command::RemovePage),
+    (KeyContext::Catalog, Command::CopyLink),
+    (KeyContext::Catalog, Command::PasteLink),
+    (KeyContext::Catalog, Command::Search),
+    (KeyContext::Catalog, Command::ListSearches),
+    (KeyContext::Catalog, Command::Chat),
+    (KeyContext::Catalog, Command::ActiveSwarms),
+    (KeyContext::Catalog, Command::SwitchSwarm),
+    (KeyContext::Catalog, Command::LeaveSwarm),
+    (KeyContext::Catalog, Command::Reconnect),
+    (KeyContext::Catalog, Command::Pin),
+    (KeyContext::Catalog, Command::Unpin),
+    (KeyContext::Catalog, Command::Watch),
+    (KeyContext::Catalog, Command::Unwatch),
+    (KeyContext::Catalog, Command::Ping),
+    (KeyContext::Catalog, Command::VillageSummary),
+    (KeyContext::Catalog, Command::FriendRequest),
+    (KeyContext::Catalog, Command::AskForContent),
+    (KeyContext::Catalog, Command::BootstrapNeighbors),
+    (KeyContext::Catalog, Command::VillageSettings),
+    (KeyContext::Catalog, Command::Invitations),
+    (KeyContext::Catalog, Command::Notifications),
+    (KeyContext::Catalog, Command::DismissNotification),
+    (KeyContext::Catalog, Command::ActivityFeed),
+    (KeyContext::Catalog, Command::LogViewer),
+    (KeyContext::Catalog, Command::DebugPanel),
+    (KeyContext::Catalog, Command::Help),
+    (KeyContext::Forum, Command::Quit),
+    (KeyContext::Forum, Command::OpenCatalog),
+    (KeyContext::Forum, Command::Topics),
+    (KeyContext::Forum, Command::NewEntry),
+    (KeyContext::Forum, Command::Filter),
+    (KeyContext::Forum, Command::Categories),
+    (KeyContext::Forum, Command::CopyEntry),
+    (KeyContext::Forum, Command::FirstPage),
+    (KeyContext::Forum, Command::PrevPage),
+    (KeyContext::Forum, Command::NextPage),
+    (KeyContext::Forum, Command::LastPage),
+    (KeyContext::Forum, Command::Settings),
+    (KeyContext::Forum, Command::RunningPolicies),
+    (KeyContext::Forum, Command::StoredPolicies),
+    (KeyContext::Forum, Command::RunningCapabilities),
+    (KeyContext::Forum, Command::StoredCapabilities),
+    (KeyContext::Forum, Command::RunningByteSets),
+    (KeyContext::Forum, Command::StoredByteSets),
+    (KeyContext::Forum, Command::Help),
+];
+
+impl Command {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Quit => "quit",
+            Command::Help => "help",
+            Command::OpenForum => "forum",
+            Command::OpenCatalog => "catalog",
+            Command::HomeVillage => "home",
+            Command::ContextMenu => "menu",
+            Command::Notifications => "notifications",
+            Command::ActivityFeed => "activity",
+            Command::LogViewer => "log",
+            Command::DebugPanel => "debug",
+            Command::DismissNotification => "dismiss",
+            Command::ShowTags => "tags",
+            Command::ShowDataTypes => "dtypes",
+            Command::AddTag => "add-tag",
+            Command::AddDataType => "add-dtype",
+            Command::CreateContent => "create",
+            Command::Invitations => "invitations",
+            Command::ActiveSwarms => "swarms",
+            Command::Search => "search",
+            Command::ListSearches => "searches",
+            Command::Chat => "chat",
+            Command::PasteLink => "paste",
+            Command::OpenContent => "open",
+            Command::CopyLink => "copy-link",
+            Command::AppendNote => "append",
+            Command::RemovePage => "remove-page",
+            Command::Street => "street",
+            Command::SwitchSwarm => "swarm",
+            Command::LeaveSwarm => "leave",
+            Command::Reconnect => "reconnect",
+            Command::Pin => "pin",
+            Command::Unpin => "unpin",
+            Command::Watch => "watch",
+            Command::Unwatch => "unwatch",
+            Command::Ping => "ping",
+            Command::VillageSummary => "summary",
+            Command::FriendRequest => "friend",
+            Command::AskForContent => "ask-cid",
+            Command::BootstrapNeighbors => "neighbors",
+            Command::VillageSettings => "config",
+            Command::Topics => "topics",
+            Command::NewEntry => "new",
+            Command::Filter => "filter",
+            Command::Categories => "categories",
+            Command::Settings => "settings",
+            Command::RunningPolicies => "policies",
+            Command::StoredPolicies => "stored-policies",
+            Command::RunningCapabilities => "capabilities",
+            Command::StoredCapabilities => "stored-capabilities",
+            Command::RunningByteSets => "bytesets",
+            Command::StoredByteSets => "stored-bytesets",
+            Command::FirstPage => "first",
+            Command::PrevPage => "prev",
+            Command::NextPage => "next",
+            Command::LastPage => "last",
+            Command::CopyEntry => "copy-entry",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        tr(&format!("command.{}", self.name()))
+    }
+
+    pub fn argument(&self) -> Argument {
+        match self {
+            Command::AddTag | Command::Street => Argument::Tag,
+            Command::AddDataType | Command::Search => Argument::Text,
+            Command::OpenContent
+            | Command::CopyLink
+            | Command::AppendNote
+            | Command::RemovePage => Argument::Content,
+            Command::SwitchSwarm
+            | Command::LeaveSwarm
+            | Command::Reconnect
+            | Command::Pin
+            | Command::Unpin
+            | Command::Watch
+            | Command::Unwatch => Argument::Swarm,
+            Command::Ping
+            | Command::VillageSummary
+            | Command::FriendRequest
+            | Command::AskForContent => Argument::Neighbor,
+            _other => Argument::None,
+        }
+    }
+
+    // Arguments that can be left out, in which case user is asked for them
+    pub fn argument_optional(&self) -> bool {
+        matches!(
+            self,
+            Command::AddTag | Command::AddDataType | Command::Search
+        )
+    }
+
+    // Name followed by argument's hint, as shown in palette
+    pub fn usage(&self) -> String {
+        match self.argument() {
+            Argument::None => self.name().to_string(),
+            arg if self.argument_optional() => format!("{} [{}]", self.name(), arg.hint()),
+            arg => format!("{} <{}>", self.name(), arg.hint()),
+        }
+    }
+
+    // Key action that does the same thing
+    pub fn key_action(&self) -> Option<KeyAction> {
+        match self {
+            Command::Quit => Some(KeyAction::Quit),
+            Command::Help => Some(KeyAction::Help),
+            Command::OpenForum => Some(KeyAction::OpenForum),
+            Command::OpenCatalog => Some(KeyAction::OpenCatalog),
+            Command::HomeVillage => Some(KeyAction::HomeVillage),
+            Command::ContextMenu => Some(KeyAction::ContextMenu),
+            Command::Notifications => Some(KeyAction::Notifications),
+            Command::ActivityFeed => Some(KeyAction::ActivityFeed),
+            Command::LogViewer => Some(KeyAction::LogViewer),
+            Command::DebugPanel => Some(KeyAction::DebugPanel),
+            Command::DismissNotification => Some(KeyAction::DismissNotification),
+            Command::FirstPage => Some(KeyAction::FirstPage),
+            Command::PrevPage => Some(KeyAction::PrevPage),
+            Command::NextPage => Some(KeyAction::NextPage),
+            Command::LastPage => Some(KeyAction::LastPage),
+            Command::CopyEntry => Some(KeyAction::CopyEntry),
+            _other => None,
+        }
+    }
+}
+
+// Developer commands are only offered in developer mode,
+// which is set once at startup
+static DEV_MODE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_dev_mode(dev_mode: bool) {
+    DEV_MODE.store(dev_mode, Ordering::Relaxed);
+}
+
+pub fn commands_for(context: KeyContext) -> Vec<Command> {
+    let dev_mode = DEV_MODE.load(Ordering::Relaxed);
+    COMMANDS
+        .iter()
+        .filter(|(c, _cmd)| *c == context)
+        .filter(|(_c, cmd)| dev_mode || *cmd != Command::DebugPanel)
+        .map(|(_c, cmd)| *cmd)
+        .collect()
+}
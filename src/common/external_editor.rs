@@ -0,0 +1,128 @@
+use std::env;
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Text is written to a temporary file which is opened with a program
+// named by $VISUAL or $EDITOR (vi if neither is set), once that program
+// exits file contents are read back.
+// That file lives in a freshly created directory only we can access,
+// both are removed afterwards.
+// Terminal is switched out of raw mode and alternate screen for the time
+// external program runs, and switched back afterwards.
+// Caller has to suspend animaterm's Manager around this call,
+// so that nothing else reads from or draws to the terminal meanwhile.
+pub fn edit(text: &str) -> io::Result<String> {
+    let command = env::var("VISUAL")
+        .or_else(|_e| env::var("EDITOR"))
+        .unwrap_or_else(|_e| "vi".to_string());
+    let mut words = command.split_whitespace();
+    let Some(program) = words.next() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no editor program given",
+        ));
+    };
+    let args: Vec<&str> = words.collect();
+    let dir = private_dir()?;
+    let path = dir.join("message.txt");
+    if let Err(e) = write_new(&path, text) {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+
+    let saved_mode = stty_mode();
+    leave_tui(saved_mode.is_some());
+    let status = Command::new(program).args(&args).arg(&path).status();
+    enter_tui(saved_mode);
+
+    let result = match status {
+        Ok(status) if status.success() => fs::read_to_string(&path),
+        Ok(status) => Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("{} exited with {}", program, status),
+        )),
+        Err(e) => Err(e),
+    };
+    let _ = fs::remove_dir_all(&dir);
+    // Most editors end a file with a newline
+    result.map(|mut text| {
+        if text.ends_with('\n') {
+            text.pop();
+        }
+        text
+    })
+}
+
+// Creating a directory fails if it already exists,
+// so nobody can have it prepared for us in advance
+fn private_dir() -> io::Result<PathBuf> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let dir = env::temp_dir().join(format!("village-tui-{}-{}", std::process::id(), nanos));
+    let mut builder = DirBuilder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(&dir)?;
+    Ok(dir)
+}
+
+fn write_new(path: &Path, text: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(text.as_bytes())?;
+    file.flush()
+}
+
+// Current terminal settings in a form accepted back by stty
+fn stty_mode() -> Option<String> {
+    let output = Command::new("stty")
+        .arg("-g")
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn leave_tui(reset_mode: bool) {
+    if reset_mode {
+        let _ = Command::new("stty")
+            .arg("sane")
+            .stdin(Stdio::inherit())
+            .status();
+    }
+    // Leave alternate screen and show cursor
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x1b[?1049l\x1b[?25h");
+    let _ = stdout.flush();
+}
+
+fn enter_tui(saved_mode: Option<String>) {
+    if let Some(mode) = saved_mode {
+        let _ = Command::new("stty")
+            .arg(mode)
+            .stdin(Stdio::inherit())
+            .status();
+    }
+    // Back to alternate screen with hidden cursor
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x1b[?1049h\x1b[?25l");
+    let _ = stdout.flush();
+}
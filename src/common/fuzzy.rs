@@ -0,0 +1,106 @@
+// Fuzzy matching of a filter typed by user against option texts.
+// Every word of a filter has to appear in text with it's chars in order,
+// but not necessarily next to each other, case is ignored.
+// Score rewards chars that follow each other, chars starting a word
+// and matches close to beginning of text, so that best ones come first.
+const MATCH: i32 = 16;
+const CONSECUTIVE: i32 = 12;
+const WORD_START: i32 = 8;
+const MAX_GAP_PENALTY: i32 = 8;
+const MAX_LEADING_PENALTY: i32 = 10;
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn is_word_start(text: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    let prev = text[idx - 1];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && text[idx].is_uppercase())
+}
+
+// Best placement of a single word within text, as score and
+// indices of matched chars
+fn match_word(word: &[char], text: &[char]) -> Option<(i32, Vec<usize>)> {
+    if word.is_empty() {
+        return Some((0, vec![]));
+    }
+    let m = text.len();
+    // best[i][j] - best score with word[i] matched at text[j],
+    // from[i][j] - where word[i-1] was matched for that score
+    let mut best: Vec<Vec<Option<i32>>> = vec![vec![None; m]; word.len()];
+    let mut from = vec![vec![0; m]; word.len()];
+    for (i, w) in word.iter().enumerate() {
+        for j in i..m {
+            if fold(text[j]) != *w {
+                continue;
+            }
+            let mut bonus = MATCH;
+            if is_word_start(text, j) {
+                bonus += WORD_START;
+            }
+            if i == 0 {
+                best[i][j] = Some(bonus - (j as i32).min(MAX_LEADING_PENALTY));
+                continue;
+            }
+            for k in i - 1..j {
+                let Some(prev) = best[i - 1][k] else {
+                    continue;
+                };
+                let link = if k + 1 == j {
+                    CONSECUTIVE
+                } else {
+                    -((j - k - 1) as i32).min(MAX_GAP_PENALTY)
+                };
+                let score = prev + bonus + link;
+                if best[i][j].map_or(true, |b| score > b) {
+                    best[i][j] = Some(score);
+                    from[i][j] = k;
+                }
+            }
+        }
+    }
+    let last = word.len() - 1;
+    let (mut j, score) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, s)| s.map(|s| (j, s)))
+        .max_by_key(|(j, s)| (*s, std::cmp::Reverse(*j)))?;
+    let mut positions = vec![0; word.len()];
+    for i in (0..word.len()).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some((score, positions))
+}
+
+// Score and sorted indices of matched chars, or None when
+// some word of filter does not match given text
+pub fn fuzzy_match(filter: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let text: Vec<char> = text.chars().collect();
+    let mut total = 0;
+    let mut positions = vec![];
+    for word in filter.split_whitespace() {
+        let word: Vec<char> = word.chars().map(fold).collect();
+        let (score, mut matched) = match_word(&word, &text)?;
+        total += score;
+        positions.append(&mut matched);
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    Some((total, positions))
+}
+
+// Indices of texts matching filter together with positions of matched chars,
+// best matches first. Empty filter keeps every text in it's original order.
+pub fn rank(filter: &str, texts: &[String]) -> Vec<(usize, Vec<usize>)> {
+    let mut ranked: Vec<(i32, usize, Vec<usize>)> = texts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, text)| fuzzy_match(filter, text).map(|(s, p)| (s, i, p)))
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    ranked.into_iter().map(|(_s, i, p)| (i, p)).collect()
+}
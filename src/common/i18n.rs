@@ -0,0 +1,1003 @@
+use crate::log_warn;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Every user-visible text is looked up by it's id in MESSAGES table.
+// Language is chosen once at startup, from LANGUAGE line in village-tui.conf,
+// or from LANG environment variable, English is used by default.
+// Message texts may contain {} placeholders that are filled by trf
+// with given arguments, in order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    Polish,
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+impl Language {
+    // Accepts both short codes like "pl" and locale names like "pl_PL.UTF-8"
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_lowercase();
+        if code.starts_with("pl") || code.starts_with("polski") {
+            Some(Language::Polish)
+        } else if code.starts_with("en") || code == "c" || code.starts_with("c.") {
+            Some(Language::English)
+        } else {
+            None
+        }
+    }
+
+    pub fn from_env() -> Self {
+        for var in ["LC_ALL", "LC_MESSAGES", "LANG"] {
+            if let Ok(value) = std::env::var(var) {
+                if let Some(lang) = Language::from_code(&value) {
+                    return lang;
+                }
+            }
+        }
+        Language::English
+    }
+}
+
+pub fn set_language(lang: Language) {
+    let value = match lang {
+        Language::English => 0,
+        Language::Polish => 1,
+    };
+    LANGUAGE.store(value, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::Polish,
+        _other => Language::English,
+    }
+}
+
+pub fn tr(id: &str) -> String {
+    if let Some((_id, en, pl)) = MESSAGES.iter().find(|(m_id, _en, _pl)| *m_id == id) {
+        match language() {
+            Language::English => en.to_string(),
+            Language::Polish => pl.to_string(),
+        }
+    } else {
+        log_warn!("Missing message: {}", id);
+        id.to_string()
+    }
+}
+
+pub fn trf(id: &str, args: &[&dyn Display]) -> String {
+    let text = tr(id);
+    let mut result = String::with_capacity(text.len() + 16 * args.len());
+    let mut args = args.iter();
+    let mut pieces = text.split("{}");
+    if let Some(first) = pieces.next() {
+        result.push_str(first);
+    }
+    for piece in pieces {
+        if let Some(arg) = args.next() {
+            result.push_str(&arg.to_string());
+        }
+        result.push_str(piece);
+    }
+    result
+}
+
+// id, English, Polish
+const MESSAGES: &[(&str, &str, &str)] = &[
+    ("common.back", "Back", "Wstecz"),
+    ("common.cancel", "Cancel", "Anuluj"),
+    ("common.unknown", "Unknown", "Nieznany"),
+    ("common.keymap_problem", "Keymap: {}", "Mapa klawiszy: {}"),
+    ("cmenu.tags", " TAGs", " TAGi"),
+    ("cmenu.data_types", " Data types", " Typy danych"),
+    ("cmenu.add_tag", " Add TAG", " Dodaj TAG"),
+    ("cmenu.add_data_type", " Add data type", " Dodaj typ danych"),
+    ("cmenu.create_new", " Create new…", " Utwórz nowy…"),
+    ("cmenu.invitations", " Invitations", " Zaproszenia"),
+    ("cmenu.active_swarms", " Active Swarms", " Aktywne roje"),
+    ("cmenu.add_search", " Add Search", " Dodaj wyszukiwanie"),
+    ("cmenu.new_note", " New Note", " Nowa Notatka"),
+    ("cmenu.remove_note", " Remove Note", " Usuń Notatkę"),
+    ("cmenu.copy_link", " Copy Link", " Kopiuj Odnośnik"),
+    ("cmenu.edit_link", " Edit Link", " Edytuj Link"),
+    ("cmenu.paste_link", " Paste Link", " Wklej Odnośnik"),
+    ("cmenu.list_searches", " List Searches", " Lista wyszukiwań"),
+    ("cmenu.chat", " Chat", " Czat"),
+    ("cmenu.ping", " Ping", " Ping"),
+    ("cmenu.summary", " Summary", " Podsumowanie"),
+    ("cmenu.invite", " Invite", " Zaproś"),
+    ("cmenu.ask_for_cid", " Ask for CID", " Zapytaj o CID"),
+    ("cmenu.not_a_link", "not a Link", "to nie Link"),
+    ("cmenu.clipboard_empty", "copy a Link first", "najpierw skopiuj Link"),
+    ("cmenu.founder_only", "founder only", "tylko założyciel"),
+    ("cmenu.policy_denies", "denied by policy", "zabronione polityką"),
+    ("dtype.text", "Text", "Tekst"),
+    ("dtype.text_file", "Text file", "Plik tekstowy"),
+    ("dtype.binary_file", "Binary file", "Plik binarny"),
+    ("indexer.title", "This is an Indexer", "Spis treści"),
+    ("list.empty", "no rows", "brak wierszy"),
+    ("list.rows", "rows {}-{} of {}", "wiersze {}-{} z {}"),
+    ("list.page", "page {}/{}", "strona {}/{}"),
+    ("list.sorted", "sorted by {} {}", "sortowanie: {} {}"),
+    ("list.jump", "jump to: {}", "skocz do: {}"),
+    ("dialog.yes", "Yes", "Tak"),
+    ("dialog.no", "No", "Nie"),
+    ("dialog.cancel", "Cancel", "Anuluj"),
+    ("dialog.ok", "OK", "OK"),
+    ("dialog.quit_title", "Quit", "Wyjście"),
+    ("dialog.quit", "Do you want to quit?", "Czy chcesz zakończyć?"),
+    ("dialog.error_title", "Error", "Błąd"),
+    ("dialog.remove_page_title", "Remove Page", "Usuń stronę"),
+    (
+        "dialog.remove_page",
+        "Page #{} will be removed from CID-{}. This can not be undone. Remove it?\nNo lets you pick another Page.",
+        "Strona #{} zostanie usunięta z CID-{}. Tego nie można cofnąć. Usunąć ją?\nNie pozwala wybrać inną stronę.",
+    ),
+    ("dialog.leave_swarm_title", "Leave swarm", "Opuść rój"),
+    (
+        "dialog.leave_swarm",
+        "Leave {}? It will no longer be joined automatically.",
+        "Opuścić {}? Nie będzie już automatycznie dołączany.",
+    ),
+    ("dialog.change_street_title", "Change Street name", "Zmień nazwę ulicy"),
+    (
+        "dialog.change_street",
+        "New name for Street '{}' (up to 32 bytes):",
+        "Nowa nazwa ulicy '{}' (do 32 bajtów):",
+    ),
+    (
+        "dialog.change_street_confirm",
+        "Rename Street '{}' to '{}' for every Content in this swarm?\nNo lets you correct the name.",
+        "Zmienić nazwę ulicy '{}' na '{}' dla całej treści w tym roju?\nNie pozwala poprawić nazwę.",
+    ),
+    ("palette.title", "Command palette", "Paleta poleceń"),
+    ("palette.no_match", "No command matches", "Żadne polecenie nie pasuje"),
+    ("palette.missing", "Type {} after command name", "Wpisz {} po nazwie polecenia"),
+    ("argument.tag", "street", "ulica"),
+    ("argument.swarm", "swarm", "rój"),
+    ("argument.neighbor", "neighbor id", "id sąsiada"),
+    ("argument.content", "content id", "id treści"),
+    ("argument.text", "text", "tekst"),
+    ("command.unavailable", "Command unavailable: {}", "Polecenie niedostępne: {}"),
+    ("command.bad_name", "'{}' is not a valid name", "'{}' nie jest poprawną nazwą"),
+    ("command.unknown_content", "No Content with id '{}'", "Brak treści o id '{}'"),
+    ("command.unknown_street", "No Street named '{}'", "Brak ulicy o nazwie '{}'"),
+    ("command.unknown_swarm", "No swarm named '{}'", "Brak roju o nazwie '{}'"),
+    (
+        "command.unknown_neighbor",
+        "'{}' is not a neighbor id",
+        "'{}' nie jest id sąsiada",
+    ),
+    ("command.quit", "Quit application", "Zakończ aplikację"),
+    ("command.forum", "Open Forum", "Otwórz Forum"),
+    ("command.catalog", "Back to Catalog", "Wróć do Katalogu"),
+    ("command.home", "Go to home village", "Idź do domowej wioski"),
+    ("command.menu", "Context menu of selected tile", "Menu kontekstowe wybranego pola"),
+    ("command.notifications", "Notification history", "Historia powiadomień"),
+    ("command.activity", "Activity feed", "Aktywność"),
+    ("command.log", "Log viewer", "Przeglądarka logów"),
+    ("command.debug", "Debug panel", "Panel debugowania"),
+    (
+        "command.copy-entry",
+        "Copy link to selected entry",
+        "Kopiuj link do wybranego wpisu",
+    ),
+    ("command.dismiss", "Dismiss notification", "Ukryj powiadomienie"),
+    ("command.tags", "Show TAGs", "Pokaż TAGi"),
+    ("command.dtypes", "Show data types", "Pokaż typy danych"),
+    ("command.add-tag", "Add a TAG", "Dodaj TAG"),
+    ("command.add-dtype", "Add a data type", "Dodaj typ danych"),
+    ("command.create", "Create new Content", "Utwórz nową treść"),
+    ("command.invitations", "Invitations", "Zaproszenia"),
+    ("command.swarms", "Active swarms", "Aktywne roje"),
+    ("command.search", "Search swarms", "Szukaj rojów"),
+    ("command.searches", "List searches", "Lista wyszukiwań"),
+    ("command.chat", "Chat room of this swarm", "Czat tego roju"),
+    ("command.paste", "Paste copied link", "Wklej skopiowany link"),
+    ("command.open", "Open Content", "Otwórz treść"),
+    ("command.copy-link", "Copy a link", "Kopiuj link"),
+    ("command.append", "Append a note to Content", "Dopisz notatkę do treści"),
+    ("command.remove-page", "Remove a page of Content", "Usuń stronę treści"),
+    ("command.street", "Show Street", "Pokaż ulicę"),
+    ("command.swarm", "Switch to swarm", "Przełącz na rój"),
+    ("command.leave", "Leave swarm", "Opuść rój"),
+    ("command.reconnect", "Reconnect to swarm", "Połącz ponownie z rojem"),
+    ("command.pin", "Join swarm on startup", "Dołączaj do roju przy starcie"),
+    ("command.unpin", "Stop joining swarm on startup", "Nie dołączaj do roju przy starcie"),
+    ("command.watch", "Watch swarm for changes", "Obserwuj zmiany w roju"),
+    ("command.unwatch", "Stop watching swarm", "Przestań obserwować rój"),
+    ("command.ping", "Ping a neighbor", "Pinguj sąsiada"),
+    ("command.summary", "Ask neighbor for village summary", "Zapytaj sąsiada o opis wioski"),
+    ("command.friend", "Send friend request", "Wyślij zaproszenie do znajomych"),
+    ("command.ask-cid", "Ask neighbor for a content", "Zapytaj sąsiada o treść"),
+    ("command.neighbors", "Bootstrap neighbors", "Sąsiedzi startowi"),
+    ("command.config", "Village settings", "Ustawienia wioski"),
+    ("command.topics", "Show Topics", "Pokaż tematy"),
+    ("command.new", "Add new Topic or Post", "Dodaj temat lub post"),
+    ("command.filter", "Filter by text", "Filtruj tekstem"),
+    ("command.categories", "Filter by categories", "Filtruj kategoriami"),
+    ("command.settings", "Forum settings", "Ustawienia Forum"),
+    ("command.policies", "Running policies", "Aktywne polityki"),
+    ("command.stored-policies", "Stored policies", "Zapisane polityki"),
+    ("command.capabilities", "Running capabilities", "Aktywne zdolności"),
+    ("command.stored-capabilities", "Stored capabilities", "Zapisane zdolności"),
+    ("command.bytesets", "Running ByteSets", "Aktywne ByteSety"),
+    ("command.stored-bytesets", "Stored ByteSets", "Zapisane ByteSety"),
+    ("command.first", "First page", "Pierwsza strona"),
+    ("command.prev", "Previous page", "Poprzednia strona"),
+    ("command.next", "Next page", "Następna strona"),
+    ("command.last", "Last page", "Ostatnia strona"),
+    ("help.title", "Keys: {}", "Klawisze: {}"),
+    ("help.footer", "Any key closes help", "Dowolny klawisz zamyka pomoc"),
+    ("help.footer_scroll", "Line {} of {}, arrows and PgUp/PgDn scroll, other keys close help", "Wiersz {} z {}, strzałki i PgUp/PgDn przewijają, inne klawisze zamykają pomoc"),
+    ("help.commands", "Commands (type after ':')", "Polecenia (wpisz po ':')"),
+    ("help.catalog", "Village", "Wioska"),
+    ("help.forum", "Forum", "Forum"),
+    ("help.editor", "Editor", "Edytor"),
+    ("help.selector", "Selector", "Wybór"),
+    ("help.indexer", "List", "Lista"),
+    ("help.list", "Table", "Tabela"),
+    ("help.dialog", "Dialog", "Okno dialogowe"),
+    ("help.palette", "Command palette", "Paleta poleceń"),
+    ("help.help", "Help", "Pomoc"),
+    ("help.menu", "Context menu", "Menu kontekstowe"),
+    ("help.creator", "Content creator", "Kreator treści"),
+    ("help.policy_editor", "Policy editor", "Edytor polityk"),
+    ("command.help", "Show keys and commands", "Pokaż klawisze i polecenia"),
+    ("action.quit", "Quit", "Zakończ"),
+    ("action.context_menu", "Context menu of selected tile", "Menu kontekstowe wybranego pola"),
+    ("action.move_left", "Move left", "W lewo"),
+    ("action.move_right", "Move right", "W prawo"),
+    ("action.move_up", "Move up", "W górę"),
+    ("action.move_down", "Move down", "W dół"),
+    ("action.word_left", "Previous word", "Poprzednie słowo"),
+    ("action.word_right", "Next word", "Następne słowo"),
+    ("action.line_start", "Start of line", "Początek wiersza"),
+    ("action.line_end", "End of line", "Koniec wiersza"),
+    ("action.home_village", "Go to home village", "Idź do domowej wioski"),
+    ("action.first_item", "First item", "Pierwszy element"),
+    ("action.last_item", "Last item", "Ostatni element"),
+    ("action.select", "Choose or open selected item", "Wybierz lub otwórz zaznaczony element"),
+    ("action.finish", "Finish and close", "Zakończ i zamknij"),
+    ("action.open_forum", "Open Forum", "Otwórz Forum"),
+    ("action.open_catalog", "Back to Catalog", "Wróć do Katalogu"),
+    ("action.first_page", "First page", "Pierwsza strona"),
+    ("action.prev_page", "Previous page", "Poprzednia strona"),
+    ("action.next_page", "Next page", "Następna strona"),
+    ("action.last_page", "Last page", "Ostatnia strona"),
+    ("action.copy_entry", "Copy link to selected entry", "Kopiuj link do zaznaczonego wpisu"),
+    ("action.delete", "Delete char under cursor", "Usuń znak pod kursorem"),
+    ("action.kill_to_end", "Cut to end of line", "Wytnij do końca wiersza"),
+    ("action.enable_editing", "Enable editing", "Włącz edycję"),
+    ("action.set_mark", "Set mark to select text", "Ustaw znacznik zaznaczenia"),
+    ("action.copy", "Copy selection", "Kopiuj zaznaczenie"),
+    ("action.cut", "Cut selection", "Wytnij zaznaczenie"),
+    ("action.paste", "Paste", "Wklej"),
+    ("action.yank_pop", "Replace pasted text with older one", "Zamień wklejony tekst na starszy"),
+    ("action.undo", "Undo", "Cofnij"),
+    ("action.redo", "Redo", "Ponów"),
+    ("action.scroll_up", "Scroll up by a screen", "Przewiń ekran w górę"),
+    ("action.scroll_down", "Scroll down by a screen", "Przewiń ekran w dół"),
+    ("action.external_editor", "Continue in external editor", "Kontynuuj w zewnętrznym edytorze"),
+    ("action.clear_filter", "Clear filter", "Wyczyść filtr"),
+    ("action.sort_column", "Sort by next column", "Sortuj według następnej kolumny"),
+    ("action.reverse_sort", "Reverse sort order", "Odwróć kolejność"),
+    ("action.next_field", "Next field or button", "Następne pole lub przycisk"),
+    ("action.prev_field", "Previous field or button", "Poprzednie pole lub przycisk"),
+    ("action.debug_panel", "Debug panel", "Panel debugowania"),
+    ("action.log_viewer", "Log viewer", "Przeglądarka logów"),
+    ("action.notifications", "Notification history", "Historia powiadomień"),
+    ("action.activity_feed", "Activity feed", "Aktywność"),
+    ("action.dismiss_notification", "Dismiss notification", "Ukryj powiadomienie"),
+    ("action.command_palette", "Command palette", "Paleta poleceń"),
+    ("action.help", "This help", "Ta pomoc"),
+    ("action.broadcast_test_data", "Broadcast test bytes", "Rozgłoś testowe bajty"),
+    ("action.change_diameter", "Change swarm diameter to 10", "Zmień średnicę roju na 10"),
+    ("action.start_broadcast", "Start broadcast", "Rozpocznij rozgłaszanie"),
+    ("action.end_broadcast", "End broadcast", "Zakończ rozgłaszanie"),
+    ("action.unsubscribe_broadcast", "Unsubscribe broadcast", "Wypisz się z rozgłaszania"),
+    ("action.start_multicast", "Start multicast", "Rozpocznij multicast"),
+    ("action.multicast_test_data", "Multicast test bytes", "Wyślij testowe bajty multicastem"),
+    ("action.end_multicast", "End multicast", "Zakończ multicast"),
+    ("action.unsubscribe_multicast", "Unsubscribe multicast", "Wypisz się z multicastu"),
+    ("action.subscribe_multicast", "Subscribe multicast", "Zapisz się na multicast"),
+    ("action.send_to_mcast_source", "Send test bytes to multicast source", "Wyślij testowe bajty do źródła multicastu"),
+    ("action.send_to_bcast_source", "Send test bytes to broadcast source", "Wyślij testowe bajty do źródła rozgłaszania"),
+    ("action.start_forum", "Start a Forum swarm named F", "Uruchom rój Forum o nazwie F"),
+    ("action.transform_link", "Send fake TransformLinkRequest", "Wyślij fałszywe TransformLinkRequest"),
+    ("action.read_manifest", "Read Manifest pages", "Odczytaj strony Manifestu"),
+    ("action.test_notification", "Show test notification", "Pokaż testowe powiadomienie"),
+    ("action.append_empty_content", "Append empty content", "Dodaj pustą treść"),
+    ("action.start_unicast", "Start unicast", "Rozpocznij unicast"),
+    ("column.phrase", "Phrase", "Fraza"),
+    ("column.results", "Results", "Wyniki"),
+    ("column.swarm", "Swarm", "Rój"),
+    ("column.content", "Content", "Treść"),
+    ("column.score", "Score", "Wynik"),
+    ("column.sid", "SID", "SID"),
+    ("column.app", "App", "Aplikacja"),
+    ("column.role", "Role", "Rola"),
+    ("column.neighbors", "Neigh", "Sąs."),
+    ("column.status", "Status", "Stan"),
+    ("column.storage", "Storage", "Przechowywanie"),
+    ("column.condition", "Condition", "Warunek"),
+    ("column.policy", "Policy", "Zasada"),
+    ("column.request", "Request", "Zgłoszenie"),
+    ("column.location", "Where", "Gdzie"),
+    ("column.entry", "Entry", "Wpis"),
+    ("column.author", "Author", "Autor"),
+    (
+        "content.read_error",
+        "Error reading CID {}:\n {}",
+        "Błąd odczytu CID {}:\n {}",
+    ),
+    ("tile.neighbor", "Neighbor {}", "Sąsiad {}"),
+    ("street.neighbors", "Neighbors", "Sąsiedzi"),
+    ("editor.text_input", "Text input", "Wprowadzanie tekstu"),
+    ("editor.position", "Line {}, col {}", "Wiersz {}, kol. {}"),
+    ("editor.bytes", "{} B", "{} B"),
+    ("editor.bytes_limit", "{}/{} B", "{}/{} B"),
+    ("editor.read_only", "read-only", "tylko odczyt"),
+    (
+        "editor.cut_to_limit",
+        "text cut to {} B",
+        "tekst skrócony do {} B",
+    ),
+    (
+        "editor.external_failed",
+        "external editor failed: {}",
+        "błąd zewnętrznego edytora: {}",
+    ),
+    ("indexer.placeholder", "Header {} of {}", "Nagłówek {} z {}"),
+    ("selector.filter", "Filter: ", "Filtr: "),
+    (
+        "selector.page",
+        " page {}/{} ({} of {} match) ",
+        " strona {}/{} (pasuje {} z {}) ",
+    ),
+    ("creator.edit", "Edit", "Edytuj"),
+    ("creator.show", "Show", "Pokaż"),
+    ("creator.save", "Save", "Zapisz"),
+    ("creator.cancel", "Cancel", "Anuluj"),
+    ("creator.close", "Close", "Zamknij"),
+    ("creator.data_type", "DataType: {}", "Typ danych: {}"),
+    ("creator.tags", "Tags: {}", "Tagi: {}"),
+    ("creator.description", "Description: {}", "Opis: {}"),
+    (
+        "viewer.define_dtype",
+        "Define new DataType                       bytes left: {}",
+        "Zdefiniuj nowy typ danych                 pozostało bajtów: {}",
+    ),
+    (
+        "viewer.unknown_dtype",
+        "EDIT    DataType: Unknown",
+        "EDYCJA  Typ danych: nieznany",
+    ),
+    (
+        "viewer.plaintext",
+        "Description: Plaintext file",
+        "Opis: plik tekstowy",
+    ),
+    (
+        "viewer.buttons",
+        "                    APPLY    CANCEL",
+        "                    ZASTOSUJ    ANULUJ",
+    ),
+    ("search.is_permanent", "Is permanent: {}", "Stałe: {}"),
+    ("search.active", "Active searches", "Aktywne wyszukiwania"),
+    ("search.results_for", "Results for {}", "Wyniki dla {}"),
+    ("swarm.active", "Swarms", "Roje"),
+    (
+        "content.read_in_progress",
+        "{} {} Read in progress…",
+        "{} {} Trwa odczyt…",
+    ),
+    (
+        "editor.new_tag",
+        " Max size: 32  Oneline  Define new Tag name    (TAB to finish)",
+        " Maks. rozmiar: 32  Jedna linia  Nazwa nowego Tagu    (TAB kończy)",
+    ),
+    (
+        "editor.new_dtype",
+        " Max size: 32  Oneline  Define new Data Type    (TAB to finish)",
+        " Maks. rozmiar: 32  Jedna linia  Nowy typ danych    (TAB kończy)",
+    ),
+    (
+        "editor.content_description",
+        " Max size: 128  Multiline  Content Description    (TAB to finish)",
+        " Maks. rozmiar: 128  Wiele linii  Opis zawartości    (TAB kończy)",
+    ),
+    (
+        "editor.link_target",
+        " Line#1: GnomeId  Line#2: SwarmName(opt, max: 32 bytes) (TAB to finish)",
+        " Linia#1: GnomeId  Linia#2: nazwa roju(opc., maks: 32 bajty) (TAB kończy)",
+    ),
+    (
+        "editor.invitation_token",
+        " Paste invitation token    (TAB to finish)",
+        " Wklej token zaproszenia    (TAB kończy)",
+    ),
+    (
+        "editor.neighbor_line",
+        " IP PORT NAT PORTALLOC TRANSPORT    (TAB to finish)",
+        " IP PORT NAT PORTALLOC TRANSPORT    (TAB kończy)",
+    ),
+    (
+        "editor.import_neighbor",
+        " Paste neighbor line or village: invitation    (TAB to finish)",
+        " Wklej linię sąsiada lub zaproszenie village:    (TAB kończy)",
+    ),
+    ("editor.add_note", "Add Note", "Dodaj Notatkę"),
+    (
+        "editor.chat_message",
+        " Chat message    (TAB to send)",
+        " Wiadomość czatu    (TAB wysyła)",
+    ),
+    (
+        "editor.add_search",
+        " Max size: 1024 Multiline  Add a new Search   (TAB to finish)",
+        " Maks. rozmiar: 1024 Wiele linii  Nowe wyszukiwanie   (TAB kończy)",
+    ),
+    (
+        "editor.friend_request",
+        " Friend request to {}    (TAB to send)",
+        " Zaproszenie do znajomych dla {}    (TAB wysyła)",
+    ),
+    ("editor.friend_greeting", "Hi, it's {}", "Cześć, tu {}"),
+    (
+        "editor.ask_for_cid",
+        " ContentID to ask {} for    (TAB to send)",
+        " ContentID, o który zapytać {}    (TAB wysyła)",
+    ),
+    (
+        "content.not_a_cid",
+        "Not a ContentID: {}",
+        "To nie jest ContentID: {}",
+    ),
+    ("content.page", "Page #{}", "Strona #{}"),
+    ("content.no_description", "No description", "Brak opisu"),
+    (
+        "content.nothing_to_show",
+        "Nothing to show",
+        "Nic do pokazania",
+    ),
+    (
+        "content.copied",
+        "Content {} copied",
+        "Zawartość {} skopiowana",
+    ),
+    ("content.link_pasted", "There you go :D", "O właśnie :D"),
+    (
+        "content.copy_first",
+        "Copy a Content first",
+        "Najpierw skopiuj Zawartość",
+    ),
+    (
+        "selector.app_types",
+        "Types of Application",
+        "Typy aplikacji",
+    ),
+    (
+        "selector.catalog_dtypes",
+        "Catalog Application's Data Types",
+        "Typy danych aplikacji Katalog",
+    ),
+    (
+        "selector.catalog_tags",
+        "Catalog Application's Tags",
+        "Tagi aplikacji Katalog",
+    ),
+    ("tag.go_to_street", "Go to street", "Idź na ulicę"),
+    ("tag.change_name", "Change name", "Zmień nazwę"),
+    ("tag.add_street", "Add new street", "Dodaj nową ulicę"),
+    ("tag.what_to_do", "What to do with {}", "Co zrobić z {}"),
+    ("tag.watch", "Watch street", "Obserwuj ulicę"),
+    (
+        "tag.unwatch",
+        "Stop watching street",
+        "Przestań obserwować ulicę",
+    ),
+    ("tag.watched", "Watching street {}", "Obserwujesz ulicę {}"),
+    (
+        "tag.unwatched",
+        "No longer watching street {}",
+        "Nie obserwujesz już ulicy {}",
+    ),
+    (
+        "settings.storage_rules",
+        "Disk storage rules",
+        "Reguły zapisu na dysku",
+    ),
+    (
+        "settings.bootstrap_neighbors",
+        "Bootstrap neighbors",
+        "Sąsiedzi startowi",
+    ),
+    (
+        "storage.append_rule",
+        "Append new rule...",
+        "Dodaj nową regułę...",
+    ),
+    ("storage.rule_condition", "Rule condition", "Warunek reguły"),
+    ("storage.rule_params", "Rule params", "Parametry reguły"),
+    (
+        "storage.select_condition",
+        "Select condition for Storage rule",
+        "Wybierz warunek reguły zapisu",
+    ),
+    (
+        "storage.select_policy",
+        "Select policy for Storage rule",
+        "Wybierz politykę reguły zapisu",
+    ),
+    (
+        "neighbors.our_address",
+        "Our public address",
+        "Nasz publiczny adres",
+    ),
+    ("neighbors.add", "Add neighbor...", "Dodaj sąsiada..."),
+    (
+        "neighbors.import",
+        "Import invitation...",
+        "Importuj zaproszenie...",
+    ),
+    ("neighbors.edit", "Edit {}", "Edytuj {}"),
+    (
+        "neighbors.try_connect",
+        "Try to connect",
+        "Spróbuj połączyć",
+    ),
+    ("neighbors.remove", "Remove", "Usuń"),
+    (
+        "neighbors.connecting",
+        "Connecting to {}:{}…",
+        "Łączenie z {}:{}…",
+    ),
+    (
+        "neighbors.connected",
+        "Connected with {}:{}",
+        "Połączono z {}:{}",
+    ),
+    (
+        "neighbors.connect_failed",
+        "No connection with {}:{}",
+        "Brak połączenia z {}:{}",
+    ),
+    ("neighbors.removed", "Removed {}:{}", "Usunięto {}:{}"),
+    (
+        "neighbors.invalid_line",
+        "Invalid neighbor line",
+        "Niepoprawna linia sąsiada",
+    ),
+    (
+        "neighbors.saved",
+        "Neighbors saved, used on next start",
+        "Zapisano sąsiadów, zostaną użyci przy następnym starcie",
+    ),
+    ("address.local", "Local: {}\n", "Lokalny: {}\n"),
+    (
+        "address.not_discovered",
+        "Public address not discovered yet\n",
+        "Publiczny adres nie został jeszcze ustalony\n",
+    ),
+    (
+        "address.seen_by_others",
+        "Our address as seen by others ({})",
+        "Nasz adres widziany przez innych ({})",
+    ),
+    (
+        "address.public_ips_for",
+        "Public IPs of {}",
+        "Publiczne IP dla {}",
+    ),
+    (
+        "invitation.create",
+        "Create invitation",
+        "Utwórz zaproszenie",
+    ),
+    (
+        "invitation.accept",
+        "Accept invitation",
+        "Przyjmij zaproszenie",
+    ),
+    ("invitation.public_ips", "Public IPs", "Publiczne IP"),
+    (
+        "invitation.title",
+        "Invitation to {} ({} addresses)",
+        "Zaproszenie do {} (adresów: {})",
+    ),
+    (
+        "invitation.invalid",
+        "Invalid invitation",
+        "Niepoprawne zaproszenie",
+    ),
+    ("invitation.visiting", "Visiting {}", "Odwiedzasz {}"),
+    ("swarm.founder", "founder", "założyciel"),
+    ("swarm.member", "member", "członek"),
+    ("swarm.open", "Open {}", "Otwórz {}"),
+    ("swarm.reconnect", "Reconnect", "Połącz ponownie"),
+    (
+        "swarm.unpin",
+        "Unpin from auto-join",
+        "Nie dołączaj automatycznie",
+    ),
+    (
+        "swarm.pin",
+        "Pin to auto-join at startup",
+        "Dołączaj automatycznie przy starcie",
+    ),
+    ("swarm.watch", "Watch for activity", "Obserwuj zmiany"),
+    ("swarm.unwatch", "Stop watching", "Przestań obserwować"),
+    ("swarm.watched", "Watching {}", "Obserwujesz {}"),
+    (
+        "swarm.unwatched",
+        "No longer watching {}",
+        "Nie obserwujesz już {}",
+    ),
+    ("swarm.leave", "Leave", "Opuść"),
+    (
+        "swarm.reconnecting",
+        "Reconnecting {}",
+        "Ponowne łączenie z {}",
+    ),
+    (
+        "swarm.unpinned",
+        "{} unpinned",
+        "{} nie będzie dołączany automatycznie",
+    ),
+    (
+        "swarm.pinned",
+        "{} will be joined at startup",
+        "{} zostanie dołączony przy starcie",
+    ),
+    (
+        "swarm.cannot_leave_own",
+        "Can not leave our own swarm",
+        "Nie można opuścić własnego roju",
+    ),
+    ("swarm.leaving", "Leaving {}…", "Opuszczanie {}…"),
+    ("swarm.left", "{} left", "Opuszczono {}"),
+    (
+        "swarm.disconnected_going_home",
+        "{} disconnected, going home",
+        "{} rozłączony, wracamy do domu",
+    ),
+    ("swarm.disconnected", "{} disconnected", "{} rozłączony"),
+    (
+        "swarm.home_disconnected_reconnecting",
+        "Our village disconnected, reconnecting…",
+        "Nasza wioska rozłączona, ponowne łączenie…",
+    ),
+    (
+        "swarm.home_disconnected",
+        "Our village disconnected",
+        "Nasza wioska rozłączona",
+    ),
+    (
+        "swarm.all_disconnected",
+        "All swarms disconnected",
+        "Wszystkie roje rozłączone",
+    ),
+    (
+        "status.offline",
+        " OFFLINE - no swarm connected ",
+        " OFFLINE - brak połączonych rojów ",
+    ),
+    (
+        "swarm.back_online",
+        "{} is back online",
+        "{} znów jest dostępny",
+    ),
+    (
+        "swarm.reconnected_going_back",
+        "{} reconnected, going back",
+        "{} połączony ponownie, wracamy",
+    ),
+    ("neighbor.request_sent", "{} sent to {}", "{} wysłano do {}"),
+    (
+        "neighbor.summary",
+        "{}: {} tags, {} contents",
+        "{}: tagów: {}, zawartości: {}",
+    ),
+    (
+        "neighbor.friend_request_from",
+        "Friend request from {}: {}",
+        "Zaproszenie do znajomych od {}: {}",
+    ),
+    ("neighbor.pong", "Pong from {}: {} ms", "Pong od {}: {} ms"),
+    (
+        "neighbor.friend_accepted",
+        "{} is our friend now, their village is pinned",
+        "{} jest teraz naszym znajomym, wioska przypięta",
+    ),
+    (
+        "neighbor.got_friend_request",
+        "{} got our friend request",
+        "{} otrzymał nasze zaproszenie",
+    ),
+    (
+        "neighbor.unknown_cid",
+        "{} does not know CID-{}",
+        "{} nie zna CID-{}",
+    ),
+    (
+        "neighbor.unsupported",
+        "{} does not support {} (v{})",
+        "{} nie obsługuje: {} (v{})",
+    ),
+    (
+        "neighbor.timed_out",
+        "{} to {} timed out",
+        "{} do {}: przekroczono czas",
+    ),
+    ("neighbor.ping", "Ping", "Ping"),
+    (
+        "neighbor.village_summary",
+        "Village summary",
+        "Podsumowanie wioski",
+    ),
+    (
+        "neighbor.friend_request",
+        "Friend request",
+        "Zaproszenie do znajomych",
+    ),
+    (
+        "neighbor.content_request",
+        "Content request",
+        "Zapytanie o zawartość",
+    ),
+    (
+        "neighbor.unknown_request",
+        "Unknown request",
+        "Nieznane zapytanie",
+    ),
+    ("neighbor.chat_message", "Chat message", "Wiadomość czatu"),
+    (
+        "chat.title",
+        "Chat in {} ({} neighbors in room)",
+        "Czat w {} (sąsiedzi w pokoju: {})",
+    ),
+    ("chat.join", "Join chat room", "Dołącz do czatu"),
+    ("chat.write", "Write message", "Napisz wiadomość"),
+    ("chat.show_log", "Show messages", "Pokaż wiadomości"),
+    ("chat.leave", "Leave chat room", "Opuść czat"),
+    ("chat.joined", "You joined the room", "Dołączono do pokoju"),
+    ("chat.left", "You left the room", "Opuszczono pokój"),
+    ("chat.member_joined", "{} joined", "{} dołączył(a)"),
+    ("chat.member_left", "{} left", "{} wyszedł/wyszła"),
+    (
+        "chat.nobody",
+        "No neighbors to send it to",
+        "Brak sąsiadów, do których można wysłać",
+    ),
+    (
+        "chat.channel_gone",
+        "No neighbors left in this swarm",
+        "W tym roju nie został żaden sąsiad",
+    ),
+    (
+        "chat.channel_gone_in",
+        "Chat in {} lost all neighbors",
+        "Czat w {} stracił wszystkich sąsiadów",
+    ),
+    ("chat.new_message", "{} wrote in chat of {}", "{} pisze na czacie {}"),
+    ("chat.me", "me", "ja"),
+    ("chat.no_messages", "No messages yet\n", "Brak wiadomości\n"),
+    ("debug.test_note", "Test note", "Testowa notka"),
+    ("notify.info", "info", "info"),
+    ("notify.warning", "WARN", "UWAGA"),
+    ("notify.error", "ERROR", "BŁĄD"),
+    (
+        "notify.open_content",
+        "Open CID-{} in {}",
+        "Otwórz CID-{} w {}",
+    ),
+    ("notify.switch_to", "Switch to {}", "Przejdź do {}"),
+    (
+        "notify.accept_friend",
+        "Accept friend {}",
+        "Przyjmij znajomego {}",
+    ),
+    ("notify.show_log", "Show log", "Pokaż log"),
+    (
+        "notify.pause",
+        "Pause notifications",
+        "Wstrzymaj powiadomienia",
+    ),
+    (
+        "notify.resume",
+        "Resume notifications",
+        "Wznów powiadomienia",
+    ),
+    ("notify.clear", "Clear history", "Wyczyść historię"),
+    ("activity.new", "New CID-{} in {}", "Nowa CID-{} w {}"),
+    (
+        "activity.changed",
+        "CID-{} changed in {}",
+        "Zmiana CID-{} w {}",
+    ),
+    (
+        "activity.clear",
+        "Clear activity feed",
+        "Wyczyść listę zmian",
+    ),
+    (
+        "log.title",
+        "Recent warnings and errors, full log: {}",
+        "Ostatnie ostrzeżenia i błędy, pełny log: {}",
+    ),
+    (
+        "log.empty",
+        "No warnings or errors so far.",
+        "Brak ostrzeżeń i błędów.",
+    ),
+    (
+        "forum.topic_description",
+        "Topic description",
+        "Opis tematu",
+    ),
+    ("forum.no_text", "No text", "Brak tekstu"),
+    ("forum.empty", "Empty", "Pusty"),
+    ("forum.empty_set", "Empty({})", "Pusty({})"),
+    ("forum.pairs_set", "Pairs({})", "Pary({})"),
+    ("forum.bytes_set", "Bytes({})", "Bajty({})"),
+    ("forum.pair_set", "PairSet({})", "ZbiórPar({})"),
+    ("forum.byte_set", "ByteSet({})", "ZbiórBajtów({})"),
+    ("forum.pair", "Pair {}", "Para {}"),
+    ("forum.byte", "Byte {}", "Bajt {}"),
+    (
+        "forum.define_search",
+        "Define Search text",
+        "Podaj tekst wyszukiwania",
+    ),
+    (
+        "forum.no_requests",
+        "No Requests pending",
+        "Brak oczekujących zgłoszeń",
+    ),
+    ("forum.requests", "Pending Requests", "Oczekujące zgłoszenia"),
+    ("forum.request_add_topic", "Add Topic", "Nowy temat"),
+    ("forum.request_add_post", "Add Post", "Nowy wpis"),
+    ("forum.request_original", "Original", "Oryginał"),
+    ("forum.request_modified", "Modified", "Zmiana"),
+    (
+        "forum.add_category",
+        "Add new Category (oneline, 32 bytes max)",
+        "Nowa kategoria (jedna linia, maks. 32 bajty)",
+    ),
+    ("forum.new_category", "NewCategory", "NowaKategoria"),
+    (
+        "forum.topic_post",
+        "Topic #{}, Post #{}",
+        "Temat #{}, wpis #{}",
+    ),
+    ("forum.editing_post", "Editing Post #{}", "Edycja wpisu #{}"),
+    (
+        "forum.edit_cid_description",
+        "Edit Description for CID{}",
+        "Edycja opisu CID{}",
+    ),
+    (
+        "forum.manifest_no_description",
+        "Manifest: no description",
+        "Manifest: brak opisu",
+    ),
+    ("forum.adding_post", "Adding new Post", "Nowy wpis"),
+    (
+        "forum.adding_post_text",
+        "Adding new post…",
+        "Dodaję nowy wpis…",
+    ),
+    (
+        "forum.adding_capability",
+        "Adding new Capability",
+        "Nowa zdolność",
+    ),
+    ("forum.add_new", "+ Add new…", "+ Dodaj nowy…"),
+    (
+        "forum.edit_description",
+        "Edit Forum's description",
+        "Edycja opisu Forum",
+    ),
+    (
+        "forum.editing_description",
+        "Editing Forum's description",
+        "Edycja opisu Forum",
+    ),
+    ("forum.adding_topic", "Adding new Topic", "Nowy temat"),
+    (
+        "forum.reviewing_modified_post",
+        "Reviewing modified Post",
+        "Przegląd zmienionego wpisu",
+    ),
+    (
+        "forum.viewing_original_post",
+        "Viewing original Post",
+        "Podgląd oryginalnego wpisu",
+    ),
+    (
+        "forum.reviewing_new_topic",
+        "Reviewing new Topic",
+        "Przegląd nowego tematu",
+    ),
+    (
+        "forum.reviewing_new_post",
+        "Reviewing new Post",
+        "Przegląd nowego wpisu",
+    ),
+    ("forum.topic", "Topic", "Temat"),
+    (
+        "forum.pick_one",
+        "Pick one & press Enter",
+        "Wybierz jeden i naciśnij Enter",
+    ),
+    (
+        "forum.pick_many",
+        "Pick many with Enter, Escape to finish",
+        "Wybieraj Enterem, Escape kończy",
+    ),
+    ("forum.btn_category", "Category", "Kategor."),
+    ("forum.btn_filter", "Filter", "Filtr"),
+    ("forum.btn_add_topic", "+ Topic", "+ Temat"),
+    ("forum.btn_options", "Options", "Opcje"),
+    ("forum.btn_village", "→ Village", "→ Wioska"),
+    ("forum.btn_more", "…More", "…Więcej"),
+    ("forum.btn_actions", "…Actions", "…Akcje"),
+    ("forum.btn_new_post", "New post", "+ Wpis"),
+    ("forum.btn_edit", "Edit", "Edytuj"),
+    ("forum.btn_forum", "← Forum", "← Forum"),
+    ("forum.btn_requests", "Requests", "Zgłosz."),
+    ("forum.btn_add_category", "+Categry", "+Kategor"),
+    (
+        "forum.btn_running_policies",
+        "Running Policies",
+        "Aktywne polityki",
+    ),
+    (
+        "forum.btn_running_capabilities",
+        "Running Capabilities",
+        "Aktywne zdolności",
+    ),
+    (
+        "forum.btn_running_byte_sets",
+        "Running Byte Sets",
+        "Aktywne zbiory bajtów",
+    ),
+    (
+        "forum.btn_stored_policies",
+        "Stored Policies",
+        "Zapisane polityki",
+    ),
+    (
+        "forum.btn_stored_capabilities",
+        "Stored Capabilities",
+        "Zapisane zdolności",
+    ),
+    (
+        "forum.btn_stored_byte_sets",
+        "Stored Byte Sets",
+        "Zapisane zbiory bajtów",
+    ),
+    (
+        "forum.btn_change_description",
+        "Change Forum's description",
+        "Zmień opis Forum",
+    ),
+    ("forum.btn_approve", "Approve", "Akceptuj"),
+    ("forum.btn_reject", "Reject", "Odrzuć"),
+    ("forum.btn_settings", "← Settings", "← Ustawienia"),
+    ("forum.btn_settings_short", "←Setings", "←Ustawn."),
+    ("forum.btn_add_capability", "Add Cap", "Dod.Zdol"),
+    ("forum.btn_new_byte", "New 1Bte", "Nowy 1B"),
+    ("forum.btn_new_pair", "New 2Bts", "Nowy 2B"),
+    ("forum.btn_run", "Run", "Uruchom"),
+    ("forum.btn_store", "Store", "Zapisz"),
+    ("forum.btn_add", "Add", "Dodaj"),
+    ("forum.btn_modify", "Modify", "Zmień"),
+    ("forum.btn_delete", "Delete", "Usuń"),
+    ("poledit.back", "Back", "Wstecz"),
+    ("poledit.run", "Run", "Uruchom"),
+    ("poledit.store", "Store", "Zapisz"),
+];
@@ -0,0 +1,185 @@
+use crate::config::NeighborEntry;
+use crate::log_warn;
+use dapp_lib::prelude::{GnomeId, SwarmName};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// Invitation token is a text of form: village:<base64url>
+// where encoded bytes are:
+// FounderID(8) | NameLen(1) | Name | AddrCount(1) | Addresses
+// and every Address is:
+// IPVer(1: 4 or 6) | IP(4 or 16) | Port(2) | NAT(1) | PortAlloc(1) | Transport(1)
+const PREFIX: &str = "village:";
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub fn encode_invitation(s_name: &SwarmName, addresses: &[NeighborEntry]) -> String {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(&s_name.founder.0.to_be_bytes());
+    let name_bytes = s_name.name.as_bytes();
+    bytes.push(name_bytes.len() as u8);
+    bytes.extend_from_slice(name_bytes);
+    let addresses: Vec<&NeighborEntry> = addresses.iter().take(u8::MAX as usize).collect();
+    bytes.push(addresses.len() as u8);
+    for addr in addresses {
+        match addr.ip {
+            IpAddr::V4(ip) => {
+                bytes.push(4);
+                bytes.extend_from_slice(&ip.octets());
+            }
+            IpAddr::V6(ip) => {
+                bytes.push(6);
+                bytes.extend_from_slice(&ip.octets());
+            }
+        }
+        bytes.extend_from_slice(&addr.port.to_be_bytes());
+        bytes.push(addr.nat);
+        bytes.push(addr.port_alloc);
+        bytes.push(addr.transport);
+    }
+    format!("{}{}", PREFIX, to_base64(&bytes))
+}
+
+pub fn decode_invitation(token: &str) -> Option<(SwarmName, Vec<NeighborEntry>)> {
+    let token = token.trim();
+    let encoded = token.strip_prefix(PREFIX).unwrap_or(token);
+    let bytes = from_base64(encoded)?;
+    let mut iter = bytes.into_iter();
+    let mut founder = [0u8; 8];
+    for b in founder.iter_mut() {
+        *b = iter.next()?;
+    }
+    let name_len = iter.next()? as usize;
+    let mut name = Vec::with_capacity(name_len);
+    for _i in 0..name_len {
+        name.push(iter.next()?);
+    }
+    let name = String::from_utf8(name).ok()?;
+    let s_name = SwarmName::new(GnomeId(u64::from_be_bytes(founder)), name).ok()?;
+    let addr_count = iter.next()?;
+    let mut addresses = Vec::with_capacity(addr_count as usize);
+    for _i in 0..addr_count {
+        let ip = match iter.next()? {
+            4 => {
+                let mut octets = [0u8; 4];
+                for b in octets.iter_mut() {
+                    *b = iter.next()?;
+                }
+                IpAddr::V4(Ipv4Addr::from(octets))
+            }
+            6 => {
+                let mut octets = [0u8; 16];
+                for b in octets.iter_mut() {
+                    *b = iter.next()?;
+                }
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            other => {
+                log_warn!("Invitation contains unknown IP version: {}", other);
+                return None;
+            }
+        };
+        let port = u16::from_be_bytes([iter.next()?, iter.next()?]);
+        addresses.push(NeighborEntry {
+            ip,
+            port,
+            nat: iter.next()?,
+            port_alloc: iter.next()?,
+            transport: iter.next()?,
+        });
+    }
+    Some((s_name, addresses))
+}
+
+fn to_base64(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+        for i in 0..chunk.len() + 1 {
+            let idx = (triple >> (18 - 6 * i)) & 0x3f;
+            text.push(ALPHABET[idx as usize] as char);
+        }
+    }
+    text
+}
+
+fn from_base64(text: &str) -> Option<Vec<u8>> {
+    // a single trailing character can not carry a whole byte
+    if text.len() % 4 == 1 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = ALPHABET.iter().position(|a| *a == c)? as u32;
+        buffer = (buffer << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = to_base64(&data[..len]);
+            assert_eq!(from_base64(&encoded), Some(data[..len].to_vec()));
+        }
+        assert_eq!(to_base64(b"village"), "dmlsbGFnZQ");
+    }
+
+    #[test]
+    fn base64_rejects_invalid_input() {
+        assert_eq!(from_base64("dmls+GFnZQ"), None);
+        assert_eq!(from_base64("dmlsbGFnZQ=="), None);
+        assert_eq!(from_base64("dmlsb"), None);
+    }
+
+    #[test]
+    fn invitation_round_trip() {
+        let s_name = SwarmName::new(GnomeId(0x0123_4567_89ab_cdef), "/test".to_string()).unwrap();
+        let addresses = vec![
+            NeighborEntry {
+                ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 7)),
+                port: 1026,
+                nat: 0,
+                port_alloc: 0,
+                transport: 1,
+            },
+            NeighborEntry {
+                ip: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                port: 65535,
+                nat: 2,
+                port_alloc: 3,
+                transport: 1,
+            },
+        ];
+        let token = encode_invitation(&s_name, &addresses);
+        assert!(token.starts_with(PREFIX));
+        let (decoded_name, decoded_addresses) = decode_invitation(&token).unwrap();
+        assert_eq!(decoded_name.founder, s_name.founder);
+        assert_eq!(decoded_name.name, s_name.name);
+        assert_eq!(decoded_addresses, addresses);
+    }
+
+    #[test]
+    fn invitation_rejects_invalid_token() {
+        assert!(decode_invitation("village:").is_none());
+        assert!(decode_invitation("village:not a token").is_none());
+        let s_name = SwarmName::new(GnomeId(7), "/test".to_string()).unwrap();
+        let token = encode_invitation(&s_name, &[]);
+        // cut in the middle of swarm name
+        assert!(decode_invitation(&token[..token.len() - 4]).is_none());
+    }
+}
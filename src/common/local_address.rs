@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+use std::fs;
+use std::net::{IpAddr, UdpSocket};
+
+// dapp-lib does not tell which port it listens on,
+// so we look for UDP sockets opened by our own process.
+// Only works where /proc is available, elsewhere it is empty.
+pub fn listening_ports() -> Vec<u16> {
+    let mut inodes = HashSet::new();
+    if let Ok(entries) = fs::read_dir("/proc/self/fd") {
+        for entry in entries.flatten() {
+            if let Ok(target) = fs::read_link(entry.path()) {
+                let target = target.to_string_lossy();
+                if let Some(inode) = target
+                    .strip_prefix("socket:[")
+                    .and_then(|t| t.strip_suffix(']'))
+                {
+                    inodes.insert(inode.to_string());
+                }
+            }
+        }
+    }
+    let mut ports = vec![];
+    for table in ["/proc/self/net/udp", "/proc/self/net/udp6"] {
+        let Ok(text) = fs::read_to_string(table) else {
+            continue;
+        };
+        // sl local_address rem_address st queues tr retrnsmt uid timeout inode
+        for line in text.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 || !inodes.contains(fields[9]) {
+                continue;
+            }
+            let port = fields[1]
+                .rsplit_once(':')
+                .and_then(|(_ip, port)| u16::from_str_radix(port, 16).ok());
+            if let Some(port) = port {
+                if port != 0 && !ports.contains(&port) {
+                    ports.push(port);
+                }
+            }
+        }
+    }
+    ports
+}
+
+// Address of interface used for outgoing traffic,
+// connecting an UDP socket does not send anything
+pub fn local_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("192.0.2.1:9").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
@@ -0,0 +1,315 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Log lines are written to village-tui.log in config dir.
+// Once that file grows above MAX_FILE_SIZE it is renamed to village-tui.log.1,
+// older files are shifted up to village-tui.log.KEEP_FILES and the oldest is removed.
+// Verbosity is set with LOG_LEVEL lines in village-tui.conf:
+// LOG_LEVEL debug            - applies to every module
+// LOG_LEVEL forum=trace      - applies to modules under forum only
+// Most recent warnings and errors are also kept in memory
+// so that they can be presented inside the app.
+const LOG_FILE: &str = "village-tui.log";
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+const KEEP_FILES: usize = 3;
+const KEEP_RECENT: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" | "warning" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _other => None,
+        }
+    }
+}
+
+// Verbosity settings, either global or for given module target
+#[derive(Debug, Clone)]
+pub struct LogLevels {
+    pub default: Level,
+    pub targets: Vec<(String, Level)>,
+}
+
+impl LogLevels {
+    pub fn new() -> Self {
+        LogLevels {
+            default: Level::Info,
+            targets: vec![],
+        }
+    }
+
+    // Accepts "level" or "target=level"
+    pub fn parse(&mut self, setting: &str) -> bool {
+        if let Some((target, level)) = setting.split_once('=') {
+            if let Some(level) = Level::from_name(level) {
+                self.targets.push((target.to_string(), level));
+                return true;
+            }
+        } else if let Some(level) = Level::from_name(setting) {
+            self.default = level;
+            return true;
+        }
+        false
+    }
+
+    // Most specific target wins
+    fn level_for(&self, target: &str) -> Level {
+        let mut best: Option<(usize, Level)> = None;
+        for (t, level) in &self.targets {
+            let matches = target == t || target.starts_with(&format!("{}::", t));
+            if matches && best.map(|(len, _l)| t.len() > len).unwrap_or(true) {
+                best = Some((t.len(), *level));
+            }
+        }
+        best.map(|(_len, level)| level).unwrap_or(self.default)
+    }
+}
+
+impl Default for LogLevels {
+    fn default() -> Self {
+        LogLevels::new()
+    }
+}
+
+struct Logger {
+    dir: PathBuf,
+    file: Option<File>,
+    written: u64,
+    levels: LogLevels,
+    recent: VecDeque<String>,
+}
+
+static LOGGER: Mutex<Option<Logger>> = Mutex::new(None);
+
+// Should be called as early as possible, messages logged before
+// are written to stderr.
+pub fn init(dir: &Path) {
+    let mut logger = Logger {
+        dir: dir.to_path_buf(),
+        file: None,
+        written: 0,
+        levels: LogLevels::new(),
+        recent: VecDeque::with_capacity(KEEP_RECENT),
+    };
+    logger.open();
+    if let Ok(mut current) = LOGGER.lock() {
+        *current = Some(logger);
+    }
+}
+
+pub fn set_levels(levels: LogLevels) {
+    if let Ok(mut current) = LOGGER.lock() {
+        if let Some(logger) = current.as_mut() {
+            logger.levels = levels;
+        }
+    }
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    let target = strip_crate_name(target);
+    if let Ok(current) = LOGGER.lock() {
+        if let Some(logger) = current.as_ref() {
+            return level <= logger.levels.level_for(target);
+        }
+    }
+    level <= Level::Info
+}
+
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    let target = strip_crate_name(target);
+    let Ok(mut current) = LOGGER.lock() else {
+        eprintln!("{}: {}", target, args);
+        return;
+    };
+    let Some(logger) = current.as_mut() else {
+        eprintln!("{}: {}", target, args);
+        return;
+    };
+    if level > logger.levels.level_for(target) {
+        return;
+    }
+    let line = format!("{} {:5} {}: {}", timestamp(), level.name(), target, args);
+    if level <= Level::Warn {
+        if logger.recent.len() >= KEEP_RECENT {
+            logger.recent.pop_front();
+        }
+        logger.recent.push_back(line.clone());
+    }
+    logger.write_line(&line);
+}
+
+// Warnings and errors, oldest first
+pub fn recent_problems() -> Vec<String> {
+    if let Ok(current) = LOGGER.lock() {
+        if let Some(logger) = current.as_ref() {
+            return logger.recent.iter().cloned().collect();
+        }
+    }
+    vec![]
+}
+
+pub fn log_path(dir: &Path) -> PathBuf {
+    dir.join(LOG_FILE)
+}
+
+impl Logger {
+    fn open(&mut self) {
+        let path = log_path(&self.dir);
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => {
+                self.written = file.metadata().map(|m| m.len()).unwrap_or(0);
+                self.file = Some(file);
+            }
+            Err(e) => {
+                eprintln!("Unable to open log file {:?}: {}", path, e);
+                self.file = None;
+            }
+        }
+    }
+
+    fn write_line(&mut self, line: &str) {
+        let Some(file) = self.file.as_mut() else {
+            eprintln!("{}", line);
+            return;
+        };
+        if writeln!(file, "{}", line).is_ok() {
+            self.written += line.len() as u64 + 1;
+        }
+        if self.written >= MAX_FILE_SIZE {
+            self.rotate();
+        }
+    }
+
+    fn rotate(&mut self) {
+        self.file = None;
+        let path = log_path(&self.dir);
+        let numbered = |n: usize| self.dir.join(format!("{}.{}", LOG_FILE, n));
+        let _ = fs::remove_file(numbered(KEEP_FILES));
+        for n in (1..KEEP_FILES).rev() {
+            let _ = fs::rename(numbered(n), numbered(n + 1));
+        }
+        let _ = fs::rename(&path, numbered(1));
+        self.open();
+    }
+}
+
+fn strip_crate_name(target: &str) -> &str {
+    target.strip_prefix("village_tui::").unwrap_or(target)
+}
+
+// UTC time in form of 2024-01-31 23:59:59.999
+pub fn timestamp() -> String {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let day_secs = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
+        year,
+        month,
+        day,
+        day_secs / 3600,
+        (day_secs % 3600) / 60,
+        day_secs % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+// Converts days since 1970-01-01 into (year, month, day)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::common::logging::log(
+            $crate::common::logging::Level::Error,
+            module_path!(),
+            format_args!($($arg)*),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::common::logging::log(
+            $crate::common::logging::Level::Warn,
+            module_path!(),
+            format_args!($($arg)*),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::common::logging::log(
+            $crate::common::logging::Level::Info,
+            module_path!(),
+            format_args!($($arg)*),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::common::logging::log(
+            $crate::common::logging::Level::Debug,
+            module_path!(),
+            format_args!($($arg)*),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)*) => {
+        $crate::common::logging::log(
+            $crate::common::logging::Level::Trace,
+            module_path!(),
+            format_args!($($arg)*),
+        )
+    };
+}
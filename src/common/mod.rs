@@ -1,2 +1,10 @@
+pub mod external_editor;
+pub mod fuzzy;
+pub mod i18n;
+pub mod invitation;
+pub mod local_address;
+pub mod logging;
 pub mod poledit;
+pub mod text_width;
+pub mod theme;
 // pub use poledit::PolicyEditor;
@@ -1,5 +1,11 @@
 use std::collections::HashMap;
 
+use crate::catalog::tui::Help;
+use crate::common::i18n::tr;
+use crate::common::text_width::{cells, str_width};
+use crate::common::theme::{glyph, Role};
+use crate::keymap::{KeyAction, KeyContext, Keymap};
+use crate::{log_debug, log_warn};
 use animaterm::{Animation, Glyph, Graphic, Manager, Timestamp};
 use dapp_lib::prelude::{Capabilities, Policy, Requirement};
 
@@ -20,6 +26,8 @@ pub struct PolicyEditor {
     selection: (usize, usize),
     size: (usize, usize),
     pyramid: Pyramid,
+    keymap: Keymap,
+    help: Help,
     // TODO: build a policy editor and open it
     // Editor should consist of a working space where you can build
     // Requirement for a Policy using graphical structures similar
@@ -36,7 +44,13 @@ impl PolicyEditor {
         let mut library = HashMap::with_capacity(2);
         library.insert(0, frame.clone());
         let mut frame = vec![Glyph::transparent(); dim];
-        draw_a_box(s_w, &pyramid.back, 0, Glyph::white(), &mut frame);
+        draw_a_box(
+            s_w,
+            &pyramid.back,
+            0,
+            glyph(Role::PolicyFrame, ' '),
+            &mut frame,
+        );
         // mgr.swap_frame(self.blinker_id, 1, frame);
         library.insert(1, frame);
         let anim = Animation::new(
@@ -49,6 +63,7 @@ impl PolicyEditor {
         animations.insert(0, anim);
         let blinker = Graphic::new(s_w, s_h, 0, library, Some(animations));
         let blinker_id = mgr.add_graphic(blinker, 2, (0, 0)).unwrap();
+        let help = Help::new(mgr);
 
         PolicyEditor {
             display_id,
@@ -59,8 +74,15 @@ impl PolicyEditor {
             // policy: Policy::Default,
             // requirement: Requirement::Has(Capabilities::Founder),
             pyramid,
+            keymap: Keymap::new(),
+            help,
         }
     }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.help.set_keymap(keymap.clone());
+        self.keymap = keymap;
+    }
     pub fn present(
         &mut self,
         policy: Policy,
@@ -85,22 +107,23 @@ impl PolicyEditor {
         mgr.start_animation(self.blinker_id, 0);
         loop {
             if let Some(key) = mgr.read_key() {
-                match key {
-                    animaterm::Key::Up | animaterm::Key::O => {
+                match self.keymap.action(KeyContext::PolicyEditor, &key) {
+                    Some(KeyAction::MoveUp) => {
                         self.blinker_up(mgr);
                     }
-                    animaterm::Key::Down | animaterm::Key::Comma => {
+                    Some(KeyAction::MoveDown) => {
                         self.blinker_down(mgr, &req);
                     }
-                    animaterm::Key::Left | animaterm::Key::P => {
+                    Some(KeyAction::MoveLeft) => {
                         self.blinker_left(mgr, &req);
                     }
-                    animaterm::Key::Right | animaterm::Key::W => {
+                    Some(KeyAction::MoveRight) => {
                         self.blinker_right(mgr, &req);
                     }
-                    animaterm::Key::Enter => {
+                    Some(KeyAction::Help) => self.help.show(KeyContext::PolicyEditor, mgr),
+                    Some(KeyAction::Select) => {
                         //TODO: Add blocks for Cancel & Apply
-                        eprintln!("Enter on: {:?}", self.selection);
+                        log_debug!("Enter on: {:?}", self.selection);
                         mgr.set_graphic(self.blinker_id, 0, false);
                         match self.selection.0 {
                             0 => match self.selection.1 {
@@ -114,7 +137,7 @@ impl PolicyEditor {
                             // a req. for current block
                             _o => {
                                 req.mark(self.selection);
-                                eprintln!("Mark loc: {:?}", req.mark_location());
+                                log_debug!("Mark loc: {:?}", req.mark_location());
                                 return Some(PolAction::SelectRequirement(req));
                             }
                         }
@@ -128,7 +151,7 @@ impl PolicyEditor {
         None
     }
     pub fn cleanup(&self, main_display: usize, mgr: &mut Manager) {
-        eprintln!("Editor cleanup");
+        log_debug!("Editor cleanup");
         mgr.restore_display(self.display_id, true);
         mgr.restore_display(main_display, false);
     }
@@ -181,10 +204,16 @@ impl PolicyEditor {
                 }
             },
             _o => {
-                eprintln!("Unexpected selection moving blinker UP: {_o}");
+                log_warn!("Unexpected selection moving blinker UP: {_o}");
             }
         }
-        draw_a_box(self.size.0, &block, 0, Glyph::white(), &mut frame);
+        draw_a_box(
+            self.size.0,
+            &block,
+            0,
+            glyph(Role::PolicyFrame, ' '),
+            &mut frame,
+        );
         mgr.stop_animation(self.blinker_id);
         mgr.set_graphic(self.blinker_id, 0, false);
         mgr.swap_frame(self.blinker_id, 1, frame);
@@ -272,12 +301,18 @@ impl PolicyEditor {
                 }
             },
             _o => {
-                eprintln!("Unexp. sel moving blinker down: {_o}");
+                log_warn!("Unexp. sel moving blinker down: {_o}");
                 return;
             }
         }
 
-        draw_a_box(self.size.0, &block, 0, Glyph::white(), &mut frame);
+        draw_a_box(
+            self.size.0,
+            &block,
+            0,
+            glyph(Role::PolicyFrame, ' '),
+            &mut frame,
+        );
         mgr.stop_animation(self.blinker_id);
         mgr.set_graphic(self.blinker_id, 0, false);
         mgr.swap_frame(self.blinker_id, 1, frame);
@@ -390,11 +425,17 @@ impl PolicyEditor {
                 }
             },
             _o => {
-                eprintln!("Unexp. sel moving blinker left: {_o}");
+                log_warn!("Unexp. sel moving blinker left: {_o}");
                 return;
             }
         }
-        draw_a_box(self.size.0, &block, 0, Glyph::white(), &mut frame);
+        draw_a_box(
+            self.size.0,
+            &block,
+            0,
+            glyph(Role::PolicyFrame, ' '),
+            &mut frame,
+        );
         mgr.stop_animation(self.blinker_id);
         mgr.set_graphic(self.blinker_id, 0, false);
         mgr.swap_frame(self.blinker_id, 1, frame);
@@ -507,10 +548,16 @@ impl PolicyEditor {
                 // self.selection = (4, self.selection.1 + 1);
             }
             _o => {
-                eprintln!("Unexp. sel moving blinker left: {_o}");
+                log_warn!("Unexp. sel moving blinker left: {_o}");
             }
         }
-        draw_a_box(self.size.0, &block, 0, Glyph::white(), &mut frame);
+        draw_a_box(
+            self.size.0,
+            &block,
+            0,
+            glyph(Role::PolicyFrame, ' '),
+            &mut frame,
+        );
         mgr.stop_animation(self.blinker_id);
         mgr.set_graphic(self.blinker_id, 0, false);
         mgr.swap_frame(self.blinker_id, 1, frame);
@@ -565,7 +612,7 @@ impl ReqTree {
         }
     }
     pub fn replace_mark(&mut self, r: Requirement) -> bool {
-        eprintln!("Replacing mark @{:?}", self);
+        log_debug!("Replacing mark @{:?}", self);
         if self.r.is_marker() {
             let rt = decompose(r);
             self.r = rt.r;
@@ -635,7 +682,7 @@ impl ReqTree {
                     self.left = Some(Box::new(rl));
                 }
                 _o => {
-                    eprintln!("Placing marker (2,{_o})");
+                    log_debug!("Placing marker (2,{_o})");
                     let mut rr = self.right();
                     rr.mark((1, 0));
                     self.right = Some(Box::new(rr));
@@ -668,7 +715,7 @@ impl ReqTree {
                 }
             },
             o => {
-                eprintln!("Can not mark: {o}");
+                log_warn!("Can not mark: {o}");
             }
         }
     }
@@ -849,7 +896,7 @@ impl Pyramid {
         let s_height = usize::max(25, s_height);
         // TODO: make sure s_height is right
 
-        let f = vec![Glyph::black(); s_width * s_height];
+        let f = vec![glyph(Role::PolicyBackground, ' '); s_width * s_height];
         let mut lib = HashMap::new();
         lib.insert(0, f);
         let canvas = Graphic::new(s_width, s_height, 0, lib, None);
@@ -998,13 +1045,13 @@ impl Pyramid {
     }
     fn paint_buttons(&self, color: Glyph, f: &mut Vec<Glyph>) {
         draw_a_box(self.size.0, &self.back, 0, color, f);
-        type_text(self.size.0, format!("Back"), &self.back, color, f);
+        type_text(self.size.0, tr("poledit.back"), &self.back, color, f);
 
         draw_a_box(self.size.0, &self.set_running, 0, color, f);
-        type_text(self.size.0, format!("Run"), &self.set_running, color, f);
+        type_text(self.size.0, tr("poledit.run"), &self.set_running, color, f);
 
         draw_a_box(self.size.0, &self.store, 0, color, f);
-        type_text(self.size.0, format!("Store"), &self.store, color, f);
+        type_text(self.size.0, tr("poledit.store"), &self.store, color, f);
     }
 
     fn paint_top_block(&self, text: String, color: Glyph, f: &mut Vec<Glyph>) {
@@ -1040,15 +1087,19 @@ impl Pyramid {
     fn paint(&self, mgr: &mut Manager, pol: Policy, req_tree: &ReqTree) {
         let f_len = self.size.0 * self.size.1;
         // let g_t = Glyph::transparent();
-        let g_t = Glyph::black();
+        let g_t = glyph(Role::PolicyBackground, ' ');
         let mut f = vec![g_t; f_len];
 
-        self.paint_buttons(Glyph::indigo(), &mut f);
-        self.paint_top_eye(pol.text(), Glyph::magenta(), &mut f);
+        self.paint_buttons(glyph(Role::PolicyButtons, ' '), &mut f);
+        self.paint_top_eye(pol.text(), glyph(Role::PolicyTop, ' '), &mut f);
         if req_tree.req().is_none() {
             return;
         }
-        self.paint_top_block(req_tree.req().text(), Glyph::green(), &mut f);
+        self.paint_top_block(
+            req_tree.req().text(),
+            glyph(Role::PolicyLevel1, ' '),
+            &mut f,
+        );
         // If we want to use only 1 layer and frame
         // first we have to paint wrappings, starting
         // from outer inwards,
@@ -1077,52 +1128,52 @@ impl Pyramid {
             right_side_painted = true;
         }
         if req_tree.left().left().req().not_none() && !teeth_painted[0] {
-            self.wrap_l3(0, Glyph::green(), 2, &mut f);
+            self.wrap_l3(0, glyph(Role::PolicyLevel1, ' '), 2, &mut f);
             if !teeth_painted[1] {
-                self.wrap_l2(0, Glyph::green(), 1, &mut f);
+                self.wrap_l2(0, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
             }
-            self.wrap_l3(0, Glyph::blue(), 1, &mut f);
+            self.wrap_l3(0, glyph(Role::PolicyLevel2, ' '), 1, &mut f);
             //add
             left_side_painted = true;
         }
         if req_tree.left().right().req().not_none() && !teeth_painted[1] {
-            self.wrap_l3(1, Glyph::green(), 2, &mut f);
+            self.wrap_l3(1, glyph(Role::PolicyLevel1, ' '), 2, &mut f);
             if !teeth_painted[0] {
-                self.wrap_l2(1, Glyph::green(), 1, &mut f);
+                self.wrap_l2(1, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
             }
-            self.wrap_l3(1, Glyph::blue(), 1, &mut f);
+            self.wrap_l3(1, glyph(Role::PolicyLevel2, ' '), 1, &mut f);
             //add
             left_side_painted = true;
         }
         if req_tree.right().left().req().not_none() && !teeth_painted[2] {
-            self.wrap_l3(2, Glyph::green(), 2, &mut f);
+            self.wrap_l3(2, glyph(Role::PolicyLevel1, ' '), 2, &mut f);
             if !teeth_painted[3] && !right_side_painted {
-                self.wrap_l2(1, Glyph::green(), 1, &mut f);
+                self.wrap_l2(1, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
             }
-            self.wrap_l3(2, Glyph::blue(), 1, &mut f);
+            self.wrap_l3(2, glyph(Role::PolicyLevel2, ' '), 1, &mut f);
             //add
             right_side_painted = true;
         }
         if req_tree.right().right().req().not_none() && !teeth_painted[3] {
-            self.wrap_l3(3, Glyph::green(), 2, &mut f);
+            self.wrap_l3(3, glyph(Role::PolicyLevel1, ' '), 2, &mut f);
             if !teeth_painted[2] && !right_side_painted {
-                self.wrap_l2(1, Glyph::green(), 1, &mut f);
+                self.wrap_l2(1, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
             }
-            self.wrap_l3(3, Glyph::blue(), 1, &mut f);
+            self.wrap_l3(3, glyph(Role::PolicyLevel2, ' '), 1, &mut f);
             //add
             right_side_painted = true;
         }
         if req_tree.left().req().not_none() && !left_side_painted {
-            self.wrap_l2(0, Glyph::green(), 1, &mut f);
+            self.wrap_l2(0, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
         }
         if req_tree.right().req().not_none() && !right_side_painted {
-            self.wrap_l2(1, Glyph::green(), 1, &mut f);
+            self.wrap_l2(1, glyph(Role::PolicyLevel1, ' '), 1, &mut f);
         }
 
         if req_tree.req().is_logic_fn() {
             self.paint_second_layer(
                 (req_tree.left().req().text(), req_tree.right().req().text()),
-                Glyph::blue(),
+                glyph(Role::PolicyLevel2, ' '),
                 &mut f,
             );
         }
@@ -1130,13 +1181,13 @@ impl Pyramid {
             self.paint_third_layer(
                 req_tree.left().left().req().text(),
                 0,
-                Glyph::orange(),
+                glyph(Role::PolicyLevel3, ' '),
                 &mut f,
             );
             self.paint_third_layer(
                 req_tree.left().right().req().text(),
                 1,
-                Glyph::orange(),
+                glyph(Role::PolicyLevel3, ' '),
                 &mut f,
             );
         }
@@ -1144,13 +1195,13 @@ impl Pyramid {
             self.paint_third_layer(
                 req_tree.right().left().req().text(),
                 2,
-                Glyph::orange(),
+                glyph(Role::PolicyLevel3, ' '),
                 &mut f,
             );
             self.paint_third_layer(
                 req_tree.right().right().req().text(),
                 3,
-                Glyph::orange(),
+                glyph(Role::PolicyLevel3, ' '),
                 &mut f,
             );
         }
@@ -1161,7 +1212,7 @@ impl Pyramid {
                     req_tree.left().left().left().req().text(),
                     req_tree.left().left().right().req().text(),
                 ),
-                Glyph::red(),
+                glyph(Role::PolicyLevel4, ' '),
                 &mut f,
             );
         }
@@ -1172,7 +1223,7 @@ impl Pyramid {
                     req_tree.left().right().left().req().text(),
                     req_tree.left().right().right().req().text(),
                 ),
-                Glyph::red(),
+                glyph(Role::PolicyLevel4, ' '),
                 &mut f,
             );
         }
@@ -1183,7 +1234,7 @@ impl Pyramid {
                     req_tree.right().left().left().req().text(),
                     req_tree.right().left().right().req().text(),
                 ),
-                Glyph::red(),
+                glyph(Role::PolicyLevel4, ' '),
                 &mut f,
             );
         }
@@ -1194,7 +1245,7 @@ impl Pyramid {
                     req_tree.right().right().left().req().text(),
                     req_tree.right().right().right().req().text(),
                 ),
-                Glyph::red(),
+                glyph(Role::PolicyLevel4, ' '),
                 &mut f,
             );
         }
@@ -1203,15 +1254,15 @@ impl Pyramid {
     }
 
     fn wrap_teeth(&self, t_id: usize, f: &mut Vec<Glyph>) {
-        self.wrap_pillar(t_id * 2, Glyph::green(), 3, f);
-        self.wrap_pillar(1 + t_id * 2, Glyph::green(), 3, f);
-        self.wrap_l3(t_id, Glyph::green(), 2, f);
-        self.wrap_l2(t_id >> 1, Glyph::green(), 1, f);
-        self.wrap_pillar(t_id * 2, Glyph::blue(), 2, f);
-        self.wrap_pillar(1 + t_id * 2, Glyph::blue(), 2, f);
-        self.wrap_l3(t_id, Glyph::blue(), 1, f);
-        self.wrap_pillar(t_id * 2, Glyph::orange(), 1, f);
-        self.wrap_pillar(1 + t_id * 2, Glyph::orange(), 1, f);
+        self.wrap_pillar(t_id * 2, glyph(Role::PolicyLevel1, ' '), 3, f);
+        self.wrap_pillar(1 + t_id * 2, glyph(Role::PolicyLevel1, ' '), 3, f);
+        self.wrap_l3(t_id, glyph(Role::PolicyLevel1, ' '), 2, f);
+        self.wrap_l2(t_id >> 1, glyph(Role::PolicyLevel1, ' '), 1, f);
+        self.wrap_pillar(t_id * 2, glyph(Role::PolicyLevel2, ' '), 2, f);
+        self.wrap_pillar(1 + t_id * 2, glyph(Role::PolicyLevel2, ' '), 2, f);
+        self.wrap_l3(t_id, glyph(Role::PolicyLevel2, ' '), 1, f);
+        self.wrap_pillar(t_id * 2, glyph(Role::PolicyLevel3, ' '), 1, f);
+        self.wrap_pillar(1 + t_id * 2, glyph(Role::PolicyLevel3, ' '), 1, f);
     }
 
     fn wrap_pillar(&self, p_id: usize, color: Glyph, frame_thickness: usize, f: &mut Vec<Glyph>) {
@@ -1242,8 +1293,14 @@ fn draw_a_box(
 }
 
 fn type_text(s_width: usize, text: String, dims: &Block, mut color: Glyph, frame: &mut Vec<Glyph>) {
-    let mut chars = text.chars();
-    let len = text.len();
+    let width = if dims.size_x > dims.size_y {
+        dims.size_x
+    } else {
+        str_width(&text)
+    };
+    let shown = cells(&text, width);
+    let mut chars = shown.iter().copied();
+    let len = shown.len();
     if dims.size_x > dims.size_y {
         let (start, end) = if len > dims.size_x {
             (
@@ -0,0 +1,154 @@
+// Terminal cells taken by text, so that wide characters (CJK, emoji)
+// and combining marks do not push the rest of a line out of its box.
+// A wide character takes two cells, the second one is filled with
+// WIDE_FILLER which has no width of it's own.
+// Combining marks have no cell to be put into and are skipped.
+// Text that does not fit is cut and ends with ELLIPSIS.
+pub const WIDE_FILLER: char = '\u{200B}';
+pub const ELLIPSIS: char = '…';
+
+// Sorted ranges of characters that take no cell
+const ZERO_WIDTH: [(u32, u32); 22] = [
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x2064),
+    (0x20D0, 0x20FF),
+    (0x302A, 0x302D),
+    (0x3099, 0x309A),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0000, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+// Sorted ranges of characters that take two cells
+const WIDE: [(u32, u32); 24] = [
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2E80, 0x303E),
+    (0x3041, 0x3247),
+    (0x3250, 0x4DBF),
+    (0x4E00, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x1B000, 0x1B2FF),
+    (0x1F004, 0x1F004),
+    (0x1F200, 0x1F2FF),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F900, 0x1FAFF),
+    (0x20000, 0x3FFFD),
+];
+
+fn in_ranges(c: u32, ranges: &[(u32, u32)]) -> bool {
+    ranges
+        .binary_search_by(|(start, end)| {
+            if *end < c {
+                std::cmp::Ordering::Less
+            } else if *start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+pub fn char_width(c: char) -> usize {
+    let code = c as u32;
+    if c.is_control() || in_ranges(code, &ZERO_WIDTH) {
+        0
+    } else if in_ranges(code, &WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+pub fn str_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+// One char for every cell text takes, up to width cells.
+// Newlines and tabs are shown as spaces.
+pub fn cells(text: &str, width: usize) -> Vec<char> {
+    indexed_cells(text, width)
+        .into_iter()
+        .map(|(c, _i)| c)
+        .collect()
+}
+
+// Like cells, but every cell also carries index of a char in text
+// it was taken from, so that chosen chars can be drawn differently.
+// ELLIPSIS gets index past the end of text.
+pub fn indexed_cells(text: &str, width: usize) -> Vec<(char, usize)> {
+    let mut cells = Vec::with_capacity(width.min(text.len() * 2));
+    for (i, c) in text.chars().enumerate() {
+        let c = if c == '\n' || c == '\t' { ' ' } else { c };
+        let w = char_width(c);
+        if w == 0 {
+            continue;
+        }
+        if cells.len() + w > width {
+            // Make room for ELLIPSIS, without leaving half of a wide char
+            while !cells.is_empty() && cells.len() >= width {
+                if let Some((WIDE_FILLER, _i)) = cells.pop() {
+                    cells.pop();
+                }
+            }
+            if width > 0 {
+                cells.push((ELLIPSIS, usize::MAX));
+            }
+            return cells;
+        }
+        cells.push((c, i));
+        if w == 2 {
+            cells.push((WIDE_FILLER, i));
+        }
+    }
+    cells
+}
+
+// Text cut to fit in width cells
+pub fn truncate(text: &str, width: usize) -> String {
+    cells(text, width)
+        .into_iter()
+        .filter(|c| *c != WIDE_FILLER)
+        .collect()
+}
+
+// Text cut or padded with spaces to take exactly width cells
+pub fn fit(text: &str, width: usize) -> String {
+    let cells = cells(text, width);
+    let mut fitted = String::with_capacity(width);
+    for c in &cells {
+        if *c != WIDE_FILLER {
+            fitted.push(*c);
+        }
+    }
+    for _i in cells.len()..width {
+        fitted.push(' ');
+    }
+    fitted
+}
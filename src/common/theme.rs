@@ -0,0 +1,515 @@
+use crate::log_warn;
+use animaterm::{Color, Glyph};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::RwLock;
+
+// Every UI element that has it's own look is drawn with a Style
+// of given Role taken from currently selected Theme.
+// Theme is chosen once at startup with THEME line in village-tui.conf.
+// Built-in themes are: default, high_contrast and monochrome.
+// Any other name is read from <name>.theme file in asset dir,
+// every line of such file is of form:
+// role foreground background [style ...]
+// for example:
+// base high_contrast
+// button_active black yellow bright
+// editor_cursor - - reverse blink
+// Colors are: black, red, green, yellow, blue, magenta, cyan, white,
+// orange, indigo, r,g,b with values in range 0-5, or '-' for terminal's default.
+// Styles are: bright, dim, italic, underline, blink, reverse.
+// 'base' line selects a built-in theme to start from, otherwise default is used.
+// If a directory named after a theme exists in asset dir, tile graphics
+// (content.g, content_text.g) are loaded from there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    TileText,
+    Button,
+    ButtonSelected,
+    ButtonActive,
+    Option,
+    OptionSelected,
+    OptionMarked,
+    OptionSelectedMarked,
+    OptionMatch,
+    OptionSelectedMatch,
+    EditorText,
+    EditorCursor,
+    EditorSelection,
+    EditorTitle,
+    MenuSelected,
+    MenuDisabled,
+    MenuSelectedDisabled,
+    ListHeader,
+    Notification,
+    NotificationWarning,
+    NotificationError,
+    ForumBackground,
+    PolicyBackground,
+    PolicyFrame,
+    PolicyButtons,
+    PolicyTop,
+    PolicyLevel1,
+    PolicyLevel2,
+    PolicyLevel3,
+    PolicyLevel4,
+}
+
+const ROLES: [Role; 30] = [
+    Role::TileText,
+    Role::Button,
+    Role::ButtonSelected,
+    Role::ButtonActive,
+    Role::Option,
+    Role::OptionSelected,
+    Role::OptionMarked,
+    Role::OptionSelectedMarked,
+    Role::OptionMatch,
+    Role::OptionSelectedMatch,
+    Role::EditorText,
+    Role::EditorCursor,
+    Role::EditorSelection,
+    Role::EditorTitle,
+    Role::MenuSelected,
+    Role::MenuDisabled,
+    Role::MenuSelectedDisabled,
+    Role::ListHeader,
+    Role::Notification,
+    Role::NotificationWarning,
+    Role::NotificationError,
+    Role::ForumBackground,
+    Role::PolicyBackground,
+    Role::PolicyFrame,
+    Role::PolicyButtons,
+    Role::PolicyTop,
+    Role::PolicyLevel1,
+    Role::PolicyLevel2,
+    Role::PolicyLevel3,
+    Role::PolicyLevel4,
+];
+
+impl Role {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Role::TileText => "tile_text",
+            Role::Button => "button",
+            Role::ButtonSelected => "button_selected",
+            Role::ButtonActive => "button_active",
+            Role::Option => "option",
+            Role::OptionSelected => "option_selected",
+            Role::OptionMarked => "option_marked",
+            Role::OptionSelectedMarked => "option_selected_marked",
+            Role::OptionMatch => "option_match",
+            Role::OptionSelectedMatch => "option_selected_match",
+            Role::EditorText => "editor_text",
+            Role::EditorCursor => "editor_cursor",
+            Role::EditorSelection => "editor_selection",
+            Role::EditorTitle => "editor_title",
+            Role::MenuSelected => "menu_selected",
+            Role::MenuDisabled => "menu_disabled",
+            Role::MenuSelectedDisabled => "menu_selected_disabled",
+            Role::ListHeader => "list_header",
+            Role::Notification => "notification",
+            Role::NotificationWarning => "notification_warning",
+            Role::NotificationError => "notification_error",
+            Role::ForumBackground => "forum_background",
+            Role::PolicyBackground => "policy_background",
+            Role::PolicyFrame => "policy_frame",
+            Role::PolicyButtons => "policy_buttons",
+            Role::PolicyTop => "policy_top",
+            Role::PolicyLevel1 => "policy_level_1",
+            Role::PolicyLevel2 => "policy_level_2",
+            Role::PolicyLevel3 => "policy_level_3",
+            Role::PolicyLevel4 => "policy_level_4",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        ROLES.iter().find(|r| r.name() == name).copied()
+    }
+
+    fn index(&self) -> usize {
+        ROLES.iter().position(|r| r == self).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThemeColor {
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Orange,
+    Indigo,
+    Rgb(u8, u8, u8),
+}
+
+impl ThemeColor {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "-" | "default" => Some(ThemeColor::Default),
+            "black" => Some(ThemeColor::Black),
+            "red" => Some(ThemeColor::Red),
+            "green" => Some(ThemeColor::Green),
+            "yellow" => Some(ThemeColor::Yellow),
+            "blue" => Some(ThemeColor::Blue),
+            "magenta" => Some(ThemeColor::Magenta),
+            "cyan" => Some(ThemeColor::Cyan),
+            "white" => Some(ThemeColor::White),
+            "orange" => Some(ThemeColor::Orange),
+            "indigo" => Some(ThemeColor::Indigo),
+            other => {
+                let mut split = other.split(',');
+                let r = split.next()?.parse::<u8>().ok()?;
+                let g = split.next()?.parse::<u8>().ok()?;
+                let b = split.next()?.parse::<u8>().ok()?;
+                if split.next().is_some() || r > 5 || g > 5 || b > 5 {
+                    return None;
+                }
+                Some(ThemeColor::Rgb(r, g, b))
+            }
+        }
+    }
+
+    // Returns None for terminal's default color
+    fn color(&self) -> Option<Color> {
+        match self {
+            ThemeColor::Default => None,
+            ThemeColor::Black => Some(Color::black()),
+            ThemeColor::Red => Some(Color::red()),
+            ThemeColor::Green => Some(Color::green()),
+            ThemeColor::Yellow => Some(Color::yellow()),
+            ThemeColor::Blue => Some(Color::blue()),
+            ThemeColor::Magenta => Some(Color::magenta()),
+            ThemeColor::Cyan => Some(Color::cyan()),
+            ThemeColor::White => Some(Color::white()),
+            ThemeColor::Orange => Some(Glyph::orange().background),
+            ThemeColor::Indigo => Some(Glyph::indigo().background),
+            ThemeColor::Rgb(r, g, b) => Some(Color::new(*r, *g, *b)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub fg: ThemeColor,
+    pub bg: ThemeColor,
+    pub bright: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub blink: bool,
+    pub reverse: bool,
+}
+
+impl Style {
+    pub fn plain() -> Self {
+        Style {
+            fg: ThemeColor::Default,
+            bg: ThemeColor::Default,
+            bright: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            blink: false,
+            reverse: false,
+        }
+    }
+
+    fn colors(fg: ThemeColor, bg: ThemeColor) -> Self {
+        let mut style = Style::plain();
+        style.fg = fg;
+        style.bg = bg;
+        style
+    }
+
+    fn reversed(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    fn blinking(mut self) -> Self {
+        self.blink = true;
+        self
+    }
+
+    fn dimmed(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    fn brightened(mut self) -> Self {
+        self.bright = true;
+        self
+    }
+
+    fn underlined(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    // Overwrites all attributes of given glyph, so that applying
+    // a different style later does not leave anything behind.
+    pub fn apply(&self, glyph: &mut Glyph) {
+        let base = Glyph::char(' ');
+        glyph.set_color(self.fg.color().unwrap_or(base.color));
+        glyph.set_background(self.bg.color().unwrap_or(base.background));
+        glyph.set_bright(self.bright);
+        glyph.set_dim(self.dim);
+        glyph.set_italic(self.italic);
+        glyph.set_underline(self.underline);
+        glyph.set_blink(self.blink);
+        glyph.set_reverse(self.reverse);
+    }
+
+    pub fn glyph(&self, c: char) -> Glyph {
+        let mut glyph = Glyph::char(c);
+        self.apply(&mut glyph);
+        glyph
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    styles: [Style; 30],
+}
+
+static THEME: RwLock<Option<Theme>> = RwLock::new(None);
+
+impl Theme {
+    pub fn default_theme() -> Self {
+        use ThemeColor::*;
+        let mut styles = [Style::plain(); 30];
+        styles[Role::ButtonSelected.index()] = Style::plain().reversed();
+        styles[Role::ButtonActive.index()] = Style::colors(Yellow, Default).reversed();
+        styles[Role::OptionSelected.index()] = Style::plain().reversed();
+        styles[Role::OptionMarked.index()] = Style::plain().blinking();
+        styles[Role::OptionSelectedMarked.index()] = Style::plain().reversed().blinking();
+        styles[Role::OptionMatch.index()] = Style::colors(Yellow, Default).brightened();
+        styles[Role::OptionSelectedMatch.index()] =
+            Style::colors(Yellow, Default).reversed().brightened();
+        styles[Role::EditorCursor.index()] = Style::plain().reversed().blinking();
+        styles[Role::EditorSelection.index()] = Style::colors(Default, Cyan);
+        styles[Role::MenuSelected.index()] = Style::plain().reversed();
+        styles[Role::MenuDisabled.index()] = Style::plain().dimmed();
+        styles[Role::MenuSelectedDisabled.index()] = Style::plain().reversed().dimmed();
+        styles[Role::ListHeader.index()] = Style::plain().brightened().underlined();
+        styles[Role::Notification.index()] = Style::colors(Default, Blue);
+        styles[Role::NotificationWarning.index()] = Style::colors(Black, Yellow);
+        styles[Role::NotificationError.index()] = Style::colors(Default, Red);
+        styles[Role::ForumBackground.index()] = Style::colors(Default, Blue);
+        styles[Role::PolicyBackground.index()] = Style::colors(Default, Black);
+        styles[Role::PolicyFrame.index()] = Style::colors(Default, White);
+        styles[Role::PolicyButtons.index()] = Style::colors(Default, Indigo);
+        styles[Role::PolicyTop.index()] = Style::colors(Default, Magenta);
+        styles[Role::PolicyLevel1.index()] = Style::colors(Default, Green);
+        styles[Role::PolicyLevel2.index()] = Style::colors(Default, Blue);
+        styles[Role::PolicyLevel3.index()] = Style::colors(Default, Orange);
+        styles[Role::PolicyLevel4.index()] = Style::colors(Default, Red);
+        Theme {
+            name: "default".to_string(),
+            styles,
+        }
+    }
+
+    // Only black and white with bright variants, so that every element
+    // stands out clearly from it's surroundings.
+    pub fn high_contrast() -> Self {
+        use ThemeColor::*;
+        let mut styles = [Style::colors(White, Black); 30];
+        styles[Role::TileText.index()] = Style::colors(White, Default).brightened();
+        styles[Role::ButtonSelected.index()] = Style::colors(Black, White).brightened();
+        styles[Role::ButtonActive.index()] = Style::colors(Black, Yellow).brightened();
+        styles[Role::OptionSelected.index()] = Style::colors(Black, White).brightened();
+        styles[Role::OptionMarked.index()] = Style::colors(Yellow, Black).brightened();
+        styles[Role::OptionSelectedMarked.index()] = Style::colors(Black, Yellow).brightened();
+        styles[Role::OptionMatch.index()] = Style::colors(Cyan, Black).brightened().underlined();
+        styles[Role::OptionSelectedMatch.index()] = Style::colors(Black, Cyan).brightened();
+        styles[Role::EditorTitle.index()] = Style::colors(White, Black).brightened();
+        styles[Role::EditorCursor.index()] = Style::colors(Black, Yellow).blinking();
+        styles[Role::EditorSelection.index()] = Style::colors(Black, White).brightened();
+        styles[Role::MenuSelected.index()] = Style::colors(Black, White).brightened();
+        styles[Role::MenuDisabled.index()] = Style::colors(White, Black).dimmed();
+        styles[Role::MenuSelectedDisabled.index()] = Style::colors(Black, White).dimmed();
+        styles[Role::ListHeader.index()] = Style::colors(Yellow, Black).brightened();
+        styles[Role::Notification.index()] = Style::colors(Black, White).brightened();
+        styles[Role::NotificationWarning.index()] = Style::colors(Black, Yellow).brightened();
+        styles[Role::NotificationError.index()] = Style::colors(White, Red).brightened();
+        styles[Role::PolicyFrame.index()] = Style::colors(Black, Yellow);
+        styles[Role::PolicyButtons.index()] = Style::colors(Black, White);
+        styles[Role::PolicyTop.index()] = Style::colors(Black, Cyan);
+        styles[Role::PolicyLevel1.index()] = Style::colors(Black, Green).brightened();
+        styles[Role::PolicyLevel2.index()] = Style::colors(White, Blue).brightened();
+        styles[Role::PolicyLevel3.index()] = Style::colors(Black, Yellow).brightened();
+        styles[Role::PolicyLevel4.index()] = Style::colors(White, Red).brightened();
+        Theme {
+            name: "high_contrast".to_string(),
+            styles,
+        }
+    }
+
+    // No colors at all, for terminals that can not display them.
+    // Elements are distinguished using reverse, underline and bright only.
+    pub fn monochrome() -> Self {
+        let mut styles = [Style::plain(); 30];
+        styles[Role::ButtonSelected.index()] = Style::plain().reversed();
+        styles[Role::ButtonActive.index()] = Style::plain().reversed().underlined();
+        styles[Role::OptionSelected.index()] = Style::plain().reversed();
+        styles[Role::OptionMarked.index()] = Style::plain().underlined();
+        styles[Role::OptionSelectedMarked.index()] = Style::plain().reversed().underlined();
+        styles[Role::OptionMatch.index()] = Style::plain().brightened().underlined();
+        styles[Role::OptionSelectedMatch.index()] =
+            Style::plain().reversed().brightened().underlined();
+        styles[Role::EditorTitle.index()] = Style::plain().brightened();
+        styles[Role::EditorCursor.index()] = Style::plain().reversed();
+        styles[Role::EditorSelection.index()] = Style::plain().underlined();
+        styles[Role::MenuSelected.index()] = Style::plain().reversed();
+        styles[Role::MenuDisabled.index()] = Style::plain().dimmed();
+        styles[Role::MenuSelectedDisabled.index()] = Style::plain().reversed().dimmed();
+        styles[Role::ListHeader.index()] = Style::plain().underlined();
+        styles[Role::Notification.index()] = Style::plain().reversed();
+        styles[Role::NotificationWarning.index()] = Style::plain().reversed().underlined();
+        styles[Role::NotificationError.index()] =
+            Style::plain().reversed().brightened().underlined();
+        styles[Role::PolicyFrame.index()] = Style::plain().reversed().brightened();
+        styles[Role::PolicyButtons.index()] = Style::plain().underlined();
+        styles[Role::PolicyTop.index()] = Style::plain().reversed();
+        styles[Role::PolicyLevel1.index()] = Style::plain().reversed();
+        styles[Role::PolicyLevel2.index()] = Style::plain().underlined();
+        styles[Role::PolicyLevel3.index()] = Style::plain().reversed().brightened();
+        styles[Role::PolicyLevel4.index()] = Style::plain().brightened().underlined();
+        Theme {
+            name: "monochrome".to_string(),
+            styles,
+        }
+    }
+
+    pub fn built_in(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "default" => Some(Theme::default_theme()),
+            "high_contrast" | "high-contrast" => Some(Theme::high_contrast()),
+            "monochrome" | "mono" => Some(Theme::monochrome()),
+            _other => None,
+        }
+    }
+
+    // Looks for <name>.theme in asset dir first, so that a user
+    // can also override built-in themes.
+    pub fn load(asset_dir: &Path, name: &str) -> Self {
+        let file_path = asset_dir.join(format!("{}.theme", name));
+        if !file_path.exists() {
+            if let Some(theme) = Theme::built_in(name) {
+                return theme;
+            }
+            log_warn!("Theme {} not found, using default", name);
+            return Theme::default_theme();
+        }
+        let file = match File::open(&file_path) {
+            Ok(f) => f,
+            Err(e) => {
+                log_warn!("Unable to open {:?}: {}", file_path, e);
+                return Theme::built_in(name).unwrap_or_else(Theme::default_theme);
+            }
+        };
+        let mut theme = Theme::default_theme();
+        for (line_no, line) in BufReader::new(file).lines().enumerate() {
+            let Ok(line) = line else {
+                break;
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            theme.apply_line(line_no + 1, line);
+        }
+        theme.name = name.to_string();
+        theme
+    }
+
+    fn apply_line(&mut self, line_no: usize, line: &str) {
+        let mut split = line.split_whitespace();
+        let role_name = split.next().unwrap_or("");
+        if role_name == "base" {
+            let base_name = split.next().unwrap_or("");
+            if let Some(base) = Theme::built_in(base_name) {
+                self.styles = base.styles;
+            } else {
+                log_warn!("Theme line {}: unknown base '{}'", line_no, base_name);
+            }
+            return;
+        }
+        let Some(role) = Role::from_name(role_name) else {
+            log_warn!("Theme line {}: unknown role '{}'", line_no, role_name);
+            return;
+        };
+        let mut style = Style::plain();
+        for (i, word) in split.enumerate() {
+            if i < 2 {
+                if let Some(color) = ThemeColor::from_name(word) {
+                    if i == 0 {
+                        style.fg = color;
+                    } else {
+                        style.bg = color;
+                    }
+                    continue;
+                }
+            }
+            match word {
+                "bright" | "bold" => style.bright = true,
+                "dim" => style.dim = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                "blink" => style.blink = true,
+                "reverse" => style.reverse = true,
+                other => {
+                    log_warn!("Theme line {}: unknown color or style '{}'", line_no, other);
+                }
+            }
+        }
+        self.styles[role.index()] = style;
+    }
+
+    pub fn style(&self, role: Role) -> Style {
+        self.styles[role.index()]
+    }
+}
+
+pub fn set_theme(theme: Theme) {
+    if let Ok(mut current) = THEME.write() {
+        *current = Some(theme);
+    }
+}
+
+pub fn theme_name() -> String {
+    if let Ok(current) = THEME.read() {
+        if let Some(theme) = current.as_ref() {
+            return theme.name.clone();
+        }
+    }
+    "default".to_string()
+}
+
+pub fn style(role: Role) -> Style {
+    if let Ok(current) = THEME.read() {
+        if let Some(theme) = current.as_ref() {
+            return theme.style(role);
+        }
+    }
+    Theme::default_theme().style(role)
+}
+
+pub fn glyph(role: Role, c: char) -> Glyph {
+    style(role).glyph(c)
+}
+
+pub fn apply(role: Role, glyph: &mut Glyph) {
+    style(role).apply(glyph);
+}
@@ -1,20 +1,31 @@
 use dapp_lib::prelude::{
-    load_content_from_disk, load_first_pages_from_disk, read_datastore_from_disk, GnomeId,
-    StoragePolicy,
+    load_content_from_disk, load_first_pages_from_disk, read_datastore_from_disk, AppType,
+    ContentID, GnomeId, StoragePolicy, SwarmName,
 };
-use dapp_lib::prelude::{DataType, NetworkSettings};
-use std::fs::{self, File};
-use std::io::{self, BufRead, BufReader};
-// use std::net::IpAddr;
+use dapp_lib::prelude::{DataType, Nat, NetworkSettings, PortAllocationRule, Transport};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
-// use std::str::FromStr;
+use std::str::FromStr;
 
 use crate::catalog::logic::Manifest;
+use crate::common::i18n::Language;
+use crate::common::logging::LogLevels;
+use crate::common::theme::Theme;
+use crate::keymap::Keymap;
+use crate::{log_debug, log_info, log_warn};
 
 #[derive(Clone)]
 pub struct Configuration {
     pub asset_dir: PathBuf,
     pub storage_neighbors: Vec<(GnomeId, NetworkSettings)>,
+    pub keymap: Keymap,
+    pub dev_mode: bool,
+    pub restore_session: bool,
+    pub language: Language,
+    pub theme: Theme,
+    pub log_levels: LogLevels,
 }
 
 impl Configuration {
@@ -34,11 +45,11 @@ impl Configuration {
             let sstr = sswarm.to_string_lossy();
             if !sstr.contains('-') {
                 // TODO: improve logic for loading contents from disk
-                eprintln!("Skipping {sstr}");
+                log_debug!("Skipping {sstr}");
                 continue;
             }
             let sstr = sstr.split('-').last().unwrap();
-            eprintln!("Parsing str: {}", sstr);
+            log_debug!("Parsing str: {}", sstr);
             let g_id: u64 = u64::from_str_radix(&sstr, 16).unwrap();
             let g_id = GnomeId(g_id);
             let dsync_file = sswarm.join("datastore.sync");
@@ -89,7 +100,7 @@ impl Configuration {
                     if pub_ip.pub_ip.is_ipv4() {
                         if next_v4 {
                             if !added_gids.contains(&g_id) {
-                                eprintln!("Add V4 neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
+                                log_info!("Add V4 neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
                                 added_gids.push(g_id);
                                 storage_neighbors.push((
                                     g_id,
@@ -105,7 +116,7 @@ impl Configuration {
                             }
                         } else if pubiplen == 1 {
                             if !added_gids.contains(&g_id) {
-                                eprintln!("Add neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
+                                log_info!("Add neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
                                 added_gids.push(g_id);
                                 storage_neighbors.push((
                                     g_id,
@@ -122,7 +133,7 @@ impl Configuration {
                     } else {
                         if next_v4 && pubiplen == 1 {
                             if !added_gids.contains(&g_id) {
-                                eprintln!("Add a neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
+                                log_info!("Add a neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
                                 added_gids.push(g_id);
                                 storage_neighbors.push((
                                     g_id,
@@ -137,7 +148,7 @@ impl Configuration {
                             }
                         } else {
                             if !added_gids.contains(&g_id) {
-                                eprintln!("Add V6 neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
+                                log_info!("Add V6 neighbor {}:{}", pub_ip.pub_ip, pub_ip.pub_port);
                                 added_gids.push(g_id);
                                 storage_neighbors.push((
                                     g_id,
@@ -155,7 +166,7 @@ impl Configuration {
                     }
                 }
             } else {
-                eprintln!("Failed to load CID-0");
+                log_warn!("Failed to load CID-0");
             }
         }
 
@@ -165,6 +176,7 @@ impl Configuration {
             default_config()
         };
         conf.storage_neighbors = storage_neighbors;
+        conf.keymap = Keymap::load(dir);
         conf
     }
 }
@@ -174,19 +186,30 @@ fn default_config() -> Configuration {
     Configuration {
         asset_dir,
         storage_neighbors: vec![],
+        keymap: Keymap::new(),
+        dev_mode: false,
+        restore_session: true,
+        language: Language::from_env(),
+        theme: Theme::default_theme(),
+        log_levels: LogLevels::new(),
     }
 }
 
 fn parse_config(file: &Path) -> Configuration {
     let mut asset_dir = PathBuf::new();
     asset_dir = asset_dir.join("/home/dxtr/projects/village-tui/assets/");
+    let mut dev_mode = false;
+    let mut restore_session = true;
+    let mut language = Language::from_env();
+    let mut theme_name = String::from("default");
+    let mut log_levels = LogLevels::new();
     let lines_iter = read_lines(file).unwrap().into_iter();
     for line in lines_iter {
         let ls = line.unwrap().to_string();
         if ls.starts_with('#') || ls.is_empty() {
-            eprintln!("Ignoring Line: {}", ls);
+            log_debug!("Ignoring Line: {}", ls);
         } else {
-            eprintln!("Parsing Line: {}", ls);
+            log_debug!("Parsing Line: {}", ls);
             let mut split = ls.split_whitespace();
             let line_header = split.next().unwrap();
             match line_header {
@@ -196,8 +219,34 @@ fn parse_config(file: &Path) -> Configuration {
                         asset_dir = asset_dir.join(dir);
                     }
                 }
+                "LANGUAGE" => {
+                    if let Some(lang) = split.next().and_then(Language::from_code) {
+                        language = lang;
+                    } else {
+                        log_warn!("Unsupported language, using {:?}", language);
+                    }
+                }
+                "THEME" => {
+                    if let Some(name) = split.next() {
+                        theme_name = name.to_string();
+                    }
+                }
+                "LOG_LEVEL" => {
+                    for setting in split {
+                        if !log_levels.parse(setting) {
+                            log_warn!("Unrecognized log level: {}", setting);
+                        }
+                    }
+                }
+                "DEV_MODE" => {
+                    dev_mode = !matches!(split.next(), Some("0") | Some("false") | Some("off"));
+                }
+                "RESTORE_SESSION" => {
+                    restore_session =
+                        !matches!(split.next(), Some("0") | Some("false") | Some("off"));
+                }
                 other => {
-                    eprintln!("Unrecognized config line: {}", other);
+                    log_warn!("Unrecognized config line: {}", other);
                 }
             }
         }
@@ -205,7 +254,393 @@ fn parse_config(file: &Path) -> Configuration {
     Configuration {
         asset_dir,
         storage_neighbors: vec![],
+        keymap: Keymap::new(),
+        dev_mode,
+        restore_session,
+        language,
+        theme: Theme::load(&asset_dir, &theme_name),
+        log_levels,
+    }
+}
+
+// A single line of neigh.conf file:
+// IPv4 or v6  PORT  NAT PORTALLOC TRANSPORT
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeighborEntry {
+    pub ip: IpAddr,
+    pub port: u16,
+    pub nat: u8,
+    pub port_alloc: u8,
+    pub transport: u8,
+}
+
+impl NeighborEntry {
+    // Besides full neigh.conf lines we also accept "IP:PORT" and "IP PORT"
+    // forms, in which case remaining fields take default values.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let mut split = line.split_whitespace();
+        let first = split.next()?;
+        let (ip, port) = if let Ok(ip) = IpAddr::from_str(first) {
+            (ip, split.next()?.parse::<u16>().ok()?)
+        } else {
+            let (ip_str, port_str) = first.rsplit_once(':')?;
+            let ip_str = ip_str.trim_start_matches('[').trim_end_matches(']');
+            (
+                IpAddr::from_str(ip_str).ok()?,
+                port_str.parse::<u16>().ok()?,
+            )
+        };
+        if port == 0 {
+            return None;
+        }
+        let nat = split
+            .next()
+            .map(|n| n.parse::<u8>().ok())
+            .unwrap_or(Some(0))?;
+        let port_alloc = split
+            .next()
+            .map(|n| n.parse::<u8>().ok())
+            .unwrap_or(Some(0))?;
+        let transport = split
+            .next()
+            .map(|n| n.parse::<u8>().ok())
+            .unwrap_or(Some(1))?;
+        Some(NeighborEntry {
+            ip,
+            port,
+            nat,
+            port_alloc,
+            transport,
+        })
+    }
+
+    pub fn from_network_settings(ns: &NetworkSettings) -> Self {
+        NeighborEntry {
+            ip: ns.pub_ip,
+            port: ns.pub_port,
+            nat: ns.nat_type as u8,
+            port_alloc: ns.port_allocation.0 as u8,
+            transport: ns.transport as u8,
+        }
+    }
+
+    pub fn to_network_settings(&self) -> NetworkSettings {
+        NetworkSettings {
+            pub_ip: self.ip,
+            pub_port: self.port,
+            nat_type: Nat::from(self.nat),
+            port_allocation: (PortAllocationRule::from(self.port_alloc), 0),
+            transport: Transport::from(self.transport),
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.ip, self.port, self.nat, self.port_alloc, self.transport
+        )
+    }
+}
+
+pub fn read_neighbors(dir: &Path) -> Vec<NeighborEntry> {
+    let n_path = dir.join("neigh.conf");
+    let mut neighbors = vec![];
+    if !n_path.exists() {
+        return neighbors;
+    }
+    if let Ok(lines) = read_lines(n_path) {
+        for line in lines.map_while(Result::ok) {
+            if let Some(entry) = NeighborEntry::from_line(&line) {
+                neighbors.push(entry);
+            } else if !line.starts_with('#') && !line.trim().is_empty() {
+                log_warn!("Ignoring invalid neighbor line: {}", line);
+            }
+        }
     }
+    neighbors
+}
+
+// neigh.conf is edited line by line, so that comments
+// and lines we can not parse stay as they were
+pub fn add_neighbors(dir: &Path, entries: &[NeighborEntry]) {
+    let existing = read_neighbors(dir);
+    let n_path = dir.join("neigh.conf");
+    let mut contents = String::new();
+    if !n_path.exists() {
+        contents.push_str("# IPv4 or v6  PORT  NAT PORTALLOC TRANSPORT\n");
+    } else if fs::read_to_string(&n_path)
+        .is_ok_and(|text| !text.is_empty() && !text.ends_with('\n'))
+    {
+        contents.push('\n');
+    }
+    for entry in entries {
+        if !existing.contains(entry) {
+            contents.push_str(&entry.to_line());
+            contents.push('\n');
+        }
+    }
+    let file_res = OpenOptions::new().create(true).append(true).open(&n_path);
+    if let Err(e) = file_res.and_then(|mut file| file.write_all(contents.as_bytes())) {
+        log_warn!("Failed to write {:?}: {}", n_path, e);
+    }
+}
+
+// Lines describing old neighbor are replaced with new one,
+// or removed when there is no new one
+pub fn replace_neighbor(dir: &Path, old: &NeighborEntry, new: Option<&NeighborEntry>) {
+    let n_path = dir.join("neigh.conf");
+    let contents = match fs::read_to_string(&n_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            log_warn!("Failed to read {:?}: {}", n_path, e);
+            return;
+        }
+    };
+    let mut edited = String::with_capacity(contents.len());
+    for line in contents.lines() {
+        if NeighborEntry::from_line(line).as_ref() == Some(old) {
+            if let Some(new) = new {
+                edited.push_str(&new.to_line());
+                edited.push('\n');
+            }
+            continue;
+        }
+        edited.push_str(line);
+        edited.push('\n');
+    }
+    if let Err(e) = fs::write(&n_path, edited) {
+        log_warn!("Failed to write {:?}: {}", n_path, e);
+    }
+}
+
+// Swarms listed in autojoin.conf are joined right after our home swarm,
+// one swarm per line: FounderID SwarmName
+pub fn read_autojoin(dir: &Path) -> Vec<SwarmName> {
+    let a_path = dir.join("autojoin.conf");
+    let mut swarms = vec![];
+    if !a_path.exists() {
+        return swarms;
+    }
+    if let Ok(lines) = read_lines(a_path) {
+        for line in lines.map_while(Result::ok) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(s_name) = parse_swarm_name(line) {
+                swarms.push(s_name);
+            } else {
+                log_warn!("Ignoring invalid autojoin line: {}", line);
+            }
+        }
+    }
+    swarms
+}
+
+pub fn write_autojoin(dir: &Path, swarms: &[SwarmName]) {
+    let a_path = dir.join("autojoin.conf");
+    let mut contents = String::from("# FounderID SwarmName\n");
+    for s_name in swarms {
+        contents.push_str(&format!("{} {}\n", s_name.founder, s_name.name));
+    }
+    if let Err(e) = fs::write(&a_path, contents) {
+        log_warn!("Failed to write {:?}: {}", a_path, e);
+    }
+}
+
+// Swarms and street names listed in watch.conf are reported
+// in activity feed when their contents change while we are elsewhere.
+// swarm FounderID SwarmName
+// tag StreetName
+#[derive(Debug, Clone, Default)]
+pub struct WatchList {
+    pub swarms: Vec<SwarmName>,
+    pub tags: Vec<String>,
+}
+
+impl WatchList {
+    pub fn is_empty(&self) -> bool {
+        self.swarms.is_empty() && self.tags.is_empty()
+    }
+}
+
+pub fn read_watch_list(dir: &Path) -> WatchList {
+    let w_path = dir.join("watch.conf");
+    let mut watch_list = WatchList::default();
+    if !w_path.exists() {
+        return watch_list;
+    }
+    if let Ok(lines) = read_lines(w_path) {
+        for line in lines.map_while(Result::ok) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once(' ') {
+                Some(("swarm", rest)) => {
+                    if let Some(s_name) = parse_swarm_name(rest) {
+                        watch_list.swarms.push(s_name);
+                    } else {
+                        log_warn!("Ignoring invalid watch line: {}", line);
+                    }
+                }
+                Some(("tag", name)) => {
+                    watch_list.tags.push(name.to_string());
+                }
+                _other => {
+                    log_warn!("Ignoring invalid watch line: {}", line);
+                }
+            }
+        }
+    }
+    watch_list
+}
+
+pub fn write_watch_list(dir: &Path, watch_list: &WatchList) {
+    let w_path = dir.join("watch.conf");
+    let mut contents = String::from("# swarm FounderID SwarmName\n# tag StreetName\n");
+    for s_name in &watch_list.swarms {
+        contents.push_str(&format!("swarm {} {}\n", s_name.founder, s_name.name));
+    }
+    for tag in &watch_list.tags {
+        contents.push_str(&format!("tag {}\n", tag));
+    }
+    if let Err(e) = fs::write(&w_path, contents) {
+        log_warn!("Failed to write {:?}: {}", w_path, e);
+    }
+}
+
+// Where we were when village-tui was closed last time,
+// stored in session.conf and restored on next start:
+// app catalog|forum
+// swarm FounderID SwarmName
+// street PageNo StreetName
+// topic TopicID PageNo
+// tile Row Column
+// clipboard ContentID FounderID SwarmName
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub app_type: AppType,
+    pub swarm: SwarmName,
+    // Catalog only
+    pub street: Option<(String, u16)>,
+    pub tile: Option<(u8, u8)>,
+    // Forum only
+    pub topic: Option<(u16, u16)>,
+    pub clipboard: Option<(SwarmName, ContentID)>,
+}
+
+pub fn read_session(dir: &Path) -> Option<Session> {
+    let s_path = dir.join("session.conf");
+    if !s_path.exists() {
+        return None;
+    }
+    let mut app_type = None;
+    let mut swarm = None;
+    let mut street = None;
+    let mut tile = None;
+    let mut topic = None;
+    let mut clipboard = None;
+    let lines = read_lines(s_path).ok()?;
+    for line in lines.map_while(Result::ok) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (header, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let parsed = match header {
+            "app" => {
+                app_type = match rest {
+                    "catalog" => Some(AppType::Catalog),
+                    "forum" => Some(AppType::Forum),
+                    _other => None,
+                };
+                app_type.is_some()
+            }
+            "swarm" => {
+                swarm = parse_swarm_name(rest);
+                swarm.is_some()
+            }
+            "street" => {
+                // Nameless street is written as page number only
+                let (pg_str, name) = rest.split_once(' ').unwrap_or((rest, ""));
+                street = pg_str.parse::<u16>().ok().map(|pg| (name.to_string(), pg));
+                street.is_some()
+            }
+            "tile" => {
+                tile = rest.split_once(' ').and_then(|(row, col)| {
+                    Some((row.parse::<u8>().ok()?, col.parse::<u8>().ok()?))
+                });
+                tile.is_some()
+            }
+            "topic" => {
+                topic = rest.split_once(' ').and_then(|(t_id, pg)| {
+                    Some((t_id.parse::<u16>().ok()?, pg.parse::<u16>().ok()?))
+                });
+                topic.is_some()
+            }
+            "clipboard" => {
+                clipboard = rest.split_once(' ').and_then(|(c_id, s_name)| {
+                    Some((parse_swarm_name(s_name)?, c_id.parse::<ContentID>().ok()?))
+                });
+                clipboard.is_some()
+            }
+            _other => false,
+        };
+        if !parsed {
+            log_warn!("Ignoring invalid session line: {}", line);
+        }
+    }
+    Some(Session {
+        app_type: app_type?,
+        swarm: swarm?,
+        street,
+        tile,
+        topic,
+        clipboard,
+    })
+}
+
+pub fn write_session(dir: &Path, session: &Session) {
+    let s_path = dir.join("session.conf");
+    let app = match session.app_type {
+        AppType::Forum => "forum",
+        _catalog => "catalog",
+    };
+    let mut contents = String::from("# Written on exit, see RESTORE_SESSION\n");
+    contents.push_str(&format!(
+        "app {}\nswarm {} {}\n",
+        app, session.swarm.founder, session.swarm.name
+    ));
+    if let Some((name, pg)) = &session.street {
+        contents.push_str(&format!("street {} {}\n", pg, name));
+    }
+    if let Some((row, col)) = session.tile {
+        contents.push_str(&format!("tile {} {}\n", row, col));
+    }
+    if let Some((t_id, pg)) = session.topic {
+        contents.push_str(&format!("topic {} {}\n", t_id, pg));
+    }
+    if let Some((s_name, c_id)) = &session.clipboard {
+        contents.push_str(&format!(
+            "clipboard {} {} {}\n",
+            c_id, s_name.founder, s_name.name
+        ));
+    }
+    if let Err(e) = fs::write(&s_path, contents) {
+        log_warn!("Failed to write {:?}: {}", s_path, e);
+    }
+}
+
+// FounderID SwarmName
+fn parse_swarm_name(text: &str) -> Option<SwarmName> {
+    let (g_str, name) = text.split_once(' ')?;
+    let g_id = GnomeId::from_string(g_str.to_string())?;
+    SwarmName::new(g_id, name.to_string()).ok()
 }
 
 fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
@@ -219,7 +654,7 @@ where
 fn list_directories_in_pathbuf(path: PathBuf) -> Vec<PathBuf> {
     let read_result = fs::read_dir(&path);
     if read_result.is_err() {
-        eprintln!(
+        log_warn!(
             "Failed to list directories in {:?}:\n{:?}",
             path,
             read_result.err().unwrap()
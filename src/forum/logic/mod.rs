@@ -1,10 +1,14 @@
 use crate::catalog::logic::SwarmShell;
+use crate::common::i18n::{tr, trf};
 mod message;
 use crate::catalog::tui::CreatorResult;
 use crate::catalog::tui::EditorResult;
+use crate::catalog::tui::{Column, ListData};
 use crate::common::poledit::decompose;
 use crate::common::poledit::PolAction;
 use crate::common::poledit::ReqTree;
+use crate::common::text_width::{fit, truncate};
+use crate::config::{write_session, Session};
 use crate::forum::tui::EditorParams;
 use async_std::channel::Receiver as AReceiver;
 use async_std::channel::Sender as ASender;
@@ -39,6 +43,7 @@ use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 use std::time::Duration;
 
+use crate::{log_debug, log_error, log_info, log_warn};
 use dapp_lib::prelude::AppType;
 #[derive(Debug, Clone)]
 pub struct TopicContext {
@@ -51,7 +56,7 @@ impl TopicContext {
     pub fn new(tag_names: Vec<String>) -> Self {
         TopicContext {
             t_id: None,
-            description: "Topic description".to_string(),
+            description: tr("forum.topic_description"),
             tags: vec![],
             tag_names,
         }
@@ -142,7 +147,7 @@ impl Entry {
             bytes.remove(0);
         // };
         let mut tags = vec![];
-        eprintln!("tags count: {tags_count}");
+        log_debug!("tags count: {tags_count}");
         for _i in 0..tags_count {
             tags.push(bytes.remove(0));
         }
@@ -159,7 +164,7 @@ impl Entry {
         for _i in 0..tlen {
             sbytes.push(bytes.remove(0));
         }
-        eprintln!("Attempt to create Entry from {:?} bytes", bytes);
+        log_debug!("Attempt to create Entry from {:?} bytes", bytes);
         let str_res = String::from_utf8(sbytes);
         let g_id = u64::from_be_bytes([
             bytes.remove(0),
@@ -174,7 +179,7 @@ impl Entry {
         if let Ok(text) = str_res {
             Ok(Entry::new(GnomeId(g_id), tags, text, hash))
         } else {
-            eprintln!("Could not create entry: {}", str_res.err().unwrap());
+            log_warn!("Could not create entry: {}", str_res.err().unwrap());
             Err(())
         }
     }
@@ -203,17 +208,16 @@ impl Entry {
         )
     }
     pub fn entry_line(&self, size: usize) -> String {
-        let mut text = if let Some(line) = self.text.lines().next() {
-            line.trim().chars().take(size - 21).collect::<String>()
-        } else {
-            "No text".to_string()
-        };
-        let t_len = text.chars().count();
+        format!("{} {}", fit(&self.headline(), size - 21), self.author)
+    }
 
-        for _i in t_len..size - 21 {
-            text.push(' ');
+    // First line of text
+    pub fn headline(&self) -> String {
+        if let Some(line) = self.text.lines().next() {
+            line.trim().to_string()
+        } else {
+            tr("forum.no_text")
         }
-        format!("{} {}", text, self.author)
     }
 
     pub fn new(author: GnomeId, tags: Vec<u8>, text: String, hash: u64) -> Self {
@@ -228,12 +232,23 @@ impl Entry {
         Entry {
             author: GnomeId::any(),
             tags: vec![],
-            text: format!("Empty"),
+            text: tr("forum.empty"),
             hash: 0,
         }
     }
 }
 
+// Row of a request table: kind of request, topic (and post) it applies to,
+// entry's first line and author
+fn request_row(kind: &str, location: String, entry: &Entry) -> Vec<String> {
+    vec![
+        tr(kind),
+        location,
+        entry.headline(),
+        entry.author.to_string(),
+    ]
+}
+
 use crate::forum::tui::serve_forum_tui;
 use crate::forum::tui::Action;
 use crate::forum::tui::FromForumView;
@@ -261,6 +276,8 @@ pub struct ForumLogic {
     to_tui_recv: Option<Receiver<ToForumView>>,
     from_tui_send: Option<Sender<FromForumView>>,
     clipboard: Option<(SwarmName, ContentID)>,
+    // Topic and it's page we were at on last exit
+    restore_topic: Option<(u16, u16)>,
 }
 impl ForumLogic {
     pub fn new(
@@ -298,12 +315,16 @@ impl ForumLogic {
             to_tui_recv: Some(to_tui_recv),
             from_tui_send: Some(from_tui_send),
             clipboard: None,
+            restore_topic: None,
         }
     }
+    pub fn restore_session(&mut self, session: Session) {
+        self.restore_topic = session.topic;
+    }
     pub async fn run(
         mut self,
         founder: GnomeId,
-        _config_dir: PathBuf,
+        config_dir: PathBuf,
         toolset: Toolset,
         clipboard_opt: Option<(SwarmName, ContentID)>,
         // mut config: Configuration,
@@ -317,6 +338,7 @@ impl ForumLogic {
         Option<(SwarmName, ContentID)>,
     )> {
         self.clipboard = clipboard_opt;
+        let save_session = toolset.config().restore_session;
         let from_presentation_msg_send = self.from_tui_send.take().unwrap();
         let to_presentation_msg_recv = self.to_tui_recv.take().unwrap();
         let tui_join = spawn_blocking(move || {
@@ -340,7 +362,7 @@ impl ForumLogic {
         loop {
             let int_msg_res = self.to_user_recv.recv().await;
             if int_msg_res.is_err() {
-                eprintln!("Forum error recv internal: {}", int_msg_res.err().unwrap());
+                log_error!("Forum error recv internal: {}", int_msg_res.err().unwrap());
                 break;
             }
             let msg = int_msg_res.unwrap();
@@ -356,15 +378,15 @@ impl ForumLogic {
                     }
                 }
                 _other => {
-                    eprintln!("Forum unexpected InternalMsg");
+                    log_warn!("Forum unexpected InternalMsg");
                 }
             }
         }
 
-        eprintln!("ForumLogic is done");
+        log_info!("ForumLogic is done");
         let toolset = tui_join.await;
 
-        eprintln!("Forum is all done.");
+        log_debug!("Forum is all done.");
         if let Some((switch_app, s_name)) = switch_to_opt {
             let _ = self
                 .to_app_mgr_send
@@ -380,6 +402,9 @@ impl ForumLogic {
                 self.clipboard,
             ))
         } else {
+            if save_session {
+                write_session(&config_dir, &self.current_session());
+            }
             let _ = self.to_app_mgr_send.send(ToAppMgr::Quit).await;
             toolset.discard();
             None
@@ -388,11 +413,11 @@ impl ForumLogic {
     async fn process_to_app_msg(&mut self, to_app: ToApp) {
         match to_app {
             ToApp::ActiveSwarm(s_name, s_id) => {
-                eprintln!("Forum: ToApp::ActiveSwarm({s_id},{s_name})");
+                log_debug!("Forum: ToApp::ActiveSwarm({s_id},{s_name})");
                 if s_name == self.shell.swarm_name {
                     self.shell.swarm_id = s_id;
 
-                    eprintln!(
+                    log_debug!(
                         "Forum request FirstPages up to incl: {}",
                         self.entries_count - 1
                     );
@@ -408,6 +433,24 @@ impl ForumLogic {
                             s_id, 0,
                         )))
                         .await;
+                    if let Some((t_id, pg)) = self.restore_topic.take() {
+                        // Read every page up to restored one at once,
+                        // otherwise we would fall back to first page
+                        log_info!("Restoring Topic {t_id} page {pg}");
+                        let _ = self
+                            .to_app_mgr_send
+                            .send(ToAppMgr::FromApp(dapp_lib::LibRequest::ReadPagesRange(
+                                s_id,
+                                t_id,
+                                0,
+                                pg.saturating_add(1)
+                                    .saturating_mul(self.entries_count)
+                                    .saturating_sub(1),
+                            )))
+                            .await;
+                        self.presentation_state = PresentationState::Topic(t_id, Some(pg));
+                        self.posts = vec![];
+                    }
                 } else {
                     // TODO: wait a bit and try again
                     // How do we do that?
@@ -427,7 +470,7 @@ impl ForumLogic {
                         message,
                         Duration::from_secs(3),
                     ));
-                    eprintln!("Try again in 3 sec…)");
+                    log_debug!("Try again in 3 sec…)");
                 }
             }
             ToApp::ReadSuccess(_s_id, s_name, c_id, d_type, start_page, d_vec) => {
@@ -450,7 +493,7 @@ impl ForumLogic {
                     }
                     self.present().await;
                 } else {
-                    eprintln!("Received Content from other Swarm");
+                    log_debug!("Received Content from other Swarm");
                 }
             }
             // ToApp::FirstPages(s_id, fp_vec) => {
@@ -463,7 +506,7 @@ impl ForumLogic {
             // }
             ToApp::ContentChanged(s_id, c_id, d_type, first_page_opt) => {
                 if s_id == self.shell.swarm_id {
-                    eprintln!("ContentChanged for {c_id}");
+                    log_debug!("ContentChanged for {c_id}");
                     if let Some(first_page) = first_page_opt {
                         if c_id == 0 {
                             // TODO: this will crash once
@@ -475,7 +518,7 @@ impl ForumLogic {
                         }
                         self.present().await;
                     } else {
-                        eprintln!("But no first page!");
+                        log_debug!("But no first page!");
                         if c_id == 0 {
                             let _ = self
                                 .to_app_mgr_send
@@ -514,11 +557,11 @@ impl ForumLogic {
                         }
                     }
                 } else {
-                    eprintln!("ContentChanged for {s_id}, my_id:{}", self.shell.swarm_id);
+                    log_debug!("ContentChanged for {s_id}, my_id:{}", self.shell.swarm_id);
                 }
             }
             ToApp::RunningPolicies(mut policies) => {
-                eprintln!("#{} RunningPolicies in forum logic", policies.len());
+                log_debug!("#{} RunningPolicies in forum logic", policies.len());
                 let pol_page = 0;
 
                 // TODO: store how many entries there are in menu.
@@ -539,7 +582,7 @@ impl ForumLogic {
                     .send(ToForumView::RunningPoliciesPage(pol_page, pols));
             }
             ToApp::RunningCapabilities(mut caps) => {
-                eprintln!("#{} RunningCapabilities in forum logic", caps.len());
+                log_debug!("#{} RunningCapabilities in forum logic", caps.len());
                 let cs_page = 0;
 
                 // TODO: store how many entries there are in menu.
@@ -562,7 +605,7 @@ impl ForumLogic {
             ToApp::RunningByteSets(mut bsets) => {
                 //TODO
 
-                eprintln!("#{} RunningByteSets in forum logic", bsets.len());
+                log_debug!("#{} RunningByteSets in forum logic", bsets.len());
                 let bs_page = 0;
 
                 // TODO: store how many entries there are in menu.
@@ -573,11 +616,11 @@ impl ForumLogic {
                     let (bs_id, bs_list) = bsets.remove(0);
                     if bbsets.len() < self.entries_count as usize {
                         if bs_list.is_none() {
-                            bbsets.push((bs_id as u16, format!("Empty({bs_id})")));
+                            bbsets.push((bs_id as u16, trf("forum.empty_set", &[&bs_id])));
                         } else if bs_list.is_pair() {
-                            bbsets.push((bs_id as u16, format!("Pairs({bs_id})")));
+                            bbsets.push((bs_id as u16, trf("forum.pairs_set", &[&bs_id])));
                         } else {
-                            bbsets.push((bs_id as u16, format!("Bytes({bs_id})")));
+                            bbsets.push((bs_id as u16, trf("forum.bytes_set", &[&bs_id])));
                         }
                     }
                     mapping.insert(bs_id, bs_list);
@@ -594,12 +637,12 @@ impl ForumLogic {
                     self.process_first_pages(pg_vec).await;
                     self.present().await;
                 } else {
-                    eprintln!("FirstPages of other swarm");
+                    log_debug!("FirstPages of other swarm");
                 }
             }
             ToApp::NewContent(s_id, c_id, d_type, data) => {
                 //TODO
-                eprintln!("We need to process new content!");
+                log_debug!("We need to process new content!");
                 if s_id == self.shell.swarm_id {
                     self.append_first_page(c_id, d_type, data).await;
                     self.present().await;
@@ -607,13 +650,13 @@ impl ForumLogic {
             }
             ToApp::HeapData(s_id, app_msg, signed_by) => {
                 if s_id == self.shell.swarm_id {
-                    eprintln!("Forum recv Heap Data {}", app_msg.m_type());
+                    log_debug!("Forum recv Heap Data {}", app_msg.m_type());
                     let forum_msg = ForumSyncMessage::parse_app_msg(app_msg).unwrap();
                     if let Some((prev_msg, prev_sign)) =
                         std::mem::replace(&mut self.last_heap_msg, None)
                     {
                         if prev_msg == forum_msg && signed_by == prev_sign {
-                            eprintln!("Same heap as before…");
+                            log_debug!("Same heap as before…");
                             self.heap_logic(false).await;
                         }
                     } else {
@@ -621,12 +664,12 @@ impl ForumLogic {
                         self.heap_logic(false).await;
                     }
                 } else {
-                    eprintln!("Heap data of {} ignored", s_id);
+                    log_debug!("Heap data of {} ignored", s_id);
                 }
             }
             ToApp::HeapEmpty(s_id) => {
                 if s_id == self.shell.swarm_id {
-                    eprintln!("Forum recv HeapEmpty");
+                    log_debug!("Forum recv HeapEmpty");
                     self.last_heap_msg = None;
                     self.heap_logic(true).await;
                 }
@@ -638,7 +681,7 @@ impl ForumLogic {
                 self.process_search_results(query, is_permanent, hits).await;
             }
             _other => {
-                eprintln!("InternalMsg::User {:?}", _other);
+                log_debug!("InternalMsg::User {:?}", _other);
             }
         }
     }
@@ -713,18 +756,18 @@ impl ForumLogic {
                                 )))
                                 .await;
                         } else {
-                            eprintln!("Stored BS collecting");
+                            log_debug!("Stored BS collecting");
                             let bss = &self.shell.manifest.byteset_reg;
                             let mut id_str_vec = Vec::with_capacity(bss.len());
                             let mut hm = HashMap::with_capacity(bss.len());
                             for id in 0..=255 {
                                 if let Some(bs) = bss.get(&id) {
                                     let label = if bs.is_none() {
-                                        format!("Empty({})", id)
+                                        trf("forum.empty_set", &[&id])
                                     } else if bs.is_pair() {
-                                        format!("Pairs({})", id)
+                                        trf("forum.pairs_set", &[&id])
                                     } else {
-                                        format!("Bytes({})", id)
+                                        trf("forum.bytes_set", &[&id])
                                     };
                                     id_str_vec.push((id as u16, label));
                                     hm.insert(id, bs.clone());
@@ -754,7 +797,7 @@ impl ForumLogic {
                         );
                         match curr_state {
                             PresentationState::Topic(c_id, pg_opt) => {
-                                eprintln!("Action Edit when in Topic");
+                                log_debug!("Action Edit when in Topic");
                                 if let Some(page) = pg_opt {
                                     let post_id = (page * self.entries_count) + id;
                                     // TODO: read contents of post,
@@ -774,7 +817,7 @@ impl ForumLogic {
                                     // present them to user in writable editor
                                     // once finished editing send regular ChangeData msg (for now)
                                 } else {
-                                    eprintln!("Can not edit, unknown Page.");
+                                    log_warn!("Can not edit, unknown Page.");
                                     self.presentation_state =
                                         PresentationState::Topic(c_id, pg_opt);
                                 }
@@ -807,13 +850,13 @@ impl ForumLogic {
                                                         ))
                                                         .await;
                                                 } else {
-                                                    eprintln!(
+                                                    log_warn!(
                                                         "Can not update, Author: {} but edited by: {}",
                                                         curr_e.author, signer
                                                     );
                                                 }
                                             } else {
-                                                eprintln!(
+                                                log_warn!(
                                                     "Can not update, unable to validate Author",
                                                 );
                                             }
@@ -850,7 +893,7 @@ impl ForumLogic {
                                     .await;
                             }
                             _other => {
-                                eprintln!("Edit not supported in {:?}", _other);
+                                log_warn!("Edit not supported in {:?}", _other);
                             }
                         }
                         // Here we directly send a SyncMessage::UserDefined for testing
@@ -876,7 +919,7 @@ impl ForumLogic {
                         self.store_action(id).await;
                     }
                     Action::NextPage => {
-                        eprintln!("Action::NextPage ");
+                        log_debug!("Action::NextPage ");
                         if self.show_next_page().await {
                             self.present().await;
                         }
@@ -914,7 +957,7 @@ impl ForumLogic {
                             // open Editor and pass results to local filtering logic
 
                             let e_p = EditorParams {
-                                title: format!("Define Search text"),
+                                title: tr("forum.define_search"),
                                 initial_text: None,
                                 allow_newlines: true,
                                 chars_limit: None,
@@ -929,7 +972,7 @@ impl ForumLogic {
                     }
                     Action::MainMenu => {
                         //TODO
-                        eprintln!("Action::MainMenu presenting topics");
+                        log_debug!("Action::MainMenu presenting topics");
                         self.presentation_state = PresentationState::MainLobby(Some(0));
                         self.filter_topics().await;
                         // self.entries = vec![];
@@ -969,7 +1012,7 @@ impl ForumLogic {
                     Action::FollowLink(s_name, _c_id, _pg_id) => {
                         // Follow Link
                         if s_name.founder.is_any() {
-                            eprintln!("Should go back to Village");
+                            log_debug!("Should go back to Village");
                             *switch_to_opt = Some((
                                 AppType::Catalog,
                                 SwarmName::new(self.my_id, s_name.name).unwrap(),
@@ -1002,15 +1045,34 @@ impl ForumLogic {
         }
         false
     }
+    // Requests are shown in a ListView, so that they can be
+    // sorted and columns of original and modified entries line up
+    fn request_list(&self, rows: Vec<Vec<String>>) -> ListData {
+        let mut requests = ListData::new(vec![
+            Column::text(tr("column.request"), 10),
+            Column::text(tr("column.location"), 7),
+            Column::text(tr("column.entry"), 0),
+            Column::text(tr("column.author"), 20),
+        ]);
+        for row in rows {
+            requests.push(row);
+        }
+        requests
+    }
+
     async fn heap_logic(&mut self, heap_empty: bool) {
         // Remember to always set self.presentation_state (or rework logic)
         self.presentation_state = PresentationState::HeapSorting(None, None);
         if heap_empty {
             // TODO: send ToPresentation to inform User that heap is empty
-            let _ = self.to_tui_send.send(ToForumView::Request(vec![(
-                0,
-                format!("No Requests pending"),
-            )]));
+            let mut requests = self.request_list(vec![]);
+            requests.pin(vec![
+                String::new(),
+                String::new(),
+                tr("forum.no_requests"),
+                String::new(),
+            ]);
+            let _ = self.to_tui_send.send(ToForumView::Request(requests));
             return;
         }
         // let last_msg = std::mem::replace(&mut self.last_heap_msg, None);
@@ -1019,30 +1081,23 @@ impl ForumLogic {
                 PresentationState::HeapSorting(Some((app_msg.clone(), *signed_by)), None);
             match app_msg {
                 ForumSyncMessage::AddTopic(_t_id, entry) => {
-                    let _ = self.to_tui_send.send(ToForumView::Request(vec![(
-                        0,
-                        format!("Add Topic: {}", entry.entry_line(self.entry_max_len - 11)),
-                    )]));
+                    let rows = vec![request_row("forum.request_add_topic", String::new(), entry)];
+                    let _ = self
+                        .to_tui_send
+                        .send(ToForumView::Request(self.request_list(rows)));
                 }
                 ForumSyncMessage::AddPost(t_id, entry) => {
-                    let _ = self.to_tui_send.send(ToForumView::Request(vec![(
-                        0,
-                        format!(
-                            "{} Add Post: {}",
-                            t_id,
-                            entry.entry_line(self.entry_max_len - 15)
-                        ),
-                    )]));
+                    let rows = vec![request_row(
+                        "forum.request_add_post",
+                        t_id.to_string(),
+                        entry,
+                    )];
+                    let _ = self
+                        .to_tui_send
+                        .send(ToForumView::Request(self.request_list(rows)));
                 }
                 ForumSyncMessage::EditPost(t_id, p_id, entry) => {
-                    //TODO: do not send until we have both entries
-                    let _ = self.to_tui_send.send(ToForumView::Request(vec![
-                        (0, format!("Orig: Pending ({}-{})", t_id, p_id)),
-                        (
-                            1,
-                            format!("Modif: {}", entry.entry_line(self.entry_max_len - 7)),
-                        ),
-                    ]));
+                    // Request is shown once original entry is read
                     let _ = self
                         .to_app_mgr_send
                         .send(ToAppMgr::FromApp(dapp_lib::LibRequest::ReadPagesRange(
@@ -1072,8 +1127,8 @@ impl ForumLogic {
             PresentationState::Editing(Some(1), Box::new(PresentationState::Settings));
 
         let e_p = EditorParams {
-            title: format!("Add new Category (oneline, 32 bytes max)"),
-            initial_text: Some(format!("NewCategory")),
+            title: tr("forum.add_category"),
+            initial_text: Some(tr("forum.new_category")),
             allow_newlines: false,
             chars_limit: None,
             text_limit: Some(32),
@@ -1091,7 +1146,7 @@ impl ForumLogic {
             // eprintln!("process_first_pages EMPTY");
             return;
         }
-        eprintln!("process_first_pages");
+        log_debug!("process_first_pages");
         for (c_id, d_type, data) in pg_vec {
             self.process_content(c_id, d_type, 0, vec![data]).await;
         }
@@ -1107,7 +1162,7 @@ impl ForumLogic {
         if d_vec.is_empty() {
             return;
         }
-        eprintln!(
+        log_debug!(
             "Process CID{c_id} from:{start_page} len: {}",
             d_vec[0].len()
         );
@@ -1157,7 +1212,7 @@ impl ForumLogic {
                             }
                             // check if c_id is visible on screen,
                             if let Some(menu_page) = _pg_opt {
-                                eprintln!("{menu_page} MPS len: {}", self.menu_pages.len());
+                                log_debug!("{menu_page} MPS len: {}", self.menu_pages.len());
                                 if self.menu_pages[menu_page as usize].contains(&c_id) {
                                     // if so redraw it
                                     self.present().await;
@@ -1166,12 +1221,12 @@ impl ForumLogic {
                         }
                         PresentationState::Topic(_t_id, mut _pg_opt) => {
                             if c_id != _t_id {
-                                eprintln!("Not my topic");
+                                log_debug!("Not my topic");
                                 self.presentation_state = curr_state;
                                 return;
                             }
                             if _pg_opt.is_none() {
-                                eprintln!("Topic page unknown");
+                                log_warn!("Topic page unknown");
                                 // TODO: we need to also include filtering logic into the mix
                                 _pg_opt = Some(start_page / self.entries_count);
                                 // return;
@@ -1186,7 +1241,7 @@ impl ForumLogic {
                             self.presentation_state = PresentationState::Topic(_t_id, _pg_opt);
 
                             for (id, first) in d_vec.into_iter().enumerate() {
-                                eprintln!("Updating post {} {}", id, first);
+                                log_debug!("Updating post {} {}", id, first);
 
                                 self.update_post(
                                     id + (start_page as usize),
@@ -1202,7 +1257,7 @@ impl ForumLogic {
                                 // Some(String::from_utf8(d_vec[0].clone().bytes()).unwrap());
                                 Some(Entry::from_data(d_vec[0].clone(),p_id>0).unwrap().text);
                                     let e_p = EditorParams {
-                                        title: format!("Topic #{t_id}, Post #{start_page}"),
+                                        title: trf("forum.topic_post", &[&t_id, &start_page]),
                                         initial_text,
                                         allow_newlines: true,
                                         chars_limit: None,
@@ -1213,15 +1268,15 @@ impl ForumLogic {
                                     self.presentation_state =
                                         PresentationState::ShowingPost(t_id, start_page);
                                 } else {
-                                    eprintln!("Got wrong page to present");
+                                    log_debug!("Got wrong page to present");
                                 }
                             } else {
-                                eprintln!("Got wrong Topic to present");
+                                log_debug!("Got wrong Topic to present");
                             }
                         }
 
                         PresentationState::Editing(what_opt, prev_state) => {
-                            eprintln!("ReadSuccess in Editing State");
+                            log_debug!("ReadSuccess in Editing State");
                             // TODO: check if what we got matches what we want!
 
                             let new_what;
@@ -1231,7 +1286,7 @@ impl ForumLogic {
                                     // Some(String::from_utf8(d_vec[0].clone().bytes()).unwrap());
                                     Some(Entry::from_data(d_vec[0].clone(),start_page>0).unwrap().text);
                                 let e_p = EditorParams {
-                                    title: format!("Editing Post #{start_page}"),
+                                    title: trf("forum.editing_post", &[&start_page]),
                                     initial_text,
                                     allow_newlines: true,
                                     chars_limit: None,
@@ -1242,7 +1297,7 @@ impl ForumLogic {
                             } else {
                                 new_what = None;
                             }
-                            eprintln!("NewWhat: {new_what:?}");
+                            log_debug!("NewWhat: {new_what:?}");
                             self.presentation_state =
                                 PresentationState::Editing(new_what, prev_state);
                         }
@@ -1255,24 +1310,38 @@ impl ForumLogic {
                                             let orig_entry =
                                                 Entry::from_data(d_vec[0].clone(), *p_id > 0)
                                                     .unwrap();
-                                            let _ =
-                                                self.to_tui_send.send(ToForumView::Request(vec![
-                                                    (0, orig_entry.entry_line(self.entry_max_len)),
-                                                    (1, new_entry.entry_line(self.entry_max_len)),
-                                                ]));
+                                            let location = format!("{}-{}", t_id, p_id);
+                                            let rows = vec![
+                                                request_row(
+                                                    "forum.request_original",
+                                                    location.clone(),
+                                                    &orig_entry,
+                                                ),
+                                                request_row(
+                                                    "forum.request_modified",
+                                                    location,
+                                                    new_entry,
+                                                ),
+                                            ];
+                                            let _ = self.to_tui_send.send(ToForumView::Request(
+                                                self.request_list(rows),
+                                            ));
                                         } else {
-                                            eprintln!("Received wrong data for comparison");
-                                            eprintln!(
+                                            log_debug!("Received wrong data for comparison");
+                                            log_debug!(
                                                 "Expected: {}-{} got: {}-{}",
-                                                t_id, p_id, c_id, start_page
+                                                t_id,
+                                                p_id,
+                                                c_id,
+                                                start_page
                                             );
                                         }
                                     }
                                     ForumSyncMessage::AddTopic(_t_id, _entry) => {
-                                        eprintln!("Unexpected Read Success, when adding new topic");
+                                        log_warn!("Unexpected Read Success, when adding new topic");
                                     }
                                     ForumSyncMessage::AddPost(_t_id, _entry) => {
-                                        eprintln!("Unexpected Read Success, when adding a post");
+                                        log_warn!("Unexpected Read Success, when adding a post");
                                     }
                                 }
                                 let entry =
@@ -1282,7 +1351,7 @@ impl ForumLogic {
                                     Some(entry),
                                 );
                             } else {
-                                eprintln!("Unexpected Read Success, when HeapSorting empty");
+                                log_warn!("Unexpected Read Success, when HeapSorting empty");
                                 self.presentation_state =
                                     PresentationState::HeapSorting(None, entry_opt);
                             }
@@ -1302,7 +1371,7 @@ impl ForumLogic {
                                     Some(1013 - 256)
                                 };
                                 let e_p = EditorParams {
-                                    title: format!("Edit Description for CID{}", t_id),
+                                    title: trf("forum.edit_cid_description", &[&t_id]),
                                     initial_text,
 
                                     allow_newlines: true,
@@ -1316,16 +1385,16 @@ impl ForumLogic {
                         }
                         PresentationState::Fitlering => {
                             // TODO: define local filtering logic for current swarm only
-                            eprintln!("TODO: Got some pages when Filtering");
+                            log_warn!("TODO: Got some pages when Filtering");
                             self.presentation_state = PresentationState::Fitlering;
                         }
                         other => {
-                            eprintln!("Unexpected ReadSuccess when in state: {:?}", other);
+                            log_warn!("Unexpected ReadSuccess when in state: {:?}", other);
                             self.presentation_state = other;
                         }
                     }
                 } else {
-                    eprintln!("Unsupportetd Forum DType: {id}");
+                    log_debug!("Unsupportetd Forum DType: {id}");
                 }
             }
             DataType::Link => {
@@ -1373,11 +1442,11 @@ impl ForumLogic {
         if !last_page.is_empty() || self.menu_pages.is_empty() {
             self.menu_pages.push(last_page);
         }
-        eprintln!("Extended mpl: {}", self.menu_pages.len());
+        log_debug!("Extended mpl: {}", self.menu_pages.len());
     }
 
     fn process_manifest(&mut self, d_type: DataType, mut d_vec: Vec<Data>) {
-        eprintln!("Mfest DType: {:?}", d_type);
+        log_debug!("Mfest DType: {:?}", d_type);
         if d_vec.len() == 1 {
             //TODO: only first page has changed, other pagas are same as previous
             // so we take them from our shell Manifest
@@ -1387,10 +1456,10 @@ impl ForumLogic {
         }
         let manifest = Manifest::from(d_vec);
         let text = if manifest.description.is_empty() {
-            format!("Manifest: no description")
+            tr("forum.manifest_no_description")
         } else {
             let line: String = manifest.description.lines().take(1).collect();
-            line.chars().take(64).collect()
+            truncate(&line, 64)
         };
         let desc = Entry::new(
             self.shell.swarm_name.founder,
@@ -1415,7 +1484,7 @@ impl ForumLogic {
     async fn present(&mut self) {
         let curr_state =
             std::mem::replace(&mut self.presentation_state, PresentationState::Settings);
-        eprintln!("present, state: {curr_state:?}, {}", self.all_topics.len());
+        log_debug!("present, state: {curr_state:?}, {}", self.all_topics.len());
         match curr_state {
             PresentationState::MainLobby(pg_opt) => {
                 // TODO: here we need to include effects of filtering logic
@@ -1447,7 +1516,7 @@ impl ForumLogic {
                 }
                 let mut posts = self.read_posts(pg, self.entries_count).await;
                 if posts.is_empty() {
-                    eprintln!("No posts read for page: {pg}, showing first page.");
+                    log_debug!("No posts read for page: {pg}, showing first page.");
                     pg = 0;
                     posts = self.read_posts(pg, self.entries_count).await;
                 }
@@ -1455,13 +1524,13 @@ impl ForumLogic {
                 self.presentation_state = PresentationState::Topic(t_id, Some(pg));
             }
             other => {
-                eprintln!("Not presenting, state is: {other:?}");
+                log_debug!("Not presenting, state is: {other:?}");
                 self.presentation_state = other;
             }
         }
     }
     async fn read_posts(&self, page: u16, page_size: u16) -> Vec<(u16, String)> {
-        eprintln!("read_posts page: {page}, p_size:{page_size}");
+        log_debug!("read_posts page: {page}, p_size:{page_size}");
         let first_idx;
         let last_idx;
         if page == u16::MAX {
@@ -1504,7 +1573,7 @@ impl ForumLogic {
                 //     read_only: false,
                 // };
                 // let _ = self.to_tui_send.send(ToForumView::OpenEditor(e_p));
-                eprintln!("We should add a new topic");
+                log_debug!("We should add a new topic");
                 let tag_names = self.shell.manifest.tag_names(None);
                 let _ = self
                     .to_tui_send
@@ -1521,8 +1590,8 @@ impl ForumLogic {
             }
             PresentationState::Topic(c_id, pg_opt) => {
                 let e_p = EditorParams {
-                    title: format!("Adding new Post"),
-                    initial_text: Some(format!("Adding new post…")),
+                    title: tr("forum.adding_post"),
+                    initial_text: Some(tr("forum.adding_post_text")),
 
                     allow_newlines: true,
                     chars_limit: None,
@@ -1530,7 +1599,7 @@ impl ForumLogic {
                     read_only: false,
                 };
                 let _ = self.to_tui_send.send(ToForumView::OpenEditor(e_p));
-                eprintln!("We should add a new post");
+                log_debug!("We should add a new post");
                 // self.presentation_state = PresentationState::MainLobby(page_opt);
                 self.presentation_state = PresentationState::Editing(
                     None,
@@ -1545,7 +1614,7 @@ impl ForumLogic {
                 };
 
                 let e_p = EditorParams {
-                    title: format!("Adding new Capability"),
+                    title: tr("forum.adding_capability"),
                     initial_text,
                     allow_newlines: false,
                     chars_limit: Some("0123456789abcdef".chars().collect()),
@@ -1652,7 +1721,7 @@ impl ForumLogic {
                     PresentationState::CreatingByteSet(options, is_run, existing_opt);
             }
             other => {
-                eprintln!("Adding not supported yet");
+                log_warn!("Adding not supported yet");
                 self.presentation_state = other;
             }
         }
@@ -1689,7 +1758,7 @@ impl ForumLogic {
                 // eprintln!("TODO: we should run a ByteSet({})", id_opt.unwrap());
             }
             _other => {
-                eprintln!("Can not run_action ");
+                log_warn!("Can not run_action ");
             }
         }
         yield_now().await;
@@ -1699,10 +1768,10 @@ impl ForumLogic {
             PresentationState::Capability(cap, gnome_ids) => {
                 let mut c_tree = CapabiLeaf::create();
                 for gnome_id in gnome_ids {
-                    eprintln!("adding {} to cap", gnome_id);
+                    log_debug!("adding {} to cap", gnome_id);
                     c_tree.insert(*gnome_id);
                 }
-                eprintln!(
+                log_debug!(
                     "After adding ctree size: {}",
                     c_tree.get_all_members().len()
                 );
@@ -1727,7 +1796,7 @@ impl ForumLogic {
                             .byteset_reg
                             .insert(_id as u8, bs.clone());
                         let m_data = self.shell.manifest.to_data();
-                        eprintln!("Sending Store BS request");
+                        log_debug!("Sending Store BS request");
                         let _ = self
                             .to_app_mgr_send
                             .send(ToAppMgr::ChangeContent(
@@ -1739,7 +1808,7 @@ impl ForumLogic {
                             .await;
                     }
                 } else {
-                    eprintln!("Can not store, can not figure what");
+                    log_warn!("Can not store, can not figure what");
                 }
             }
             _other => {
@@ -1778,18 +1847,18 @@ impl ForumLogic {
                     .await;
             }
             other => {
-                eprintln!("Deleting not supported yet");
+                log_warn!("Deleting not supported yet");
                 self.presentation_state = other;
             }
         }
     }
     async fn show_first_page(&mut self) -> bool {
         let mut did_page_change = false;
-        eprintln!("show_first_page");
+        log_debug!("show_first_page");
         let mut new_state = None;
         match &self.presentation_state {
             PresentationState::MainLobby(pg_opt) => {
-                eprintln!("show_first_page MainLobby {pg_opt:?}");
+                log_debug!("show_first_page MainLobby {pg_opt:?}");
                 // if let Some(pg) = pg_opt {
                 // TODO: maybe set new pgid?
                 let next_pg = 0;
@@ -1813,7 +1882,7 @@ impl ForumLogic {
                 // self.present().await;
             }
             PresentationState::Topic(t_id, pg_opt) => {
-                eprintln!("show_first_page Topic {pg_opt:?}");
+                log_debug!("show_first_page Topic {pg_opt:?}");
                 // if let Some(pg) = pg_opt {
                 // TODO: maybe set new pgid?
                 let next_pg = 0;
@@ -1849,11 +1918,11 @@ impl ForumLogic {
 
     async fn show_last_page(&mut self) -> bool {
         let mut did_page_change = false;
-        eprintln!("show_last_page");
+        log_debug!("show_last_page");
         let mut new_state = None;
         match &self.presentation_state {
             PresentationState::MainLobby(pg_opt) => {
-                eprintln!("show_last_page MainLobby {pg_opt:?}");
+                log_debug!("show_last_page MainLobby {pg_opt:?}");
                 // if let Some(pg) = pg_opt {
                 // TODO: maybe set new pgid?
                 let next_pg = u16::MAX;
@@ -1877,7 +1946,7 @@ impl ForumLogic {
                 // self.present().await;
             }
             PresentationState::Topic(t_id, pg_opt) => {
-                eprintln!("show_last_page Topic {pg_opt:?}");
+                log_debug!("show_last_page Topic {pg_opt:?}");
                 // if let Some(pg) = pg_opt {
                 // TODO: maybe set new pgid?
                 let next_pg = u16::MAX;
@@ -1912,11 +1981,11 @@ impl ForumLogic {
     }
     async fn show_next_page(&mut self) -> bool {
         let mut page_changed = false;
-        eprintln!("show_next_page");
+        log_debug!("show_next_page");
         let mut new_state = None;
         match &self.presentation_state {
             PresentationState::MainLobby(pg_opt) => {
-                eprintln!(
+                log_debug!(
                     "show_next_page MainLobby {pg_opt:?} mpl:{}",
                     self.menu_pages.len()
                 );
@@ -1949,7 +2018,7 @@ impl ForumLogic {
                 // self.present().await;
             }
             PresentationState::Topic(t_id, pg_opt) => {
-                eprintln!("show_next_page Topic {pg_opt:?}");
+                log_debug!("show_next_page Topic {pg_opt:?}");
                 if let Some(pg) = pg_opt {
                     // TODO: maybe set new pgid?
                     let next_pg = pg.saturating_add(1);
@@ -2052,7 +2121,7 @@ impl ForumLogic {
         //
         match curr_state {
             PresentationState::Editing(id, prev_state) => {
-                eprintln!("State Editing: {id:?}");
+                log_debug!("State Editing: {id:?}");
                 match *prev_state {
                     PresentationState::Capability(cap, mut vec_gid) => {
                         let id = if let Some(i) = id {
@@ -2070,7 +2139,7 @@ impl ForumLogic {
                                 }
                             }
                         } else {
-                            eprintln!("Got nothing from Editor");
+                            log_debug!("Got nothing from Editor");
                         }
                         let v_len = vec_gid.len();
                         // let items_per_page: usize = 10;
@@ -2094,7 +2163,7 @@ impl ForumLogic {
                     PresentationState::MainLobby(page_opt) => {
                         self.presentation_state = PresentationState::MainLobby(page_opt);
                         if let EditorResult::Text(topic_desc) = ed_res {
-                            eprintln!("We should add a new topic:\n{topic_desc}");
+                            log_debug!("We should add a new topic:\n{topic_desc}");
                             // let can_directly_append = false;
                             let entry = Entry::new(self.my_id, vec![], topic_desc, 0);
                             // if can_directly_append {
@@ -2126,7 +2195,7 @@ impl ForumLogic {
                             // We distinguish between what to do by value of id
                             if None == id {
                                 //TODO: we have an updated Manifest descr.
-                                eprintln!("New descr: {}", text);
+                                log_debug!("New descr: {}", text);
                                 self.shell.manifest.set_description(text);
                                 let d_vec = self.shell.manifest.to_data();
                                 let _ = self
@@ -2141,7 +2210,7 @@ impl ForumLogic {
                                 self.presentation_state = PresentationState::MainLobby(Some(0));
                                 self.present().await;
                             } else if Some(1) == id {
-                                eprintln!("Adding new Category: {text}");
+                                log_debug!("Adding new Category: {text}");
                                 let tag = Tag::new(text).unwrap();
                                 self.shell.manifest.add_tags(vec![tag]);
                                 let d_vec = self.shell.manifest.to_data();
@@ -2164,7 +2233,7 @@ impl ForumLogic {
                             let entry = Entry::new(self.my_id, vec![], text, 0);
                             if let Some(id) = id {
                                 // TODO: check if current user can edit given post
-                                eprintln!("Should update {id:?}");
+                                log_debug!("Should update {id:?}");
                                 // let can_directly_edit = false;
                                 // if can_directly_edit {
                                 // let data = entry.into_data(id > 0).unwrap();
@@ -2197,7 +2266,7 @@ impl ForumLogic {
                                 // This simplifies Application logic and does not introduce
                                 // another policy verification logic on application level.
                                 //
-                                eprintln!("Should append a post {id:?}");
+                                log_debug!("Should append a post {id:?}");
                                 // let can_directly_append = false;
                                 // if can_directly_append {
                                 // let data = entry.into_data(true).unwrap();
@@ -2219,7 +2288,7 @@ impl ForumLogic {
                                 // }
                             }
                         } else {
-                            eprintln!("Ignore, empty text");
+                            log_debug!("Ignore, empty text");
                         }
 
                         self.presentation_state = PresentationState::Topic(c_id, pg_opt);
@@ -2233,15 +2302,15 @@ impl ForumLogic {
                             if let EditorResult::Text(text) = ed_res {
                                 //TODO:
                                 if (tag_id as usize) < t_names.len() {
-                                    eprintln!("Editing Tag({tag_id}): {text}");
+                                    log_debug!("Editing Tag({tag_id}): {text}");
                                     t_names[tag_id as usize] = text;
                                 } else {
-                                    eprintln!("Adding Tag({tag_id}): {text}");
+                                    log_debug!("Adding Tag({tag_id}): {text}");
                                     t_names.push(text);
                                 }
                             }
                         } else {
-                            eprintln!("Unexpected value for id: {id:?}");
+                            log_warn!("Unexpected value for id: {id:?}");
                         }
                         let new_ctx = TopicContext {
                             t_id: t_ctx.t_id,
@@ -2253,7 +2322,7 @@ impl ForumLogic {
                         let _ = self.to_tui_send.send(ToForumView::OpenCreator(new_ctx));
                     }
                     other => {
-                        eprintln!("Editing not supported yet");
+                        log_warn!("Editing not supported yet");
                         self.presentation_state = other;
                     }
                 }
@@ -2324,7 +2393,7 @@ impl ForumLogic {
                         .await;
                 }
                 EditorResult::Text(text) => {
-                    eprintln!("Unexpected text, when showing read-only post: {}", text);
+                    log_warn!("Unexpected text, when showing read-only post: {}", text);
                 }
             },
             PresentationState::TopicEditing(t_ctx) => {
@@ -2372,18 +2441,18 @@ impl ForumLogic {
                 self.present().await;
             }
             other => {
-                eprintln!("Edit result when in state: {:?}", other);
+                log_debug!("Edit result when in state: {:?}", other);
                 self.presentation_state = other;
             }
         }
     }
     async fn process_creator_result(&mut self, c_res: CreatorResult) {
-        eprintln!("in process_creator_result, {c_res:?}");
+        log_debug!("in process_creator_result, {c_res:?}");
         if let PresentationState::TopicEditing(_tctx) = &self.presentation_state {
             // self.presentation_state = PresentationState::TopicEditing(_tctx);
             match c_res {
                 CreatorResult::SelectDType => {
-                    eprintln!("Should select DType (always Topic…)");
+                    log_debug!("Should select DType (always Topic…)");
                     // Right now we will ignore thiss call, since we only have Topic DType
                     let _ = self
                         .to_tui_send
@@ -2394,16 +2463,16 @@ impl ForumLogic {
                     if let Some(t_id) = _tctx.t_id {
                         if t_id == 0 {
                             if names.len() < 256 {
-                                names.push(format!("+ Add new…"));
+                                names.push(tr("forum.add_new"));
                             }
-                            eprintln!("All Tags: {names:?}");
+                            log_debug!("All Tags: {names:?}");
                             let _ = self.to_tui_send.send(ToForumView::Select(
                                 t_id == 0,
                                 names,
                                 vec![],
                             ));
                         } else {
-                            eprintln!("Should select Tags from: {names:?}");
+                            log_debug!("Should select Tags from: {names:?}");
                             let _ = self.to_tui_send.send(ToForumView::Select(
                                 t_id == 0,
                                 names,
@@ -2432,9 +2501,9 @@ impl ForumLogic {
                                 )))
                                 .await;
                         } else {
-                            eprintln!("Edit Forum's description");
+                            log_debug!("Edit Forum's description");
                             let e_p = EditorParams {
-                                title: format!("Edit Forum's description"),
+                                title: tr("forum.edit_description"),
                                 initial_text: Some(_tctx.description.clone()),
 
                                 allow_newlines: true,
@@ -2445,9 +2514,9 @@ impl ForumLogic {
                             let _ = self.to_tui_send.send(ToForumView::OpenEditor(e_p));
                         }
                     } else {
-                        eprintln!("Should set description");
+                        log_debug!("Should set description");
                         let e_p = EditorParams {
-                            title: format!("Adding new Topic"),
+                            title: tr("forum.adding_topic"),
                             initial_text: Some(_tctx.description.clone()),
 
                             allow_newlines: true,
@@ -2462,13 +2531,13 @@ impl ForumLogic {
                     if _tctx.t_id.is_some() {
                         let t_id = _tctx.t_id.unwrap();
                         if t_id == 0 {
-                            eprintln!("Should edit Forum's Manifest",);
+                            log_debug!("Should edit Forum's Manifest",);
                             //TODO: support Editing Categories
                             let mut manifest = self.shell.manifest.clone();
                             manifest.set_description(_tctx.description.clone());
                             let mut new_tags = vec![];
                             for i in manifest.tags.len().._tctx.tag_names.len() {
-                                eprintln!("Adding Tag: {}", _tctx.tag_names[i]);
+                                log_debug!("Adding Tag: {}", _tctx.tag_names[i]);
                                 new_tags.push(Tag::new(_tctx.tag_names[i].clone()).unwrap());
                             }
                             if !new_tags.is_empty() {
@@ -2489,7 +2558,7 @@ impl ForumLogic {
                                 .await;
                             self.presentation_state = PresentationState::MainLobby(Some(0));
                         } else {
-                            eprintln!("Should edit existing Topic {}", t_id);
+                            log_debug!("Should edit existing Topic {}", t_id);
                             let mut tags_u8 = Vec::with_capacity(_tctx.tags.len());
                             for t in &_tctx.tags {
                                 tags_u8.push(*t as u8);
@@ -2510,7 +2579,7 @@ impl ForumLogic {
                         }
                         self.present().await;
                     } else {
-                        eprintln!("Should create new Topic");
+                        log_debug!("Should create new Topic");
                         let prev_state = std::mem::replace(
                             &mut self.presentation_state,
                             PresentationState::MainLobby(Some(0)),
@@ -2551,7 +2620,7 @@ impl ForumLogic {
                     }
                 }
                 CreatorResult::Cancel => {
-                    eprintln!("Should cancel");
+                    log_debug!("Should cancel");
                     if let Some(t_id) = _tctx.t_id {
                         if t_id > 0 {
                             self.presentation_state = PresentationState::Topic(t_id, Some(0));
@@ -2568,7 +2637,7 @@ impl ForumLogic {
     }
 
     async fn serve_pol_action(&mut self, pa: PolAction) {
-        eprintln!("PolAction: {:?}", pa);
+        log_debug!("PolAction: {:?}", pa);
         match pa {
             PolAction::SelectPolicy => {
                 let curr_state = std::mem::replace(
@@ -2611,12 +2680,12 @@ impl ForumLogic {
                 }
             }
             PolAction::Store(p, r) => {
-                eprintln!("PolAction::Store");
+                log_debug!("PolAction::Store");
                 let r_res = r.requirement();
                 if let Ok(r) = r_res {
                     self.store_policy(p, r).await;
                 } else {
-                    eprintln!("Failed to build Requirements");
+                    log_warn!("Failed to build Requirements");
                 }
                 let _ = self.to_tui_send.send(ToForumView::TopicsPage(0, vec![]));
                 self.presentation_state = PresentationState::MainLobby(Some(0));
@@ -2624,13 +2693,13 @@ impl ForumLogic {
                 // running & stored Policy at once
             }
             PolAction::Run(p, r) => {
-                eprintln!("PolAction::Run");
+                log_debug!("PolAction::Run");
                 let r_res = r.requirement();
                 if let Ok(r) = r_res {
                     self.run_policy(p, r).await;
                 } else {
                     // TODO: Notify user
-                    eprintln!("Failed to build Requirements");
+                    log_warn!("Failed to build Requirements");
                 }
                 let _ = self.to_tui_send.send(ToForumView::TopicsPage(0, vec![]));
                 self.presentation_state = PresentationState::MainLobby(Some(0));
@@ -2641,7 +2710,7 @@ impl ForumLogic {
         // TODO: this can not stay here, since then we do not cover cases
         // when no element was selected. We should reset presentation_state then
         // and possibly send something to presentation
-        eprintln!("Selected ids: {:?}", ids);
+        log_debug!("Selected ids: {:?}", ids);
         let curr_state = std::mem::replace(
             &mut self.presentation_state,
             PresentationState::MainLobby(Some(0)),
@@ -2649,7 +2718,7 @@ impl ForumLogic {
         match curr_state {
             PresentationState::SelectingOneCapability(caps, prev_state) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 let id = ids[0];
@@ -2661,7 +2730,7 @@ impl ForumLogic {
                             let key = mapping.len() as u16;
                             if mapping.contains_key(&key) {
                                 //TODO: fix this
-                                eprintln!("Ups, we need to find a key");
+                                log_debug!("Ups, we need to find a key");
                             } else {
                                 mapping.insert(key, (new_cap, vec![]));
                             }
@@ -2679,7 +2748,7 @@ impl ForumLogic {
                                 ccaps.push((*i, cap.text()));
                             }
                         }
-                        eprintln!("CCaps: {:?}", ccaps);
+                        log_debug!("CCaps: {:?}", ccaps);
                         let _ = self
                             .to_tui_send
                             .send(ToForumView::RunningCapabilitiesPage(c_id, ccaps));
@@ -2707,14 +2776,14 @@ impl ForumLogic {
                     //     // maybe opt_id can be the judge which one it is.
                     // }
                     other => {
-                        eprintln!("Selecting not supported");
+                        log_warn!("Selecting not supported");
                         self.presentation_state = other;
                     }
                 }
             }
             PresentationState::SelectingOnePolicy(p_vec, r_tree) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 let id = ids[0];
@@ -2725,23 +2794,23 @@ impl ForumLogic {
             }
             PresentationState::SelectingOneRequirement(req_vec, pol, mut r_tree) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 let id = ids[0];
-                eprintln!("should put req at: {:?}", r_tree.mark_location());
+                log_debug!("should put req at: {:?}", r_tree.mark_location());
                 let r = req_vec[id].clone();
                 if r_tree.replace_mark(r) {
                     self.presentation_state =
                         PresentationState::Pyramid(pol.clone(), r_tree.clone());
                     let _ = self.to_tui_send.send(ToForumView::ShowPolicy(pol, r_tree));
                 } else {
-                    eprintln!("Did not find a Marker to replace");
+                    log_warn!("Did not find a Marker to replace");
                 }
             }
             PresentationState::CreatingByteSet(opts, is_run, exist_opt) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 // let id = ids[0];
@@ -2750,14 +2819,14 @@ impl ForumLogic {
                     for id in ids {
                         hset.insert(id as u8);
                     }
-                    eprintln!("We have a ByteSet");
+                    log_debug!("We have a ByteSet");
                     ByteSet::Bytes(hset)
                 } else {
                     let mut hset = HashSet::with_capacity(ids.len());
                     for id in ids {
                         hset.insert(id as u16);
                     }
-                    eprintln!("We have a Pair ByteSet");
+                    log_debug!("We have a Pair ByteSet");
                     ByteSet::Pairs(hset)
                 };
                 let (id, mut h_map) = if let Some((i, s)) = exist_opt {
@@ -2780,9 +2849,9 @@ impl ForumLogic {
                 for (id, bset) in &h_map {
                     if bbsets.len() < self.entries_count as usize {
                         if bset.is_pair() {
-                            bbsets.push((*id as u16, format!("PairSet({id})")));
+                            bbsets.push((*id as u16, trf("forum.pair_set", &[&id])));
                         } else {
-                            bbsets.push((*id as u16, format!("ByteSet({id})")));
+                            bbsets.push((*id as u16, trf("forum.byte_set", &[&id])));
                         }
                     }
                 }
@@ -2802,11 +2871,11 @@ impl ForumLogic {
             }
             PresentationState::ByteSets(is_run, mapping_opt) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 // let id = ids[0];
-                eprintln!("Got Selected while in ByteSets");
+                log_debug!("Got Selected while in ByteSets");
                 if let Some((id, mut hm)) = mapping_opt {
                     if let Some(bset) = hm.get_mut(&id) {
                         //TODO: update contents of ByteSet
@@ -2833,7 +2902,7 @@ impl ForumLogic {
             }
             PresentationState::TopicEditing(t_ctx) => {
                 if ids.is_empty() {
-                    eprintln!("No item was selected!");
+                    log_debug!("No item was selected!");
                     return;
                 }
                 let id = ids[0];
@@ -2888,10 +2957,10 @@ impl ForumLogic {
                     let initial_text = if id < t_ctx.tag_names.len() {
                         Some(t_ctx.tag_names[id].clone())
                     } else {
-                        Some(format!("NewCategory"))
+                        Some(tr("forum.new_category"))
                     };
                     let e_params = EditorParams {
-                        title: format!("Add new Category (oneline, 32 bytes max)"),
+                        title: tr("forum.add_category"),
                         initial_text,
                         allow_newlines: false,
                         chars_limit: None,
@@ -2928,13 +2997,13 @@ impl ForumLogic {
         }
     }
     async fn serve_query(&mut self, id: u16) {
-        eprintln!("Logic got a query {:?} ({:?})", id, self.presentation_state);
+        log_debug!("Logic got a query {:?} ({:?})", id, self.presentation_state);
         let mut new_state = None;
         match &self.presentation_state {
             PresentationState::MainLobby(page_opt) => {
                 //TODO: retrieve a Topic and present it to user
                 if page_opt.is_none() {
-                    eprintln!("Unable to tell which page is shown (1)");
+                    log_warn!("Unable to tell which page is shown (1)");
                     return;
                 }
                 let _page = page_opt.unwrap();
@@ -2987,7 +3056,7 @@ impl ForumLogic {
                         )))
                         .await;
                     self.presentation_state = PresentationState::Topic(topic_id, Some(0));
-                    eprintln!("cleaning topics…");
+                    log_debug!("cleaning topics…");
                     self.posts = vec![];
                     // TODO: instead of opening Editor
                     // switch to selected Topic Menu
@@ -3017,7 +3086,7 @@ impl ForumLogic {
                 // Once received open Editor
                 // in ReadOnly mode.
                 if let Some(_page_nr) = page_opt {
-                    eprintln!("Topic {c_id} P#{_page_nr} ID: {id}");
+                    log_debug!("Topic {c_id} P#{_page_nr} ID: {id}");
                     // let d_id = page_nr * self.entries_count + id;
                     let d_id = id;
                     if d_id == 0 {
@@ -3029,7 +3098,7 @@ impl ForumLogic {
                         //         description: description.clone(),
                         //         tags: tags.clone(),
                         //     }));
-                        eprintln!("NS TopicEditing");
+                        log_debug!("NS TopicEditing");
 
                         let mut tags_usize = Vec::with_capacity(self.posts[0].tags.len());
                         for t in self.posts[0].tags.iter() {
@@ -3064,20 +3133,20 @@ impl ForumLogic {
                                 d_id,
                             )))
                             .await;
-                        eprintln!("NS ShowingPost");
+                        log_debug!("NS ShowingPost");
                         new_state = Some(PresentationState::ShowingPost(*c_id, d_id));
                     }
                 } else {
-                    eprintln!("Got a Query in Topics,no page");
+                    log_debug!("Got a Query in Topics,no page");
                 }
             }
             PresentationState::Settings => {
                 // TODO: when we get Query in settings
                 // it means User wants to change Manifest
                 // description
-                eprintln!("Should edit Manifest Descr.");
+                log_debug!("Should edit Manifest Descr.");
                 let e_params = EditorParams {
-                    title: format!("Editing Forum's description"),
+                    title: tr("forum.editing_description"),
                     initial_text: Some(self.shell.manifest.description.clone()),
                     allow_newlines: true,
                     chars_limit: None,
@@ -3102,10 +3171,10 @@ impl ForumLogic {
                             .to_tui_send
                             .send(ToForumView::ShowPolicy(p.clone(), r_tree));
                     } else {
-                        eprintln!("Did not find running policy for {id}");
+                        log_warn!("Did not find running policy for {id}");
                     }
                 } else {
-                    eprintln!("Unable to tell which page is shown (2)");
+                    log_warn!("Unable to tell which page is shown (2)");
                     return;
                 };
                 // Here we should also store running policies in
@@ -3117,10 +3186,10 @@ impl ForumLogic {
                 //TODO: get Capability & show it to user
                 // let items_per_page = 10;
                 if r_caps.is_none() {
-                    eprintln!("Unable to tell which page is shown (3)");
+                    log_warn!("Unable to tell which page is shown (3)");
                     return;
                 }
-                eprintln!("Got non-empty Caps page");
+                log_debug!("Got non-empty Caps page");
                 if let Some((_page_no, mapping)) = r_caps {
                     if let Some((c, v_gid)) = mapping.get(&id) {
                         let v_len = v_gid.len();
@@ -3149,17 +3218,17 @@ impl ForumLogic {
             PresentationState::ByteSets(is_run, page_opt) => {
                 //TODO: get ByteSet & show it to user
                 if page_opt.is_none() {
-                    eprintln!("Unable to tell which page is shown (4)");
+                    log_warn!("Unable to tell which page is shown (4)");
                     return;
                 }
-                eprintln!("Got non-empty BSets page");
+                log_debug!("Got non-empty BSets page");
                 if let Some((_i, hm)) = page_opt {
                     if let Some(bset) = hm.get(&(id as u8)) {
                         let (strings, preselected) = if bset.is_pair() {
                             let mut strings = vec![];
                             let mut preselected = vec![];
                             for p in 0..=u16::MAX {
-                                strings.push(format!("Pair {p}"));
+                                strings.push(trf("forum.pair", &[&p]));
                                 if bset.contains_pair(&p) {
                                     preselected.push(p as usize);
                                 }
@@ -3169,7 +3238,7 @@ impl ForumLogic {
                             let mut strings = vec![];
                             let mut preselected = vec![];
                             for p in 0..=255 {
-                                strings.push(format!("Byte {p}"));
+                                strings.push(trf("forum.byte", &[&p]));
                                 if bset.contains(&p) {
                                     preselected.push(p as usize);
                                 }
@@ -3209,7 +3278,7 @@ impl ForumLogic {
                 let cap = Capabilities::from(id as u8);
                 if let Some(c_tree) = self.shell.manifest.capability_reg.get(&cap) {
                     let all_members = c_tree.get_all_members();
-                    eprintln!("Members #:{}", all_members.len());
+                    log_debug!("Members #:{}", all_members.len());
                     let mut mem_enum = Vec::with_capacity(all_members.len());
                     for (i, member) in all_members.iter().enumerate() {
                         mem_enum.push((i as u16, format!("GID-{:016x}", member.0)));
@@ -3244,16 +3313,16 @@ impl ForumLogic {
             // }
             PresentationState::SelectingOnePolicy(_pol_vec, _req) => {
                 //TODO
-                eprintln!("serve queue while in SelectingOnePolicy");
+                log_debug!("serve queue while in SelectingOnePolicy");
             }
             PresentationState::SelectingOneRequirement(_req_vec, _pol, _r_tree) => {
-                eprintln!("serve queue while in SelectingOneRequirement");
+                log_debug!("serve queue while in SelectingOneRequirement");
             }
             PresentationState::Pyramid(_pol, _req) => {
-                eprintln!("serve queue while in Pyramid");
+                log_debug!("serve queue while in Pyramid");
             }
             PresentationState::Capability(c, v_gid) => {
-                eprintln!("TODO: We should open an Editor with Selected GID");
+                log_warn!("TODO: We should open an Editor with Selected GID");
                 // new_state = Some(PresentationState::Capability(c.clone(), v_gid.clone()));
 
                 let g_id = v_gid[id as usize];
@@ -3264,7 +3333,7 @@ impl ForumLogic {
                 };
 
                 let e_p = EditorParams {
-                    title: format!("Adding new Capability"),
+                    title: tr("forum.adding_capability"),
                     initial_text,
                     allow_newlines: false,
                     chars_limit: Some("0123456789abcdef".chars().collect()),
@@ -3284,7 +3353,7 @@ impl ForumLogic {
                             // TODO
                             if id == 1 {
                                 let e_p = EditorParams {
-                                    title: format!("Reviewing modified Post"),
+                                    title: tr("forum.reviewing_modified_post"),
                                     initial_text: Some(entry.text.clone()),
                                     allow_newlines: false,
                                     chars_limit: None,
@@ -3302,7 +3371,7 @@ impl ForumLogic {
                             } else if id == 0 {
                                 if let Some(entry) = entry_opt {
                                     let e_p = EditorParams {
-                                        title: format!("Viewing original Post"),
+                                        title: tr("forum.viewing_original_post"),
                                         initial_text: Some(entry.text.clone()),
                                         allow_newlines: false,
                                         chars_limit: None,
@@ -3331,7 +3400,7 @@ impl ForumLogic {
                         ForumSyncMessage::AddTopic(_t_id, entry) => {
                             if id == 0 {
                                 let e_p = EditorParams {
-                                    title: format!("Reviewing new Topic"),
+                                    title: tr("forum.reviewing_new_topic"),
                                     initial_text: Some(entry.text.clone()),
                                     allow_newlines: false,
                                     chars_limit: None,
@@ -3340,13 +3409,13 @@ impl ForumLogic {
                                 };
                                 let _ = self.to_tui_send.send(ToForumView::OpenEditor(e_p));
                             } else {
-                                eprintln!("Only id=0 expected when reviewing add topic, {id}");
+                                log_debug!("Only id=0 expected when reviewing add topic, {id}");
                             }
                         }
                         ForumSyncMessage::AddPost(_t_id, entry) => {
                             if id == 0 {
                                 let e_p = EditorParams {
-                                    title: format!("Reviewing new Post"),
+                                    title: tr("forum.reviewing_new_post"),
                                     initial_text: Some(entry.text.clone()),
                                     allow_newlines: false,
                                     chars_limit: None,
@@ -3355,7 +3424,7 @@ impl ForumLogic {
                                 };
                                 let _ = self.to_tui_send.send(ToForumView::OpenEditor(e_p));
                             } else {
-                                eprintln!("Only id=0 expected when reviewing add post, {id}");
+                                log_debug!("Only id=0 expected when reviewing add post, {id}");
                             }
                         }
                     }
@@ -3371,22 +3440,22 @@ impl ForumLogic {
                 }
             }
             PresentationState::Editing(_id, _prev_state) => {
-                eprintln!("Got ID when in state Editing");
+                log_debug!("Got ID when in state Editing");
             }
             PresentationState::SelectingOneCapability(_v_caps, _p_state) => {
-                eprintln!("Got ID when in state SelectingOneCapability");
+                log_debug!("Got ID when in state SelectingOneCapability");
             }
             PresentationState::CreatingByteSet(_opts, _is_run, _ex_opt) => {
-                eprintln!("Got ID when creating ByteSet");
+                log_debug!("Got ID when creating ByteSet");
             }
             PresentationState::ShowingPost(_c, _p) => {
-                eprintln!("Got ID when showing Post");
+                log_debug!("Got ID when showing Post");
             }
             PresentationState::TopicEditing(_tctx) => {
                 //ignore
             }
             PresentationState::Fitlering => {
-                eprintln!("Querying not supported while defining Filtering conditions");
+                log_warn!("Querying not supported while defining Filtering conditions");
             }
         }
         if let Some(n_s) = new_state {
@@ -3394,7 +3463,7 @@ impl ForumLogic {
         }
     }
     async fn run_policy(&mut self, pol: Policy, req: Requirement) {
-        eprintln!("In run_policy");
+        log_debug!("In run_policy");
         // TODO: update selected policy to new value by
         // sending a msg to Gnome in order for
         // him to reconfigure Swarm.
@@ -3408,7 +3477,7 @@ impl ForumLogic {
             .await;
     }
     async fn store_policy(&mut self, pol: Policy, req: Requirement) {
-        eprintln!("In store_policy");
+        log_debug!("In store_policy");
         self.shell.manifest.policy_reg.insert(pol, req);
         let m_data = self.shell.manifest.to_data();
         let _ = self
@@ -3435,7 +3504,7 @@ impl ForumLogic {
             return;
         }
 
-        eprintln!("Forum app got rejected: {sm_type:?}");
+        log_debug!("Forum app got rejected: {sm_type:?}");
         match sm_type {
             SyncMessageType::AppendContent(d_type) => {
                 if d_type.byte() == 0 {
@@ -3497,7 +3566,7 @@ impl ForumLogic {
 
     async fn process_search_results(&mut self, query: String, is_permanent: bool, hits: Vec<Hit>) {
         //TODO
-        eprintln!(
+        log_debug!(
             "in process_search_results {} have {} hits",
             query,
             hits.len()
@@ -3564,6 +3633,21 @@ impl ForumLogic {
         }
         // eprintln!("in filter_topics");
     }
+    // Where we are now, to be restored on next start
+    fn current_session(&self) -> Session {
+        let topic = match &self.presentation_state {
+            PresentationState::Topic(t_id, pg_opt) => Some((*t_id, pg_opt.unwrap_or(0))),
+            _other => None,
+        };
+        Session {
+            app_type: AppType::Forum,
+            swarm: self.shell.swarm_name.clone(),
+            street: None,
+            tile: None,
+            topic,
+            clipboard: self.clipboard.clone(),
+        }
+    }
     fn set_clipboard(&mut self, which: u16) {
         match &self.presentation_state {
             PresentationState::MainLobby(pg_opt) => {
@@ -3603,11 +3687,11 @@ pub async fn from_forum_tui_adapter(
             }
         }
     }
-    eprintln!("from_tui_adapter is done");
+    log_info!("from_tui_adapter is done");
 }
 pub async fn start_a_timer(sender: ASender<ToAppMgr>, message: ToAppMgr, timeout: Duration) {
     // let timeout = Duration::from_secs(5);
     sleep(timeout).await;
-    eprintln!("Timeout {:?} is over, sending message…", timeout);
+    log_debug!("Timeout {:?} is over, sending message…", timeout);
     let _ = sender.send(message).await;
 }
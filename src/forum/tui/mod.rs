@@ -1,5 +1,3 @@
-use animaterm::prelude::Key;
-use animaterm::Glyph;
 use animaterm::Graphic;
 use animaterm::Manager;
 use dapp_lib::prelude::AppType;
@@ -15,13 +13,22 @@ use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 
 use crate::catalog::tui::button::Button;
+use crate::catalog::tui::dialog::{Answer, Dialog};
+use crate::catalog::tui::palette::Palette;
 use crate::catalog::tui::CreatorResult;
 use crate::catalog::tui::EditorResult;
+use crate::catalog::tui::Help;
+use crate::catalog::tui::{ListData, ListView};
+use crate::commands::Command;
+use crate::common::i18n::tr;
 use crate::common::poledit::PolAction;
 use crate::common::poledit::PolicyEditor;
 use crate::common::poledit::ReqTree;
+use crate::common::theme::{glyph, Role};
 use crate::forum::logic::TopicContext;
+use crate::keymap::{KeyAction, KeyContext};
 use crate::Toolset;
+use crate::{log_debug, log_error, log_info, log_warn};
 pub struct EditorParams {
     pub title: String,
     pub initial_text: Option<String>,
@@ -64,7 +71,7 @@ pub enum Action {
 pub enum ToForumView {
     TopicsPage(u16, Vec<(u16, String)>),
     PostsPage(u16, Vec<(u16, String)>),
-    Request(Vec<(u16, String)>),
+    Request(ListData),
     RunningPoliciesPage(u16, Vec<(u16, String)>),
     StoredPoliciesPage(u16, Vec<(u16, String)>),
     RunningCapabilitiesPage(u16, Vec<(u16, String)>),
@@ -114,20 +121,27 @@ impl ButtonsLogic {
         //
         // 6. 7. unocuppied
         // (in options +Category)
-        let button_1 = Button::new((10, 3), 2, (1, 0), "Category", None, tui_mgr);
+        let button_1 = Button::new((10, 3), 2, (1, 0), &tr("forum.btn_category"), None, tui_mgr);
         button_1.show(tui_mgr);
         button_1.select(tui_mgr, false);
-        let button_2 = Button::new((10, 3), 2, (12, 0), "Filter", None, tui_mgr);
+        let button_2 = Button::new((10, 3), 2, (12, 0), &tr("forum.btn_filter"), None, tui_mgr);
         button_2.show(tui_mgr);
-        let button_3 = Button::new((10, 3), 2, (23, 0), "+ Topic", None, tui_mgr);
+        let button_3 = Button::new(
+            (10, 3),
+            2,
+            (23, 0),
+            &tr("forum.btn_add_topic"),
+            None,
+            tui_mgr,
+        );
         button_3.show(tui_mgr);
-        let button_4 = Button::new((10, 3), 2, (34, 0), "Options", None, tui_mgr);
+        let button_4 = Button::new((10, 3), 2, (34, 0), &tr("forum.btn_options"), None, tui_mgr);
         button_4.show(tui_mgr);
-        let button_5 = Button::new((10, 3), 2, (45, 0), "→Village", None, tui_mgr);
+        let button_5 = Button::new((10, 3), 2, (45, 0), &tr("forum.btn_village"), None, tui_mgr);
         button_5.show(tui_mgr);
-        let button_6 = Button::new((10, 3), 2, (56, 0), "…More", None, tui_mgr);
+        let button_6 = Button::new((10, 3), 2, (56, 0), &tr("forum.btn_more"), None, tui_mgr);
         button_6.show(tui_mgr);
-        let button_7 = Button::new((10, 3), 2, (67, 0), "…Actions", None, tui_mgr);
+        let button_7 = Button::new((10, 3), 2, (67, 0), &tr("forum.btn_actions"), None, tui_mgr);
         button_7.show(tui_mgr);
         let menu_buttons = [
             (button_1, true),
@@ -159,11 +173,11 @@ impl ButtonsLogic {
             MenuType::Main,
             MenuConfig::new(
                 [
-                    ButtonState::Show("Category".to_string()),
-                    ButtonState::Show("Filter".to_string()),
-                    ButtonState::Show("+ Topic".to_string()),
-                    ButtonState::Show("Options".to_string()),
-                    ButtonState::Show("→ Village".to_string()),
+                    ButtonState::Show(tr("forum.btn_category")),
+                    ButtonState::Show(tr("forum.btn_filter")),
+                    ButtonState::Show(tr("forum.btn_add_topic")),
+                    ButtonState::Show(tr("forum.btn_options")),
+                    ButtonState::Show(tr("forum.btn_village")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                 ],
@@ -174,10 +188,10 @@ impl ButtonsLogic {
             MenuType::Topic,
             MenuConfig::new(
                 [
-                    ButtonState::Show("New post".to_string()),
-                    ButtonState::Show("Edit".to_string()),
+                    ButtonState::Show(tr("forum.btn_new_post")),
+                    ButtonState::Show(tr("forum.btn_edit")),
                     ButtonState::Hide,
-                    ButtonState::Show("← Forum".to_string()),
+                    ButtonState::Show(tr("forum.btn_forum")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -189,9 +203,9 @@ impl ButtonsLogic {
             MenuType::Settings,
             MenuConfig::new(
                 [
-                    ButtonState::Show("← Forum".to_string()),
-                    ButtonState::Show("Requests".to_string()),
-                    ButtonState::Show("+Categry".to_string()),
+                    ButtonState::Show(tr("forum.btn_forum")),
+                    ButtonState::Show(tr("forum.btn_requests")),
+                    ButtonState::Show(tr("forum.btn_add_category")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -199,31 +213,31 @@ impl ButtonsLogic {
                 ],
                 EntriesState::Fixed(vec![
                     (
-                        ButtonState::Show("Running Policies".to_string()),
+                        ButtonState::Show(tr("forum.btn_running_policies")),
                         EntryAction::RunningPolicies,
                     ),
                     (
-                        ButtonState::Show("Running Capabilities".to_string()),
+                        ButtonState::Show(tr("forum.btn_running_capabilities")),
                         EntryAction::RunningCapabilities,
                     ),
                     (
-                        ButtonState::Show("Running Byte Sets".to_string()),
+                        ButtonState::Show(tr("forum.btn_running_byte_sets")),
                         EntryAction::RunningByteSets,
                     ),
                     (
-                        ButtonState::Show("Stored Policies".to_string()),
+                        ButtonState::Show(tr("forum.btn_stored_policies")),
                         EntryAction::StoredPolicies,
                     ),
                     (
-                        ButtonState::Show("Stored Capabilities".to_string()),
+                        ButtonState::Show(tr("forum.btn_stored_capabilities")),
                         EntryAction::StoredCapabilities,
                     ),
                     (
-                        ButtonState::Show("Stored Byte Sets".to_string()),
+                        ButtonState::Show(tr("forum.btn_stored_byte_sets")),
                         EntryAction::StoredByteSets,
                     ),
                     (
-                        ButtonState::Show("Change Forum's description".to_string()),
+                        ButtonState::Show(tr("forum.btn_change_description")),
                         EntryAction::EditDescription,
                     ),
                 ]),
@@ -233,9 +247,9 @@ impl ButtonsLogic {
             MenuType::Requests,
             MenuConfig::new(
                 [
-                    ButtonState::Show("Approve".to_string()),
-                    ButtonState::Show("Reject".to_string()),
-                    ButtonState::Show("← Settings".to_string()),
+                    ButtonState::Show(tr("forum.btn_approve")),
+                    ButtonState::Show(tr("forum.btn_reject")),
+                    ButtonState::Show(tr("forum.btn_settings")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -248,8 +262,8 @@ impl ButtonsLogic {
             MenuType::RunningPolicies,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -263,9 +277,9 @@ impl ButtonsLogic {
             MenuType::RunningCapabilities,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
-                    ButtonState::Show("Add Cap".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
+                    ButtonState::Show(tr("forum.btn_add_capability")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -278,12 +292,12 @@ impl ButtonsLogic {
             MenuType::RunningByteSets,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
-                    ButtonState::Show("New 1Bte".to_string()),
-                    ButtonState::Show("New 2Bts".to_string()),
-                    ButtonState::Show("Run".to_string()),
-                    ButtonState::Show("Store".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
+                    ButtonState::Show(tr("forum.btn_new_byte")),
+                    ButtonState::Show(tr("forum.btn_new_pair")),
+                    ButtonState::Show(tr("forum.btn_run")),
+                    ButtonState::Show(tr("forum.btn_store")),
                     ButtonState::Hide,
                 ],
                 EntriesState::QueryLogic(QueryType::ActiveByteSet),
@@ -293,8 +307,8 @@ impl ButtonsLogic {
             MenuType::StoredPolicies,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -308,9 +322,9 @@ impl ButtonsLogic {
             MenuType::StoredCapabilities,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
-                    ButtonState::Show("Add Cap".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
+                    ButtonState::Show(tr("forum.btn_add_capability")),
                     ButtonState::Hide,
                     ButtonState::Hide,
                     ButtonState::Hide,
@@ -323,12 +337,12 @@ impl ButtonsLogic {
             MenuType::StoredByteSets,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("← Forum".to_string()),
-                    ButtonState::Show("New 1Bte".to_string()),
-                    ButtonState::Show("New 2Bts".to_string()),
-                    ButtonState::Show("Run".to_string()),
-                    ButtonState::Show("Store".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_forum")),
+                    ButtonState::Show(tr("forum.btn_new_byte")),
+                    ButtonState::Show(tr("forum.btn_new_pair")),
+                    ButtonState::Show(tr("forum.btn_run")),
+                    ButtonState::Show(tr("forum.btn_store")),
                     ButtonState::Hide,
                 ],
                 EntriesState::QueryLogic(QueryType::StoredByteSet),
@@ -345,13 +359,13 @@ impl ButtonsLogic {
             MenuType::Capability,
             MenuConfig::new(
                 [
-                    ButtonState::Show("←Setings".to_string()),
-                    ButtonState::Show("Filter".to_string()),
-                    ButtonState::Show("Add".to_string()),
-                    ButtonState::Show("Modify".to_string()),
-                    ButtonState::Show("Delete".to_string()),
-                    ButtonState::Show("Run".to_string()),
-                    ButtonState::Show("Store".to_string()),
+                    ButtonState::Show(tr("forum.btn_settings_short")),
+                    ButtonState::Show(tr("forum.btn_filter")),
+                    ButtonState::Show(tr("forum.btn_add")),
+                    ButtonState::Show(tr("forum.btn_modify")),
+                    ButtonState::Show(tr("forum.btn_delete")),
+                    ButtonState::Show(tr("forum.btn_run")),
+                    ButtonState::Show(tr("forum.btn_store")),
                 ],
                 EntriesState::QueryLogic(QueryType::StoredByteSet),
             ),
@@ -364,7 +378,7 @@ impl ButtonsLogic {
                 (cols - 2, 1),
                 2,
                 (1, 4 + (i << 1) as isize),
-                "Empty",
+                &tr("forum.empty"),
                 None,
                 tui_mgr,
             );
@@ -398,7 +412,7 @@ impl ButtonsLogic {
                             // Filter Topics to only include
                             // Topics marked with at least
                             // one of selected Catagories.
-                            eprintln!("Returinig Action::Filter(true)");
+                            log_debug!("Returinig Action::Filter(true)");
                             Some(Action::Filter(true))
                         }
                         1 => {
@@ -408,7 +422,7 @@ impl ButtonsLogic {
                             // Filter Topics to only include
                             // those containing at least one
                             // word from user-defined Filter
-                            eprintln!("Returinig Action::Filter(false)");
+                            log_debug!("Returinig Action::Filter(false)");
                             Some(Action::Filter(false))
                         }
                         2 => {
@@ -523,7 +537,7 @@ impl ButtonsLogic {
                             Some(Action::Settings)
                         }
                         _o => {
-                            eprintln!("Menu Button id {_o} not supported in Requests ");
+                            log_warn!("Menu Button id {_o} not supported in Requests ");
                             None
                         }
                     }
@@ -820,9 +834,40 @@ impl ButtonsLogic {
             }
         }
     }
+    // Same menu and action as pressing a button would give,
+    // for palette commands that have no key binding
+    pub fn run_command(&mut self, command: Command, tui_mgr: &mut Manager) -> Option<Action> {
+        let (menu, action) = match command {
+            Command::Topics => (Some(MenuType::Main), Action::MainMenu),
+            Command::NewEntry => (None, Action::AddNew(false)),
+            Command::Filter => (None, Action::Filter(false)),
+            Command::Categories => (None, Action::Filter(true)),
+            Command::Settings => (Some(MenuType::Settings), Action::Settings),
+            Command::RunningPolicies => (Some(MenuType::RunningPolicies), Action::RunningPolicies),
+            Command::StoredPolicies => (Some(MenuType::StoredPolicies), Action::StoredPolicies),
+            Command::RunningCapabilities => (
+                Some(MenuType::RunningCapabilities),
+                Action::RunningCapabilities,
+            ),
+            Command::StoredCapabilities => (
+                Some(MenuType::StoredCapabilities),
+                Action::StoredCapabilities,
+            ),
+            Command::RunningByteSets => (Some(MenuType::RunningByteSets), Action::ByteSets(true)),
+            Command::StoredByteSets => (Some(MenuType::StoredByteSets), Action::ByteSets(false)),
+            other => {
+                log_debug!("{} command has no meaning in Forum", other.name());
+                return None;
+            }
+        };
+        if let Some(menu) = menu {
+            self.activate_menu(menu, tui_mgr);
+        }
+        Some(action)
+    }
     pub fn update_entries(&mut self, mut new_list: Vec<(u16, String)>, tui_mgr: &mut Manager) {
         // TODO:
-        eprintln!("New list len: {:?}", new_list);
+        log_debug!("New list len: {:?}", new_list);
         for butn in &mut self.entry_buttons {
             if !new_list.is_empty() {
                 let (id, text) = new_list.remove(0);
@@ -837,7 +882,7 @@ impl ButtonsLogic {
             }
         }
         if !new_list.is_empty() {
-            eprintln!("Received overflowing entries page!");
+            log_debug!("Received overflowing entries page!");
         }
     }
 
@@ -1039,7 +1084,7 @@ impl ButtonsLogic {
 
             //TODO
         } else {
-            eprintln!("Could not activate menu: {}", self.selected_menu_button);
+            log_warn!("Could not activate menu: {}", self.selected_menu_button);
         }
     }
 }
@@ -1135,17 +1180,31 @@ pub fn serve_forum_tui(
     // startup & should be passed to an app
     // together with other tools
     let mut editor = e_opt.unwrap();
+    let keymap = config.keymap.clone();
+    editor.set_keymap(keymap.clone());
+    selector.set_keymap(keymap.clone());
     let mut pedit = if let Some(pe) = pe_opt {
         pe
     } else {
         PolicyEditor::new(&mut tui_mgr)
     };
+    pedit.set_keymap(keymap.clone());
     // TODO: do not create a new display every time Forum App is opened
     let main_display = tui_mgr.new_display(true);
     let (cols, rows) = tui_mgr.screen_size();
-    let frame = vec![Glyph::blue(); cols * rows];
+    let frame = vec![glyph(Role::ForumBackground, ' '); cols * rows];
     // Forum
     let mut buttons_logic = ButtonsLogic::new(&mut tui_mgr);
+    let mut dialog = Dialog::new(&mut tui_mgr);
+    dialog.set_keymap(keymap.clone());
+    let mut palette = Palette::new(&mut tui_mgr);
+    palette.set_keymap(keymap.clone());
+    let mut help = Help::new(&mut tui_mgr);
+    help.set_keymap(keymap.clone());
+    let mut list_view = ListView::new(&mut tui_mgr);
+    list_view.set_keymap(keymap.clone());
+    // Action of a command chosen from palette, run as if it's key was pressed
+    let mut pending: Option<KeyAction> = None;
     // let mut action = buttons_logic.activate(&mut tui_mgr);
     let mut action = None;
     // let mut active_button = 0;
@@ -1192,62 +1251,86 @@ pub fn serve_forum_tui(
     tui_mgr.set_graphic(bg_idx, 0, true);
     loop {
         if let Some(act) = action.take() {
-            eprintln!("Some action: {:?}", act);
+            log_debug!("Some action: {:?}", act);
             let _ = to_app.send(FromForumView::Act(act));
         }
-        if let Some(key) = tui_mgr.read_key() {
-            match key {
-                Key::Enter => {
+        let mut next_action = pending.take();
+        if next_action.is_none() {
+            if let Some(key) = tui_mgr.read_key() {
+                let Some(k_action) = keymap.action(KeyContext::Forum, &key) else {
+                    //TODO
+                    continue;
+                };
+                next_action = Some(k_action);
+            }
+        }
+        if let Some(k_action) = next_action {
+            match k_action {
+                KeyAction::Select => {
                     action = buttons_logic.activate(&mut tui_mgr);
                 }
-                Key::ShiftQ => {
-                    eprintln!("Forum ShiftQ");
-                    let _ = to_app.send(FromForumView::Quit);
-                    break;
+                KeyAction::Quit => {
+                    let answer =
+                        dialog.confirm(&tr("dialog.quit_title"), &tr("dialog.quit"), &mut tui_mgr);
+                    if answer == Answer::Yes {
+                        log_debug!("Forum Quit");
+                        let _ = to_app.send(FromForumView::Quit);
+                        break;
+                    }
                 }
-                Key::C => {
+                KeyAction::OpenCatalog => {
                     let s_name = SwarmName::new(GnomeId::any(), "/".to_string()).unwrap();
-                    eprintln!("Forum C");
+                    log_debug!("Forum OpenCatalog");
                     let _ = to_app.send(FromForumView::SwitchTo(AppType::Catalog, s_name));
                     break;
                 }
-                Key::Right | Key::W => {
+                KeyAction::MoveRight => {
                     let _action = buttons_logic.right(&mut tui_mgr);
                 }
-                Key::Left | Key::P => {
+                KeyAction::MoveLeft => {
                     let _action = buttons_logic.left(&mut tui_mgr);
                 }
-                Key::Up | Key::O => {
+                KeyAction::MoveUp => {
                     if buttons_logic.up(&mut tui_mgr) {
                         // TODO: open prev page
                         action = Some(Action::PreviousPage);
                     }
                 }
-                Key::Down | Key::Comma => {
+                KeyAction::MoveDown => {
                     if buttons_logic.down(&mut tui_mgr) {
                         // TODO: open next page
                         action = Some(Action::NextPage);
                     }
                 }
-                Key::Home => {
+                KeyAction::FirstPage => {
                     action = Some(Action::FirstPage);
                 }
-                Key::PgUp => {
+                KeyAction::PrevPage => {
                     action = Some(Action::PreviousPage);
                 }
-                Key::PgDn => {
+                KeyAction::NextPage => {
                     action = Some(Action::NextPage);
                 }
-                Key::End => {
+                KeyAction::LastPage => {
                     action = Some(Action::LastPage);
                 }
-                Key::F5 => {
+                KeyAction::CopyEntry => {
                     let _ = to_app.send(FromForumView::CopyToClipboard(
                         buttons_logic.selected_entry_button as u16,
                     ));
                 }
-                _other => {
-                    //TODO
+                KeyAction::Help => help.show(KeyContext::Forum, &mut tui_mgr),
+                KeyAction::CommandPalette => {
+                    if let Some((command, _argument)) = palette.run(KeyContext::Forum, &mut tui_mgr)
+                    {
+                        match command.key_action() {
+                            Some(k_action) => pending = Some(k_action),
+                            None => action = buttons_logic.run_command(command, &mut tui_mgr),
+                        }
+                    }
+                }
+                other => {
+                    log_debug!("{} has no meaning in Forum", other.name());
                 }
             }
         }
@@ -1263,9 +1346,25 @@ pub fn serve_forum_tui(
                     buttons_logic.activate_menu(MenuType::Topic, &mut tui_mgr);
                     buttons_logic.update_entries(topics, &mut tui_mgr);
                 }
-                ToForumView::Request(v_req) => {
-                    buttons_logic.activate_menu(MenuType::Requests, &mut tui_mgr);
-                    buttons_logic.update_entries(v_req, &mut tui_mgr);
+                ToForumView::Request(requests) => {
+                    let chosen = list_view.serve(
+                        main_display,
+                        &tr("forum.requests"),
+                        requests.clone(),
+                        &mut tui_mgr,
+                    );
+                    if let Some(idx) = chosen {
+                        // Chosen request stays visible while it is approved or rejected
+                        buttons_logic.activate_menu(MenuType::Requests, &mut tui_mgr);
+                        buttons_logic.update_entries(
+                            vec![(idx as u16, requests.line(idx, cols - 4))],
+                            &mut tui_mgr,
+                        );
+                    } else {
+                        // Back to Settings menu
+                        buttons_logic.activate_menu(MenuType::Settings, &mut tui_mgr);
+                        action = Some(Action::Settings);
+                    }
                 }
                 ToForumView::RunningPoliciesPage(_pg_no, plcs) => {
                     if buttons_logic.is_current_config_equal(MenuType::RunningPolicies) {
@@ -1304,7 +1403,7 @@ pub fn serve_forum_tui(
                     }
                 }
                 ToForumView::ShowPolicy(pol, req) => {
-                    eprintln!("Forum TUI Policy: {:?} – {:?} to present", pol, req);
+                    log_debug!("Forum TUI Policy: {:?} – {:?} to present", pol, req);
 
                     if let Some(p_action) = pedit.present(pol, req, &mut tui_mgr) {
                         // TODO
@@ -1344,33 +1443,33 @@ pub fn serve_forum_tui(
                     // 6 - Run
                     // 7 - Store
                     if buttons_logic.is_current_config_equal(MenuType::Capability) {
-                        eprintln!("Updating entries with {} items", v_gids.len());
+                        log_debug!("Updating entries with {} items", v_gids.len());
                         buttons_logic.update_entries(v_gids, &mut tui_mgr);
                         // TODO: present them to user if in right menu
                         // we might be showing stored config by this time…
                     } else {
-                        eprintln!(
+                        log_warn!(
                             "Can not show Capability in {:?}",
                             buttons_logic.current_config_id
                         );
                     }
-                    eprintln!("ForumTUI should present cap",);
+                    log_debug!("ForumTUI should present cap",);
                 }
                 ToForumView::ShowByteSet(bs_id, _bs) => {
                     // TODO
-                    eprintln!("ForumTUI should present ByteSet({bs_id})");
+                    log_debug!("ForumTUI should present ByteSet({bs_id})");
                 }
                 ToForumView::Select(only_one, list, preselected) => {
                     let text = if only_one {
-                        "Pick one & press Enter"
+                        tr("forum.pick_one")
                     } else {
-                        "Pick many with Enter, Escape to finish"
+                        tr("forum.pick_many")
                     };
                     let selected =
-                        selector.select(text, &list, preselected, &mut tui_mgr, only_one);
+                        selector.select(&text, &list, preselected, &mut tui_mgr, only_one);
                     // TODO: bring up selector with
                     // a given list & reply back to logic.
-                    eprintln!("Selected: {:?}", selected);
+                    log_debug!("Selected: {:?}", selected);
                     tui_mgr.restore_display(main_display, true);
                     action = Some(Action::Selected(selected));
                     // if !selected.is_empty() {
@@ -1396,12 +1495,12 @@ pub fn serve_forum_tui(
                     tui_mgr.restore_display(main_display, true);
                 }
                 ToForumView::OpenCreator(t_ctx) => {
-                    eprintln!("Forum should open creator");
+                    log_debug!("Forum should open creator");
                     let mut tags_str = String::new();
                     let tags_len = t_ctx.tag_names.len();
                     for t_id in t_ctx.tags {
                         if t_id >= tags_len {
-                            eprintln!("Tags: {:?} but idx: {t_id}", t_ctx.tag_names);
+                            log_debug!("Tags: {:?} but idx: {t_id}", t_ctx.tag_names);
                             break;
                         }
                         // TODO: got a panic below here once, tag_names index too big
@@ -1412,30 +1511,30 @@ pub fn serve_forum_tui(
                         main_display,
                         &mut tui_mgr,
                         false,
-                        format!("Topic"),
+                        tr("forum.topic"),
                         tags_str,
                         t_ctx.description,
                     );
                     action = Some(Action::CreatorResult(res));
                 }
                 ToForumView::Finish => {
-                    eprintln!("Forum is finished");
+                    log_debug!("Forum is finished");
                     break;
                 }
             }
-            eprintln!("Forum TUI recv");
+            log_debug!("Forum TUI recv");
         } else {
             let err = to_tui_res.err().unwrap();
             match err {
                 std::sync::mpsc::TryRecvError::Empty => {}
                 std::sync::mpsc::TryRecvError::Disconnected => {
-                    eprintln!("Forum TUI got Disconnected error.");
+                    log_error!("Forum TUI got Disconnected error.");
                     break;
                 }
             }
         }
     }
-    eprintln!("serve_forum_tui is done");
+    log_info!("serve_forum_tui is done");
     // (tui_mgr, config)
     Toolset::fold(tui_mgr, config, None, None, None, None, Some(pedit))
 }
@@ -0,0 +1,1073 @@
+use crate::common::i18n::tr;
+use crate::log_warn;
+use animaterm::prelude::map_private_char_to_key;
+use animaterm::Key;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+// Key bindings are read from keymap.conf in config dir.
+// Every line is of form:
+// context action KEY [KEY ...]
+// for example:
+// catalog quit ShiftQ
+// editor finish Tab CtrlS
+// Keys listed for an action replace it's default keys within given context.
+// Lines starting with '#' are ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyContext {
+    Catalog,
+    Forum,
+    Editor,
+    Selector,
+    Indexer,
+    List,
+    Dialog,
+    Palette,
+    Help,
+    Menu,
+    Creator,
+    PolicyEditor,
+    // Test keys served in developer mode
+    Debug,
+}
+
+impl KeyContext {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyContext::Catalog => "catalog",
+            KeyContext::Forum => "forum",
+            KeyContext::Editor => "editor",
+            KeyContext::Selector => "selector",
+            KeyContext::Indexer => "indexer",
+            KeyContext::List => "list",
+            KeyContext::Dialog => "dialog",
+            KeyContext::Palette => "palette",
+            KeyContext::Help => "help",
+            KeyContext::Menu => "menu",
+            KeyContext::Creator => "creator",
+            KeyContext::PolicyEditor => "policy_editor",
+            KeyContext::Debug => "debug",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "catalog" => Some(KeyContext::Catalog),
+            "forum" => Some(KeyContext::Forum),
+            "editor" => Some(KeyContext::Editor),
+            "selector" => Some(KeyContext::Selector),
+            "indexer" => Some(KeyContext::Indexer),
+            "list" => Some(KeyContext::List),
+            "dialog" => Some(KeyContext::Dialog),
+            "palette" => Some(KeyContext::Palette),
+            "help" => Some(KeyContext::Help),
+            "menu" => Some(KeyContext::Menu),
+            "creator" => Some(KeyContext::Creator),
+            "policy_editor" => Some(KeyContext::PolicyEditor),
+            "debug" => Some(KeyContext::Debug),
+            _other => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyAction {
+    Quit,
+    ContextMenu,
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    WordLeft,
+    WordRight,
+    LineStart,
+    LineEnd,
+    HomeVillage,
+    FirstItem,
+    LastItem,
+    Select,
+    Finish,
+    OpenForum,
+    OpenCatalog,
+    FirstPage,
+    PrevPage,
+    NextPage,
+    LastPage,
+    CopyEntry,
+    Delete,
+    KillToEnd,
+    EnableEditing,
+    SetMark,
+    Copy,
+    Cut,
+    Paste,
+    YankPop,
+    Undo,
+    Redo,
+    ScrollUp,
+    ScrollDown,
+    ExternalEditor,
+    ClearFilter,
+    SortColumn,
+    ReverseSort,
+    NextField,
+    PrevField,
+    DebugPanel,
+    LogViewer,
+    Notifications,
+    ActivityFeed,
+    DismissNotification,
+    CommandPalette,
+    Help,
+    // Experiments with dapp-lib, see catalog::logic::debug
+    BroadcastTestData,
+    ChangeDiameter,
+    StartBroadcast,
+    EndBroadcast,
+    UnsubscribeBroadcast,
+    StartMulticast,
+    MulticastTestData,
+    EndMulticast,
+    UnsubscribeMulticast,
+    SubscribeMulticast,
+    SendToMCastSource,
+    SendToBCastSource,
+    StartForum,
+    TransformLink,
+    ReadManifest,
+    TestNotification,
+    AppendEmptyContent,
+    StartUnicast,
+}
+
+const ACTIONS: [KeyAction; 65] = [
+    KeyAction::Quit,
+    KeyAction::ContextMenu,
+    KeyAction::MoveLeft,
+    KeyAction::MoveRight,
+    KeyAction::MoveUp,
+    KeyAction::MoveDown,
+    KeyAction::WordLeft,
+    KeyAction::WordRight,
+    KeyAction::LineStart,
+    KeyAction::LineEnd,
+    KeyAction::HomeVillage,
+    KeyAction::FirstItem,
+    KeyAction::LastItem,
+    KeyAction::Select,
+    KeyAction::Finish,
+    KeyAction::OpenForum,
+    KeyAction::OpenCatalog,
+    KeyAction::FirstPage,
+    KeyAction::PrevPage,
+    KeyAction::NextPage,
+    KeyAction::LastPage,
+    KeyAction::CopyEntry,
+    KeyAction::Delete,
+    KeyAction::KillToEnd,
+    KeyAction::EnableEditing,
+    KeyAction::SetMark,
+    KeyAction::Copy,
+    KeyAction::Cut,
+    KeyAction::Paste,
+    KeyAction::YankPop,
+    KeyAction::Undo,
+    KeyAction::Redo,
+    KeyAction::ScrollUp,
+    KeyAction::ScrollDown,
+    KeyAction::ExternalEditor,
+    KeyAction::ClearFilter,
+    KeyAction::SortColumn,
+    KeyAction::ReverseSort,
+    KeyAction::NextField,
+    KeyAction::PrevField,
+    KeyAction::DebugPanel,
+    KeyAction::LogViewer,
+    KeyAction::Notifications,
+    KeyAction::ActivityFeed,
+    KeyAction::DismissNotification,
+    KeyAction::CommandPalette,
+    KeyAction::Help,
+    KeyAction::BroadcastTestData,
+    KeyAction::ChangeDiameter,
+    KeyAction::StartBroadcast,
+    KeyAction::EndBroadcast,
+    KeyAction::UnsubscribeBroadcast,
+    KeyAction::StartMulticast,
+    KeyAction::MulticastTestData,
+    KeyAction::EndMulticast,
+    KeyAction::UnsubscribeMulticast,
+    KeyAction::SubscribeMulticast,
+    KeyAction::SendToMCastSource,
+    KeyAction::SendToBCastSource,
+    KeyAction::StartForum,
+    KeyAction::TransformLink,
+    KeyAction::ReadManifest,
+    KeyAction::TestNotification,
+    KeyAction::AppendEmptyContent,
+    KeyAction::StartUnicast,
+];
+
+impl KeyAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::ContextMenu => "context_menu",
+            KeyAction::MoveLeft => "move_left",
+            KeyAction::MoveRight => "move_right",
+            KeyAction::MoveUp => "move_up",
+            KeyAction::MoveDown => "move_down",
+            KeyAction::WordLeft => "word_left",
+            KeyAction::WordRight => "word_right",
+            KeyAction::LineStart => "line_start",
+            KeyAction::LineEnd => "line_end",
+            KeyAction::HomeVillage => "home_village",
+            KeyAction::FirstItem => "first_item",
+            KeyAction::LastItem => "last_item",
+            KeyAction::Select => "select",
+            KeyAction::Finish => "finish",
+            KeyAction::OpenForum => "open_forum",
+            KeyAction::OpenCatalog => "open_catalog",
+            KeyAction::FirstPage => "first_page",
+            KeyAction::PrevPage => "prev_page",
+            KeyAction::NextPage => "next_page",
+            KeyAction::LastPage => "last_page",
+            KeyAction::CopyEntry => "copy_entry",
+            KeyAction::Delete => "delete",
+            KeyAction::KillToEnd => "kill_to_end",
+            KeyAction::EnableEditing => "enable_editing",
+            KeyAction::SetMark => "set_mark",
+            KeyAction::Copy => "copy",
+            KeyAction::Cut => "cut",
+            KeyAction::Paste => "paste",
+            KeyAction::YankPop => "yank_pop",
+            KeyAction::Undo => "undo",
+            KeyAction::Redo => "redo",
+            KeyAction::ScrollUp => "scroll_up",
+            KeyAction::ScrollDown => "scroll_down",
+            KeyAction::ExternalEditor => "external_editor",
+            KeyAction::ClearFilter => "clear_filter",
+            KeyAction::SortColumn => "sort_column",
+            KeyAction::ReverseSort => "reverse_sort",
+            KeyAction::NextField => "next_field",
+            KeyAction::PrevField => "prev_field",
+            KeyAction::DebugPanel => "debug_panel",
+            KeyAction::LogViewer => "log_viewer",
+            KeyAction::Notifications => "notifications",
+            KeyAction::ActivityFeed => "activity_feed",
+            KeyAction::DismissNotification => "dismiss_notification",
+            KeyAction::CommandPalette => "command_palette",
+            KeyAction::Help => "help",
+            KeyAction::BroadcastTestData => "broadcast_test_data",
+            KeyAction::ChangeDiameter => "change_diameter",
+            KeyAction::StartBroadcast => "start_broadcast",
+            KeyAction::EndBroadcast => "end_broadcast",
+            KeyAction::UnsubscribeBroadcast => "unsubscribe_broadcast",
+            KeyAction::StartMulticast => "start_multicast",
+            KeyAction::MulticastTestData => "multicast_test_data",
+            KeyAction::EndMulticast => "end_multicast",
+            KeyAction::UnsubscribeMulticast => "unsubscribe_multicast",
+            KeyAction::SubscribeMulticast => "subscribe_multicast",
+            KeyAction::SendToMCastSource => "send_to_mcast_source",
+            KeyAction::SendToBCastSource => "send_to_bcast_source",
+            KeyAction::StartForum => "start_forum",
+            KeyAction::TransformLink => "transform_link",
+            KeyAction::ReadManifest => "read_manifest",
+            KeyAction::TestNotification => "test_notification",
+            KeyAction::AppendEmptyContent => "append_empty_content",
+            KeyAction::StartUnicast => "start_unicast",
+        }
+    }
+
+    // Short explanation shown in help overlay
+    pub fn description(&self) -> String {
+        tr(&format!("action.{}", self.name()))
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase().replace('-', "_");
+        // Name used before it matched copy-entry command
+        if name == "copy_link" {
+            return Some(KeyAction::CopyEntry);
+        }
+        ACTIONS.iter().find(|a| a.name() == name).copied()
+    }
+}
+
+#[derive(Clone)]
+pub struct Keymap {
+    bindings: Vec<(KeyContext, KeyAction, Key)>,
+    problems: Vec<String>,
+}
+
+impl Keymap {
+    // Defaults reflect bindings that used to be hard-coded in TUI loops.
+    pub fn new() -> Self {
+        let defaults: Vec<(KeyContext, KeyAction, Vec<Key>)> = vec![
+            (
+                KeyContext::Catalog,
+                KeyAction::Quit,
+                vec![Key::Q, Key::ShiftQ],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::ContextMenu,
+                vec![Key::AltEnter, Key::Space],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::MoveLeft,
+                vec![Key::Left, Key::H, Key::CtrlB],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::MoveRight,
+                vec![Key::Right, Key::L, Key::CtrlF],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::K, Key::CtrlP],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::J, Key::CtrlN],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::HomeVillage,
+                vec![Key::AltCtrlH],
+            ),
+            (KeyContext::Catalog, KeyAction::FirstItem, vec![Key::Home]),
+            (KeyContext::Catalog, KeyAction::LastItem, vec![Key::End]),
+            (KeyContext::Catalog, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::Catalog, KeyAction::OpenForum, vec![Key::F]),
+            (KeyContext::Catalog, KeyAction::DebugPanel, vec![Key::F12]),
+            (KeyContext::Catalog, KeyAction::LogViewer, vec![Key::F11]),
+            (
+                KeyContext::Catalog,
+                KeyAction::Notifications,
+                vec![Key::F10],
+            ),
+            (KeyContext::Catalog, KeyAction::ActivityFeed, vec![Key::F9]),
+            (
+                KeyContext::Catalog,
+                KeyAction::DismissNotification,
+                vec![Key::Escape],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::CommandPalette,
+                vec![Key::Colon, Key::F2],
+            ),
+            (
+                KeyContext::Catalog,
+                KeyAction::Help,
+                vec![Key::F1, Key::QuestionMark],
+            ),
+            (KeyContext::Forum, KeyAction::Quit, vec![Key::ShiftQ]),
+            (KeyContext::Forum, KeyAction::OpenCatalog, vec![Key::C]),
+            (KeyContext::Forum, KeyAction::Select, vec![Key::Enter]),
+            (
+                KeyContext::Forum,
+                KeyAction::MoveLeft,
+                vec![Key::Left, Key::P],
+            ),
+            (
+                KeyContext::Forum,
+                KeyAction::MoveRight,
+                vec![Key::Right, Key::W],
+            ),
+            (KeyContext::Forum, KeyAction::MoveUp, vec![Key::Up, Key::O]),
+            (
+                KeyContext::Forum,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::Comma],
+            ),
+            (KeyContext::Forum, KeyAction::FirstPage, vec![Key::Home]),
+            (KeyContext::Forum, KeyAction::PrevPage, vec![Key::PgUp]),
+            (KeyContext::Forum, KeyAction::NextPage, vec![Key::PgDn]),
+            (KeyContext::Forum, KeyAction::LastPage, vec![Key::End]),
+            (KeyContext::Forum, KeyAction::CopyEntry, vec![Key::F5]),
+            (
+                KeyContext::Forum,
+                KeyAction::CommandPalette,
+                vec![Key::Colon, Key::F2],
+            ),
+            (
+                KeyContext::Forum,
+                KeyAction::Help,
+                vec![Key::F1, Key::QuestionMark],
+            ),
+            (KeyContext::Editor, KeyAction::Finish, vec![Key::Tab]),
+            (
+                KeyContext::Editor,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Editor,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (
+                KeyContext::Editor,
+                KeyAction::MoveLeft,
+                vec![Key::Left, Key::CtrlB],
+            ),
+            (
+                KeyContext::Editor,
+                KeyAction::MoveRight,
+                vec![Key::Right, Key::CtrlF],
+            ),
+            (KeyContext::Editor, KeyAction::WordLeft, vec![Key::AltB]),
+            (KeyContext::Editor, KeyAction::WordRight, vec![Key::AltF]),
+            (KeyContext::Editor, KeyAction::LineStart, vec![Key::CtrlA]),
+            (KeyContext::Editor, KeyAction::LineEnd, vec![Key::CtrlE]),
+            (KeyContext::Editor, KeyAction::Delete, vec![Key::Delete]),
+            (KeyContext::Editor, KeyAction::KillToEnd, vec![Key::CtrlK]),
+            (KeyContext::Editor, KeyAction::EnableEditing, vec![Key::F8]),
+            (KeyContext::Editor, KeyAction::SetMark, vec![Key::CtrlT]),
+            (KeyContext::Editor, KeyAction::Copy, vec![Key::CtrlC]),
+            (
+                KeyContext::Editor,
+                KeyAction::Cut,
+                vec![Key::CtrlW, Key::CtrlX],
+            ),
+            (
+                KeyContext::Editor,
+                KeyAction::Paste,
+                vec![Key::CtrlY, Key::CtrlV],
+            ),
+            (KeyContext::Editor, KeyAction::YankPop, vec![Key::CtrlO]),
+            (KeyContext::Editor, KeyAction::Undo, vec![Key::CtrlZ]),
+            (KeyContext::Editor, KeyAction::Redo, vec![Key::CtrlR]),
+            (KeyContext::Editor, KeyAction::ScrollUp, vec![Key::CtrlU]),
+            (KeyContext::Editor, KeyAction::ScrollDown, vec![Key::CtrlD]),
+            (KeyContext::Editor, KeyAction::ExternalEditor, vec![Key::F4]),
+            (KeyContext::Editor, KeyAction::FirstPage, vec![Key::Home]),
+            (KeyContext::Editor, KeyAction::PrevPage, vec![Key::PgUp]),
+            (KeyContext::Editor, KeyAction::NextPage, vec![Key::PgDn]),
+            (KeyContext::Editor, KeyAction::LastPage, vec![Key::End]),
+            (KeyContext::Editor, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::Selector,
+                KeyAction::Select,
+                vec![Key::AltTab, Key::Enter],
+            ),
+            (KeyContext::Selector, KeyAction::Finish, vec![Key::Escape]),
+            (
+                KeyContext::Selector,
+                KeyAction::PrevPage,
+                vec![Key::PgUp, Key::CtrlU],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::NextPage,
+                vec![Key::PgDn, Key::CtrlD],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::MoveLeft,
+                vec![Key::Left, Key::CtrlB],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::MoveRight,
+                vec![Key::Right, Key::CtrlF],
+            ),
+            (
+                KeyContext::Selector,
+                KeyAction::ClearFilter,
+                vec![Key::CtrlK],
+            ),
+            (KeyContext::Selector, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::Indexer,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Indexer,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (KeyContext::Indexer, KeyAction::FirstItem, vec![Key::CtrlA]),
+            (KeyContext::Indexer, KeyAction::LastItem, vec![Key::CtrlE]),
+            (KeyContext::Indexer, KeyAction::Finish, vec![Key::Tab]),
+            (KeyContext::Indexer, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::Indexer, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::List,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::List,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (KeyContext::List, KeyAction::PrevPage, vec![Key::PgUp]),
+            (KeyContext::List, KeyAction::NextPage, vec![Key::PgDn]),
+            (KeyContext::List, KeyAction::FirstItem, vec![Key::CtrlA]),
+            (KeyContext::List, KeyAction::LastItem, vec![Key::CtrlE]),
+            (KeyContext::List, KeyAction::SortColumn, vec![Key::CtrlS]),
+            (KeyContext::List, KeyAction::ReverseSort, vec![Key::CtrlR]),
+            (KeyContext::List, KeyAction::ClearFilter, vec![Key::CtrlK]),
+            (KeyContext::List, KeyAction::Finish, vec![Key::Tab]),
+            (KeyContext::List, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::List, KeyAction::Help, vec![Key::F1]),
+            (KeyContext::Dialog, KeyAction::MoveLeft, vec![Key::Left]),
+            (KeyContext::Dialog, KeyAction::MoveRight, vec![Key::Right]),
+            (KeyContext::Dialog, KeyAction::MoveUp, vec![Key::Up]),
+            (KeyContext::Dialog, KeyAction::MoveDown, vec![Key::Down]),
+            (
+                KeyContext::Dialog,
+                KeyAction::LineStart,
+                vec![Key::Home, Key::CtrlA],
+            ),
+            (
+                KeyContext::Dialog,
+                KeyAction::LineEnd,
+                vec![Key::End, Key::CtrlE],
+            ),
+            (KeyContext::Dialog, KeyAction::Delete, vec![Key::Delete]),
+            (KeyContext::Dialog, KeyAction::KillToEnd, vec![Key::CtrlK]),
+            (KeyContext::Dialog, KeyAction::NextField, vec![Key::Tab]),
+            (KeyContext::Dialog, KeyAction::PrevField, vec![Key::AltTab]),
+            (KeyContext::Dialog, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::Dialog, KeyAction::Finish, vec![Key::Escape]),
+            (KeyContext::Dialog, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::Palette,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Palette,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (KeyContext::Palette, KeyAction::MoveLeft, vec![Key::Left]),
+            (KeyContext::Palette, KeyAction::MoveRight, vec![Key::Right]),
+            (
+                KeyContext::Palette,
+                KeyAction::LineStart,
+                vec![Key::Home, Key::CtrlA],
+            ),
+            (
+                KeyContext::Palette,
+                KeyAction::LineEnd,
+                vec![Key::End, Key::CtrlE],
+            ),
+            (KeyContext::Palette, KeyAction::Delete, vec![Key::Delete]),
+            (KeyContext::Palette, KeyAction::KillToEnd, vec![Key::CtrlK]),
+            (KeyContext::Palette, KeyAction::NextField, vec![Key::Tab]),
+            (KeyContext::Palette, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::Palette, KeyAction::Finish, vec![Key::Escape]),
+            (KeyContext::Palette, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::Help,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Help,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (KeyContext::Help, KeyAction::PrevPage, vec![Key::PgUp]),
+            (KeyContext::Help, KeyAction::NextPage, vec![Key::PgDn]),
+            (
+                KeyContext::Help,
+                KeyAction::Finish,
+                vec![Key::Escape, Key::Enter, Key::F1],
+            ),
+            (
+                KeyContext::Menu,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::K, Key::CtrlP],
+            ),
+            (
+                KeyContext::Menu,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::J, Key::CtrlN],
+            ),
+            (
+                KeyContext::Menu,
+                KeyAction::Select,
+                vec![Key::Enter, Key::Space],
+            ),
+            (KeyContext::Menu, KeyAction::Finish, vec![Key::Escape]),
+            (KeyContext::Menu, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::Creator,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::CtrlP],
+            ),
+            (
+                KeyContext::Creator,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::CtrlN],
+            ),
+            (KeyContext::Creator, KeyAction::Select, vec![Key::Enter]),
+            (KeyContext::Creator, KeyAction::Finish, vec![Key::Escape]),
+            (KeyContext::Creator, KeyAction::Help, vec![Key::F1]),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::MoveUp,
+                vec![Key::Up, Key::O],
+            ),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::MoveDown,
+                vec![Key::Down, Key::Comma],
+            ),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::MoveLeft,
+                vec![Key::Left, Key::P],
+            ),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::MoveRight,
+                vec![Key::Right, Key::W],
+            ),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::Select,
+                vec![Key::Enter],
+            ),
+            (
+                KeyContext::PolicyEditor,
+                KeyAction::Finish,
+                vec![Key::Escape],
+            ),
+            (KeyContext::PolicyEditor, KeyAction::Help, vec![Key::F1]),
+            // Debug keys reach logic only when not bound in Catalog
+            (
+                KeyContext::Debug,
+                KeyAction::BroadcastTestData,
+                vec![Key::U],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::ChangeDiameter,
+                vec![Key::ShiftD],
+            ),
+            (KeyContext::Debug, KeyAction::StartBroadcast, vec![Key::B]),
+            (
+                KeyContext::Debug,
+                KeyAction::EndBroadcast,
+                vec![Key::ShiftB],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::UnsubscribeBroadcast,
+                vec![Key::CtrlG],
+            ),
+            (KeyContext::Debug, KeyAction::StartMulticast, vec![Key::M]),
+            (
+                KeyContext::Debug,
+                KeyAction::MulticastTestData,
+                vec![Key::N],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::EndMulticast,
+                vec![Key::ShiftM],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::UnsubscribeMulticast,
+                vec![Key::O],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::SubscribeMulticast,
+                vec![Key::ShiftO],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::SendToMCastSource,
+                vec![Key::ShiftN],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::SendToBCastSource,
+                vec![Key::ShiftS],
+            ),
+            (KeyContext::Debug, KeyAction::StartForum, vec![Key::ShiftF]),
+            (
+                KeyContext::Debug,
+                KeyAction::TransformLink,
+                vec![Key::CtrlU],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::ReadManifest,
+                vec![Key::ShiftR],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::TestNotification,
+                vec![Key::ShiftT],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::AppendEmptyContent,
+                vec![Key::S, Key::ShiftA],
+            ),
+            (
+                KeyContext::Debug,
+                KeyAction::StartUnicast,
+                vec![Key::ShiftU],
+            ),
+        ];
+        let mut bindings = Vec::with_capacity(defaults.len() * 2);
+        for (context, action, keys) in defaults {
+            for key in keys {
+                bindings.push((context, action, key));
+            }
+        }
+        Keymap {
+            bindings,
+            problems: vec![],
+        }
+    }
+
+    pub fn load(dir: &Path) -> Self {
+        let mut keymap = Keymap::new();
+        let file_path = dir.join("keymap.conf");
+        if !file_path.exists() {
+            return keymap;
+        }
+        let file = match File::open(&file_path) {
+            Ok(f) => f,
+            Err(e) => {
+                keymap
+                    .problems
+                    .push(format!("Unable to open {:?}: {}", file_path, e));
+                return keymap;
+            }
+        };
+        for (line_no, line) in BufReader::new(file).lines().enumerate() {
+            let Ok(line) = line else {
+                break;
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            keymap.apply_line(line_no + 1, line);
+        }
+        keymap.check_debug_shadowing();
+        for problem in &keymap.problems {
+            log_warn!("Keymap: {}", problem);
+        }
+        keymap
+    }
+
+    fn apply_line(&mut self, line_no: usize, line: &str) {
+        let mut split = line.split_whitespace();
+        let ctx_name = split.next().unwrap_or("");
+        let Some(context) = KeyContext::from_name(ctx_name) else {
+            self.problems
+                .push(format!("line {}: unknown context '{}'", line_no, ctx_name));
+            return;
+        };
+        let act_name = split.next().unwrap_or("");
+        let Some(action) = KeyAction::from_name(act_name) else {
+            self.problems
+                .push(format!("line {}: unknown action '{}'", line_no, act_name));
+            return;
+        };
+        let mut keys = vec![];
+        for key_name in split {
+            if let Some(key) = key_from_name(key_name) {
+                keys.push(key);
+            } else {
+                self.problems
+                    .push(format!("line {}: unknown key '{}'", line_no, key_name));
+            }
+        }
+        if keys.is_empty() {
+            self.problems.push(format!(
+                "line {}: no valid keys for {} {}, keeping defaults",
+                line_no,
+                context.name(),
+                action.name()
+            ));
+            return;
+        }
+        self.bindings
+            .retain(|(c, a, _k)| !(*c == context && *a == action));
+        for key in keys {
+            // A key can only trigger one action within a context,
+            // most recently configured binding wins.
+            if let Some(pos) = self
+                .bindings
+                .iter()
+                .position(|(c, _a, k)| *c == context && *k == key)
+            {
+                let (_c, prev_action, _k) = self.bindings.remove(pos);
+                self.problems.push(format!(
+                    "line {}: {} in {} was bound to {}, now bound to {}",
+                    line_no,
+                    key_name(&key),
+                    context.name(),
+                    prev_action.name(),
+                    action.name()
+                ));
+            }
+            self.bindings.push((context, action, key));
+        }
+    }
+
+    // Catalog loop only passes keys it has no action for to logic,
+    // so a debug key also bound in catalog never runs it's experiment.
+    fn check_debug_shadowing(&mut self) {
+        for (c, debug_action, key) in &self.bindings {
+            if *c != KeyContext::Debug {
+                continue;
+            }
+            if let Some(catalog_action) = self.action(KeyContext::Catalog, key) {
+                self.problems.push(format!(
+                    "{} in debug ({}) is shadowed by {} in catalog",
+                    key_name(key),
+                    debug_action.name(),
+                    catalog_action.name()
+                ));
+            }
+        }
+    }
+
+    pub fn action(&self, context: KeyContext, key: &Key) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|(c, _a, k)| *c == context && k == key)
+            .map(|(_c, a, _k)| *a)
+    }
+
+    // Editor and Selector read chars instead of Keys,
+    // so we need to translate them first.
+    pub fn action_for_char(&self, context: KeyContext, ch: char) -> Option<KeyAction> {
+        let key = char_to_key(ch)?;
+        self.action(context, &key)
+    }
+
+    pub fn keys_for(&self, context: KeyContext, action: KeyAction) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|(c, a, _k)| *c == context && *a == action)
+            .map(|(_c, _a, k)| key_name(k))
+            .collect()
+    }
+
+    // Actions bound within given context, in order they were defined,
+    // each with names of it's keys
+    pub fn bindings_for(&self, context: KeyContext) -> Vec<(KeyAction, Vec<String>)> {
+        let mut result: Vec<(KeyAction, Vec<String>)> = vec![];
+        for (c, a, k) in &self.bindings {
+            if *c != context {
+                continue;
+            }
+            match result.iter_mut().find(|(action, _keys)| action == a) {
+                Some((_action, keys)) => keys.push(key_name(k)),
+                None => result.push((*a, vec![key_name(k)])),
+            }
+        }
+        result
+    }
+
+    pub fn take_problems(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.problems)
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap::new()
+    }
+}
+
+pub fn char_to_key(ch: char) -> Option<Key> {
+    if let Some(key) = map_private_char_to_key(ch) {
+        return Some(key);
+    }
+    let key = match ch {
+        '\t' => Key::Tab,
+        '\n' => Key::Enter,
+        '\u{1b}' => Key::Escape,
+        ' ' => Key::Space,
+        '\u{1}' => Key::CtrlA,
+        '\u{2}' => Key::CtrlB,
+        '\u{3}' => Key::CtrlC,
+        '\u{4}' => Key::CtrlD,
+        '\u{5}' => Key::CtrlE,
+        '\u{6}' => Key::CtrlF,
+        '\u{7}' => Key::CtrlG,
+        '\u{8}' => Key::CtrlH,
+        '\u{b}' => Key::CtrlK,
+        '\u{c}' => Key::CtrlL,
+        '\u{d}' => Key::CtrlM,
+        '\u{e}' => Key::CtrlN,
+        '\u{f}' => Key::CtrlO,
+        '\u{10}' => Key::CtrlP,
+        '\u{11}' => Key::CtrlQ,
+        '\u{12}' => Key::CtrlR,
+        '\u{13}' => Key::CtrlS,
+        '\u{14}' => Key::CtrlT,
+        '\u{15}' => Key::CtrlU,
+        '\u{16}' => Key::CtrlV,
+        '\u{17}' => Key::CtrlW,
+        '\u{18}' => Key::CtrlX,
+        '\u{19}' => Key::CtrlY,
+        '\u{1a}' => Key::CtrlZ,
+        ',' => Key::Comma,
+        ':' => Key::Colon,
+        '?' => Key::QuestionMark,
+        // Letters have keys named after them
+        'a'..='z' => return key_from_name(&ch.to_string()),
+        'A'..='Z' => return key_from_name(&format!("Shift{}", ch)),
+        _other => return None,
+    };
+    Some(key)
+}
+
+pub fn key_name(key: &Key) -> String {
+    format!("{:?}", key)
+}
+
+pub fn key_from_name(name: &str) -> Option<Key> {
+    named_keys()
+        .into_iter()
+        .find(|k| key_name(k).eq_ignore_ascii_case(name))
+}
+
+fn named_keys() -> Vec<Key> {
+    vec![
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+        Key::ShiftA,
+        Key::ShiftB,
+        Key::ShiftC,
+        Key::ShiftD,
+        Key::ShiftE,
+        Key::ShiftF,
+        Key::ShiftG,
+        Key::ShiftH,
+        Key::ShiftI,
+        Key::ShiftJ,
+        Key::ShiftK,
+        Key::ShiftL,
+        Key::ShiftM,
+        Key::ShiftN,
+        Key::ShiftO,
+        Key::ShiftP,
+        Key::ShiftQ,
+        Key::ShiftR,
+        Key::ShiftS,
+        Key::ShiftT,
+        Key::ShiftU,
+        Key::ShiftV,
+        Key::ShiftW,
+        Key::ShiftX,
+        Key::ShiftY,
+        Key::ShiftZ,
+        Key::CtrlA,
+        Key::CtrlB,
+        Key::CtrlC,
+        Key::CtrlD,
+        Key::CtrlE,
+        Key::CtrlF,
+        Key::CtrlG,
+        Key::CtrlH,
+        Key::CtrlK,
+        Key::CtrlL,
+        Key::CtrlM,
+        Key::CtrlN,
+        Key::CtrlO,
+        Key::CtrlP,
+        Key::CtrlQ,
+        Key::CtrlR,
+        Key::CtrlS,
+        Key::CtrlT,
+        Key::CtrlU,
+        Key::CtrlV,
+        Key::CtrlW,
+        Key::CtrlX,
+        Key::CtrlY,
+        Key::CtrlZ,
+        Key::AltB,
+        Key::AltF,
+        Key::AltM,
+        Key::AltTab,
+        Key::AltEnter,
+        Key::AltCtrlH,
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+        Key::Up,
+        Key::Down,
+        Key::Left,
+        Key::Right,
+        Key::Home,
+        Key::End,
+        Key::PgUp,
+        Key::PgDn,
+        Key::Delete,
+        Key::Enter,
+        Key::Tab,
+        Key::Escape,
+        Key::Space,
+        Key::Comma,
+        Key::Colon,
+        Key::QuestionMark,
+    ]
+}
@@ -5,19 +5,25 @@ use dapp_lib::prelude::*;
 use std::env::args;
 use std::path::PathBuf;
 mod catalog;
+mod commands;
 mod common;
 mod config;
 mod forum;
+mod keymap;
 use catalog::logic::CatalogLogic;
 pub use catalog::tui::Creator;
 pub use catalog::tui::Editor;
 pub use catalog::tui::Indexer;
 pub use catalog::tui::Selector;
 use catalog::tui::{instantiate_tui_mgr, FromCatalogView};
-use config::Configuration;
+use config::{read_session, Configuration};
 use forum::logic::ForumLogic;
 
+use crate::commands::set_dev_mode;
+use crate::common::i18n::set_language;
+use crate::common::logging;
 use crate::common::poledit::PolicyEditor;
+use crate::common::theme::set_theme;
 use crate::forum::tui::FromForumView;
 
 enum InternalMsg {
@@ -25,6 +31,8 @@ enum InternalMsg {
     Forum(FromForumView),
     User(ToApp),
     PresentOptionsForTag(u8, String),
+    NeighborRequestTimeout(u32),
+    ConnectTimeout(u32),
 }
 
 struct Toolbox {
@@ -143,6 +151,9 @@ impl Toolset {
             policy_editor,
         }
     }
+    pub fn config(&self) -> &Configuration {
+        &self.config
+    }
     pub fn unfold(
         self,
     ) -> (
@@ -186,17 +197,34 @@ impl Toolset {
 
 #[async_std::main]
 async fn main() {
-    let dir = if let Some(arg) = args().nth(1) {
+    // --dev flag enables developer mode with test keys and debug panel
+    let dev_flag = args().skip(1).any(|a| a == "--dev");
+    let dir = if let Some(arg) = args().skip(1).find(|a| !a.starts_with("--")) {
         let args = arg.to_string();
         PathBuf::new().join(args)
     } else {
         PathBuf::new()
     };
+    logging::init(&dir);
 
     let (to_application_send, to_application_recv) = achannel::unbounded();
     let (wrapped_sender, wrapped_receiver) = achannel::unbounded();
     let (to_app_mgr_send, to_app_mgr_recv) = achannel::unbounded();
     let mut config = Configuration::new(&dir).await;
+    if dev_flag {
+        config.dev_mode = true;
+    }
+    logging::set_levels(config.log_levels.clone());
+    set_language(config.language);
+    set_dev_mode(config.dev_mode);
+    set_theme(config.theme.clone());
+    let mut session = if config.restore_session {
+        read_session(&dir)
+    } else {
+        None
+    };
+    // Catalog switches to Forum of last session once it is active
+    let mut forum_session = None;
     let storage_neighbors = if config.storage_neighbors.is_empty() {
         vec![]
     } else {
@@ -224,12 +252,13 @@ async fn main() {
     //       old one should self-terminate on error receiving FromPresentation.
     // TODO: InternalMessage should serve every defined AppType, and Notification
     let toolset = Toolset::fold(tui_mgr, config, None, None, None, None, None);
+    let clipboard = session.as_ref().and_then(|s| s.clipboard.clone());
     let mut next_app = Some((
         Some(AppType::Catalog),
         my_name.clone(),
         wrapped_receiver,
         toolset,
-        None,
+        clipboard,
     ));
     // TODO: Define a Toolbox struct to store all the tools an app might use.
     // Those are Editor, Notifier, Selector etc.
@@ -239,24 +268,30 @@ async fn main() {
         if let Some((app_type, s_name, wrapped_receiver, toolset, clipboard_opt)) = next_app.take()
         {
             if app_type.is_none() {
-                eprintln!("Dunno what AppType that is,terminating");
+                log_warn!("Dunno what AppType that is,terminating");
                 // TODO: discover AppType
                 break;
             }
             let app_type = app_type.unwrap();
-            eprintln!("Next app: {} {:?}", s_name, app_type);
+            log_info!("Next app: {} {:?}", s_name, app_type);
             let (mut tui_mgr, config, e_opt, c_opt, s_opt, i_opt, pe_opt) = toolset.unfold();
             toolbox.return_tools(e_opt, c_opt, s_opt, i_opt, pe_opt);
             match app_type {
                 AppType::Catalog => {
                     tui_mgr.restore_display(0, false);
-                    let c_logic = CatalogLogic::new(
+                    let mut c_logic = CatalogLogic::new(
                         s_name,
                         to_app_mgr_send.clone(),
                         &mut tui_mgr,
                         wrapped_sender.clone(),
                         wrapped_receiver,
                     );
+                    if let Some(session) = session.take() {
+                        if matches!(session.app_type, AppType::Forum) {
+                            forum_session = Some(session.clone());
+                        }
+                        c_logic.restore_session(session);
+                    }
                     let get_editor = true;
                     let get_creator = true;
                     let get_selector = true;
@@ -274,7 +309,8 @@ async fn main() {
                     next_app = c_logic.run(dir.clone(), toolset, clipboard_opt).await;
                 }
                 AppType::Forum => {
-                    let f_logic = ForumLogic::new(
+                    let session = forum_session.take().filter(|s| s.swarm == s_name);
+                    let mut f_logic = ForumLogic::new(
                         my_name.founder,
                         tui_mgr.screen_size(),
                         s_name,
@@ -282,6 +318,9 @@ async fn main() {
                         wrapped_sender.clone(),
                         wrapped_receiver,
                     );
+                    if let Some(session) = session {
+                        f_logic.restore_session(session);
+                    }
                     let get_editor = true;
                     let get_creator = true;
                     let get_selector = true;
@@ -310,7 +349,7 @@ async fn main() {
             break;
         }
     }
-    eprintln!("Main loop is done.");
+    log_info!("Main loop is done.");
 }
 
 async fn to_user_adapter(to_user: AReceiver<ToApp>, wrapped_sender: Sender<InternalMsg>) {